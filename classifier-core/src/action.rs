@@ -1,14 +1,15 @@
 use alloy_primitives::TxHash;
-use brontes_tracer::types::CallFrameInfo;
 
 use crate::context::DataContext;
+use crate::types::CallFrameInfo;
 use std::fmt::Debug;
 
 pub trait ActionCollection: Default + Sync + Send {
     type DispatchOut;
     type ProtocolContext;
+    type ProtocolMetadata;
 
-    fn dispatch<DB: DataContext<Self::ProtocolContext>>(
+    fn dispatch<DB: DataContext<Self::ProtocolContext, Self::ProtocolMetadata>>(
         &self,
         call_info: CallFrameInfo<'_>,
         db_ctx: &DB,
@@ -21,10 +22,11 @@ pub trait ActionCollection: Default + Sync + Send {
 pub trait IntoAction: Debug + Send + Sync {
     type DecodeOut;
     type ProtocolContext;
+    type ProtocolMetadata;
 
-    fn decode_call_trace<DB: DataContext<Self::ProtocolContext>>(
+    fn decode_call_trace<DB: DataContext<Self::ProtocolContext, Self::ProtocolMetadata>>(
         &self,
-        call_info: CallFrameInfo<'_>,
+        call_info: &CallFrameInfo<'_>,
         block: u64,
         tx_idx: u64,
         db_ctx: &DB,
@@ -0,0 +1,482 @@
+use std::{
+    hash::Hash,
+    num::NonZeroUsize,
+    sync::{
+        Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+
+use alloy_primitives::{Address, B256};
+use lru::LruCache;
+
+use super::{DataContext, NotRegistered, TokenInfo, is_not_registered};
+
+/// snapshot of the lookup counters of a [`CachedDataContext`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// lookups answered from the positive caches
+    pub hits: u64,
+    /// lookups answered from the negative cache without touching the inner
+    /// context
+    pub negative_hits: u64,
+    /// lookups forwarded to the inner context
+    pub misses: u64,
+}
+
+impl CacheStats {
+    pub fn hit_rate(&self) -> f64 {
+        let total = self.hits + self.negative_hits + self.misses;
+        if total == 0 {
+            return 0.0;
+        }
+
+        (self.hits + self.negative_hits) as f64 / total as f64
+    }
+}
+
+#[derive(Debug, Default)]
+struct CacheCounters {
+    hits: AtomicU64,
+    negative_hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+/// Wraps any [`DataContext`] with LRU caches of its results.
+///
+/// Most call targets in a block aren't protocols, so addresses and pool ids that
+/// `get_protocol` or `get_pool_tokens_sorted` of the inner context report as
+/// [`NotRegistered`] are kept in separate negative LRUs and rejected up front
+/// on the next lookup. any other error, e.g. a store that couldn't be read, is
+/// passed through without being remembered. The negative caches are exact, a
+/// registered protocol can never be shadowed by a false positive. Use
+/// [`CachedDataContext::invalidate`], [`CachedDataContext::invalidate_pool`] or
+/// [`CachedDataContext::clear`] when the inner registry changes.
+#[derive(Debug)]
+pub struct CachedDataContext<D, P, M = ()> {
+    inner: D,
+    protocols: Mutex<LruCache<Address, P>>,
+    tokens: Mutex<LruCache<Address, Vec<Address>>>,
+    ordered_tokens: Mutex<LruCache<Address, Vec<Address>>>,
+    metadata: Mutex<LruCache<Address, M>>,
+    token_info: Mutex<LruCache<Address, TokenInfo>>,
+    unknown: Mutex<LruCache<Address, ()>>,
+    pool_tokens: Mutex<LruCache<B256, Vec<Address>>>,
+    pool_metadata: Mutex<LruCache<B256, M>>,
+    unknown_pools: Mutex<LruCache<B256, ()>>,
+    counters: CacheCounters,
+}
+
+impl<D, P, M> CachedDataContext<D, P, M>
+where
+    D: DataContext<P, M>,
+    P: Clone,
+    M: Clone,
+{
+    /// `capacity` bounds each of the positive caches, `negative_capacity` the
+    /// amount of unknown addresses and pool ids remembered.
+    pub fn new(inner: D, capacity: NonZeroUsize, negative_capacity: NonZeroUsize) -> Self {
+        Self {
+            inner,
+            protocols: Mutex::new(LruCache::new(capacity)),
+            tokens: Mutex::new(LruCache::new(capacity)),
+            ordered_tokens: Mutex::new(LruCache::new(capacity)),
+            metadata: Mutex::new(LruCache::new(capacity)),
+            token_info: Mutex::new(LruCache::new(capacity)),
+            unknown: Mutex::new(LruCache::new(negative_capacity)),
+            pool_tokens: Mutex::new(LruCache::new(capacity)),
+            pool_metadata: Mutex::new(LruCache::new(capacity)),
+            unknown_pools: Mutex::new(LruCache::new(negative_capacity)),
+            counters: CacheCounters::default(),
+        }
+    }
+
+    pub fn inner(&self) -> &D {
+        &self.inner
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.counters.hits.load(Ordering::Relaxed),
+            negative_hits: self.counters.negative_hits.load(Ordering::Relaxed),
+            misses: self.counters.misses.load(Ordering::Relaxed),
+        }
+    }
+
+    pub fn reset_stats(&self) {
+        self.counters.hits.store(0, Ordering::Relaxed);
+        self.counters.negative_hits.store(0, Ordering::Relaxed);
+        self.counters.misses.store(0, Ordering::Relaxed);
+    }
+
+    /// seeds the cache with a known protocol, e.g. from a batched prefetch
+    pub fn insert_protocol(&self, target_address: Address, protocol: P) {
+        self.unknown.lock().unwrap().pop(&target_address);
+        self.protocols.lock().unwrap().put(target_address, protocol);
+    }
+
+    /// seeds the negative cache with an address known not to be a protocol
+    pub fn insert_unknown(&self, target_address: Address) {
+        self.protocols.lock().unwrap().pop(&target_address);
+        self.unknown.lock().unwrap().put(target_address, ());
+    }
+
+    /// drops everything cached for `target_address`
+    pub fn invalidate(&self, target_address: Address) {
+        self.protocols.lock().unwrap().pop(&target_address);
+        self.tokens.lock().unwrap().pop(&target_address);
+        self.ordered_tokens.lock().unwrap().pop(&target_address);
+        self.metadata.lock().unwrap().pop(&target_address);
+        self.token_info.lock().unwrap().pop(&target_address);
+        self.unknown.lock().unwrap().pop(&target_address);
+    }
+
+    /// drops everything cached for `pool_id`
+    pub fn invalidate_pool(&self, pool_id: B256) {
+        self.pool_tokens.lock().unwrap().pop(&pool_id);
+        self.pool_metadata.lock().unwrap().pop(&pool_id);
+        self.unknown_pools.lock().unwrap().pop(&pool_id);
+    }
+
+    pub fn clear(&self) {
+        self.protocols.lock().unwrap().clear();
+        self.tokens.lock().unwrap().clear();
+        self.ordered_tokens.lock().unwrap().clear();
+        self.metadata.lock().unwrap().clear();
+        self.token_info.lock().unwrap().clear();
+        self.unknown.lock().unwrap().clear();
+        self.pool_tokens.lock().unwrap().clear();
+        self.pool_metadata.lock().unwrap().clear();
+        self.unknown_pools.lock().unwrap().clear();
+    }
+
+    fn is_known_unknown(&self, target_address: Address) -> bool {
+        self.is_negative_hit(&self.unknown, &target_address)
+    }
+
+    fn is_known_unknown_pool(&self, pool_id: B256) -> bool {
+        self.is_negative_hit(&self.unknown_pools, &pool_id)
+    }
+
+    fn is_negative_hit<K: Hash + Eq>(&self, cache: &Mutex<LruCache<K, ()>>, key: &K) -> bool {
+        let unknown = cache.lock().unwrap().get(key).is_some();
+        if unknown {
+            self.counters.negative_hits.fetch_add(1, Ordering::Relaxed);
+        }
+
+        unknown
+    }
+
+    fn get_or_fetch<K: Hash + Eq, V: Clone>(
+        &self,
+        cache: &Mutex<LruCache<K, V>>,
+        key: K,
+        fetch: impl FnOnce(&D) -> eyre::Result<V>,
+    ) -> eyre::Result<V> {
+        if let Some(value) = cache.lock().unwrap().get(&key) {
+            self.counters.hits.fetch_add(1, Ordering::Relaxed);
+            return Ok(value.clone());
+        }
+
+        self.counters.misses.fetch_add(1, Ordering::Relaxed);
+        let value = fetch(&self.inner)?;
+        cache.lock().unwrap().put(key, value.clone());
+
+        Ok(value)
+    }
+}
+
+impl<D, P, M> DataContext<P, M> for CachedDataContext<D, P, M>
+where
+    D: DataContext<P, M>,
+    P: Clone,
+    M: Clone,
+{
+    fn get_protocol(&self, target_address: Address) -> eyre::Result<P> {
+        if self.is_known_unknown(target_address) {
+            return Err(NotRegistered::Address(target_address).into());
+        }
+
+        self.get_or_fetch(&self.protocols, target_address, |inner| {
+            inner.get_protocol(target_address)
+        })
+        .inspect_err(|e| {
+            if is_not_registered(e) {
+                self.unknown.lock().unwrap().put(target_address, ());
+            }
+        })
+    }
+
+    fn get_protocol_tokens_sorted(&self, target_address: Address) -> eyre::Result<Vec<Address>> {
+        if self.is_known_unknown(target_address) {
+            return Err(NotRegistered::Address(target_address).into());
+        }
+
+        self.get_or_fetch(&self.tokens, target_address, |inner| {
+            inner.get_protocol_tokens_sorted(target_address)
+        })
+    }
+
+    fn get_protocol_tokens(&self, target_address: Address) -> eyre::Result<Vec<Address>> {
+        if self.is_known_unknown(target_address) {
+            return Err(NotRegistered::Address(target_address).into());
+        }
+
+        self.get_or_fetch(&self.ordered_tokens, target_address, |inner| {
+            inner.get_protocol_tokens(target_address)
+        })
+    }
+
+    fn get_metadata(&self, target_address: Address) -> eyre::Result<M> {
+        if self.is_known_unknown(target_address) {
+            return Err(NotRegistered::Address(target_address).into());
+        }
+
+        self.get_or_fetch(&self.metadata, target_address, |inner| {
+            inner.get_metadata(target_address)
+        })
+    }
+
+    /// a pool id without tokens isn't a pool at all, so only this lookup marks
+    /// ids as unknown. `get_pool_metadata` misses aren't remembered as the
+    /// default of contexts without pool metadata fails for registered pools too
+    fn get_pool_tokens_sorted(&self, pool_id: B256) -> eyre::Result<Vec<Address>> {
+        if self.is_known_unknown_pool(pool_id) {
+            return Err(NotRegistered::Pool(pool_id).into());
+        }
+
+        self.get_or_fetch(&self.pool_tokens, pool_id, |inner| {
+            inner.get_pool_tokens_sorted(pool_id)
+        })
+        .inspect_err(|e| {
+            if is_not_registered(e) {
+                self.unknown_pools.lock().unwrap().put(pool_id, ());
+            }
+        })
+    }
+
+    fn get_pool_metadata(&self, pool_id: B256) -> eyre::Result<M> {
+        if self.is_known_unknown_pool(pool_id) {
+            return Err(NotRegistered::Pool(pool_id).into());
+        }
+
+        self.get_or_fetch(&self.pool_metadata, pool_id, |inner| {
+            inner.get_pool_metadata(pool_id)
+        })
+    }
+
+    fn get_token_info(&self, token: Address) -> eyre::Result<TokenInfo> {
+        self.get_or_fetch(&self.token_info, token, |inner| inner.get_token_info(token))
+    }
+
+    fn prefetch(&self, addresses: &[Address]) -> eyre::Result<()> {
+        self.inner.prefetch(addresses)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+
+    use alloy_primitives::address;
+
+    use super::*;
+
+    const PAIR: Address = address!("0000000000000000000000000000000000000001");
+    const OTHER_PAIR: Address = address!("0000000000000000000000000000000000000002");
+    const EOA: Address = address!("0000000000000000000000000000000000000003");
+    const OTHER_EOA: Address = address!("0000000000000000000000000000000000000004");
+    /// lookups of this address fail without a definite answer
+    const FLAKY: Address = address!("0000000000000000000000000000000000000005");
+
+    /// knows the two pairs and a single pool id, counts the lookups that reach
+    /// it
+    #[derive(Default)]
+    struct Inner {
+        lookups: AtomicUsize,
+    }
+
+    impl Inner {
+        fn lookup(&self) {
+            self.lookups.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    impl DataContext<u8> for Inner {
+        fn get_protocol(&self, target_address: Address) -> eyre::Result<u8> {
+            self.lookup();
+            match target_address {
+                PAIR => Ok(1),
+                OTHER_PAIR => Ok(2),
+                FLAKY => Err(eyre::eyre!("store unavailable")),
+                _ => Err(NotRegistered::Address(target_address).into()),
+            }
+        }
+
+        fn get_protocol_tokens_sorted(
+            &self,
+            target_address: Address,
+        ) -> eyre::Result<Vec<Address>> {
+            self.get_protocol(target_address).map(|_| vec![EOA])
+        }
+
+        fn get_pool_tokens_sorted(&self, pool_id: B256) -> eyre::Result<Vec<Address>> {
+            self.lookup();
+            if pool_id == B256::with_last_byte(1) {
+                Ok(vec![PAIR, OTHER_PAIR])
+            } else {
+                Err(NotRegistered::Pool(pool_id).into())
+            }
+        }
+    }
+
+    fn cache(capacity: usize, negative_capacity: usize) -> CachedDataContext<Inner, u8> {
+        CachedDataContext::new(
+            Inner::default(),
+            NonZeroUsize::new(capacity).unwrap(),
+            NonZeroUsize::new(negative_capacity).unwrap(),
+        )
+    }
+
+    fn lookups(cache: &CachedDataContext<Inner, u8>) -> usize {
+        cache.inner().lookups.load(Ordering::Relaxed)
+    }
+
+    #[test]
+    fn caches_found_protocols() {
+        let cache = cache(2, 2);
+
+        assert_eq!(cache.get_protocol(PAIR).unwrap(), 1);
+        assert_eq!(cache.get_protocol(PAIR).unwrap(), 1);
+        assert_eq!(lookups(&cache), 1);
+        assert_eq!(
+            cache.stats(),
+            CacheStats {
+                hits: 1,
+                negative_hits: 0,
+                misses: 1
+            }
+        );
+        assert_eq!(cache.stats().hit_rate(), 0.5);
+    }
+
+    #[test]
+    fn evicts_the_least_recently_used_protocol() {
+        let cache = cache(1, 1);
+
+        cache.get_protocol(PAIR).unwrap();
+        cache.get_protocol(OTHER_PAIR).unwrap();
+        assert_eq!(cache.get_protocol(PAIR).unwrap(), 1);
+        assert_eq!(lookups(&cache), 3);
+    }
+
+    #[test]
+    fn negative_caches_unregistered_addresses() {
+        let cache = cache(1, 1);
+
+        assert!(is_not_registered(&cache.get_protocol(EOA).unwrap_err()));
+        assert_eq!(lookups(&cache), 1);
+
+        // every lookup of a known unknown is answered up front
+        assert!(is_not_registered(&cache.get_protocol(EOA).unwrap_err()));
+        assert!(is_not_registered(
+            &cache.get_protocol_tokens_sorted(EOA).unwrap_err()
+        ));
+        assert_eq!(lookups(&cache), 1);
+        assert_eq!(cache.stats().negative_hits, 2);
+    }
+
+    #[test]
+    fn evicts_the_least_recently_used_unknown_address() {
+        let cache = cache(1, 1);
+
+        assert!(cache.get_protocol(EOA).is_err());
+        assert!(cache.get_protocol(OTHER_EOA).is_err());
+        assert!(cache.get_protocol(EOA).is_err());
+        assert_eq!(lookups(&cache), 3);
+        assert_eq!(cache.stats().negative_hits, 0);
+    }
+
+    #[test]
+    fn doesnt_negative_cache_failed_lookups() {
+        let cache = cache(1, 1);
+
+        for _ in 0..2 {
+            let error = cache.get_protocol(FLAKY).unwrap_err();
+            assert!(!is_not_registered(&error));
+        }
+        assert_eq!(lookups(&cache), 2);
+    }
+
+    #[test]
+    fn seeded_entries_replace_each_other() {
+        let cache = cache(1, 1);
+
+        cache.insert_unknown(PAIR);
+        assert!(cache.get_protocol(PAIR).is_err());
+        cache.insert_protocol(PAIR, 7);
+        assert_eq!(cache.get_protocol(PAIR).unwrap(), 7);
+        assert_eq!(lookups(&cache), 0);
+
+        cache.invalidate(PAIR);
+        assert_eq!(cache.get_protocol(PAIR).unwrap(), 1);
+        assert_eq!(lookups(&cache), 1);
+    }
+
+    #[test]
+    fn caches_pool_ids_and_their_misses() {
+        let cache = cache(1, 1);
+        let pool = B256::with_last_byte(1);
+        let unknown = B256::with_last_byte(2);
+
+        assert_eq!(
+            cache.get_pool_tokens_sorted(pool).unwrap(),
+            vec![PAIR, OTHER_PAIR]
+        );
+        cache.get_pool_tokens_sorted(pool).unwrap();
+        assert_eq!(lookups(&cache), 1);
+
+        for _ in 0..2 {
+            let error = cache.get_pool_tokens_sorted(unknown).unwrap_err();
+            assert!(is_not_registered(&error));
+        }
+        // the tokens miss is shared with the metadata lookup
+        assert!(cache.get_pool_metadata(unknown).is_err());
+        assert_eq!(lookups(&cache), 2);
+
+        cache.invalidate_pool(unknown);
+        assert!(cache.get_pool_tokens_sorted(unknown).is_err());
+        assert_eq!(lookups(&cache), 3);
+    }
+
+    #[test]
+    fn metadata_misses_dont_shadow_registered_pools() {
+        let cache = cache(1, 1);
+        let pool = B256::with_last_byte(1);
+
+        // `Inner` has no pool metadata, so the default lookup fails
+        assert!(is_not_registered(
+            &cache.get_pool_metadata(pool).unwrap_err()
+        ));
+        assert_eq!(
+            cache.get_pool_tokens_sorted(pool).unwrap(),
+            vec![PAIR, OTHER_PAIR]
+        );
+        assert_eq!(cache.stats().negative_hits, 0);
+    }
+
+    #[test]
+    fn clears_everything() {
+        let cache = cache(2, 2);
+
+        cache.get_protocol(PAIR).unwrap();
+        assert!(cache.get_protocol(EOA).is_err());
+        cache.clear();
+        cache.get_protocol(PAIR).unwrap();
+        assert!(cache.get_protocol(EOA).is_err());
+        assert_eq!(lookups(&cache), 4);
+
+        cache.reset_stats();
+        assert_eq!(cache.stats(), CacheStats::default());
+    }
+}
@@ -0,0 +1,307 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{Arc, Mutex, RwLock},
+    time::SystemTime,
+};
+
+use alloy_primitives::{Address, B256};
+use serde::de::DeserializeOwned;
+
+use super::{
+    DataContext, NotRegistered, TokenInfo, TokenList,
+    registry::{ProtocolEntry, load_registry, resolve_pool_registry, resolve_registry},
+};
+
+#[derive(Debug)]
+struct Registry<P, M> {
+    protocols: HashMap<Address, ProtocolEntry<P, M>>,
+    pools: HashMap<B256, ProtocolEntry<P, M>>,
+}
+
+impl<P: FromStr, M: DeserializeOwned + Default> Registry<P, M> {
+    fn load(path: &Path) -> eyre::Result<Self> {
+        let (pools, protocols): (Vec<_>, Vec<_>) = load_registry(path)?
+            .into_iter()
+            .partition(|entry| entry.pool_id.is_some());
+
+        Ok(Self {
+            protocols: resolve_registry(protocols)?,
+            pools: resolve_pool_registry(pools)?,
+        })
+    }
+}
+
+/// A [`DataContext`] backed by a protocol registry file on disk (see
+/// [`RegistryEntry`](super::RegistryEntry) for the formats).
+///
+/// The registry is held behind a lock so it can be swapped out while
+/// classification is running, either explicitly through
+/// [`FileDataContext::reload`] or by polling
+/// [`FileDataContext::reload_if_changed`].
+#[derive(Debug)]
+pub struct FileDataContext<P, M = ()> {
+    path: PathBuf,
+    registry: RwLock<Arc<Registry<P, M>>>,
+    last_modified: Mutex<Option<SystemTime>>,
+    tokens: TokenList,
+}
+
+impl<P, M> FileDataContext<P, M>
+where
+    P: FromStr + Clone,
+    M: DeserializeOwned + Default + Clone,
+{
+    /// loads the registry, failing if the file can't be parsed or it
+    /// references protocol names that `P` doesn't know.
+    pub fn new(path: impl AsRef<Path>) -> eyre::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let last_modified = modified_at(&path);
+        let registry = Registry::load(&path)?;
+
+        Ok(Self {
+            path,
+            registry: RwLock::new(Arc::new(registry)),
+            last_modified: Mutex::new(last_modified),
+            tokens: TokenList::default(),
+        })
+    }
+
+    /// token metadata returned from `get_token_info`
+    pub fn with_token_list(mut self, tokens: TokenList) -> Self {
+        self.tokens = tokens;
+        self
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// re-reads the registry file. on failure the currently loaded registry is
+    /// kept.
+    pub fn reload(&self) -> eyre::Result<()> {
+        let last_modified = modified_at(&self.path);
+        let registry = Registry::load(&self.path)?;
+
+        *self.registry.write().unwrap() = Arc::new(registry);
+        *self.last_modified.lock().unwrap() = last_modified;
+
+        Ok(())
+    }
+
+    /// reloads the registry if the file's modification time moved since the
+    /// last load, returns whether a reload happened.
+    pub fn reload_if_changed(&self) -> eyre::Result<bool> {
+        let modified = modified_at(&self.path);
+        if modified.is_some() && modified == *self.last_modified.lock().unwrap() {
+            return Ok(false);
+        }
+
+        self.reload()?;
+        Ok(true)
+    }
+
+    pub fn get_entry(&self, target_address: Address) -> Option<ProtocolEntry<P, M>> {
+        self.registry
+            .read()
+            .unwrap()
+            .protocols
+            .get(&target_address)
+            .cloned()
+    }
+
+    pub fn get_pool_entry(&self, pool_id: B256) -> Option<ProtocolEntry<P, M>> {
+        self.registry.read().unwrap().pools.get(&pool_id).cloned()
+    }
+
+    /// the amount of entries, address and pool id keyed ones combined
+    pub fn len(&self) -> usize {
+        let registry = self.registry.read().unwrap();
+        registry.protocols.len() + registry.pools.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn with_entry<R>(
+        &self,
+        target_address: Address,
+        f: impl FnOnce(&ProtocolEntry<P, M>) -> R,
+    ) -> eyre::Result<R> {
+        self.registry
+            .read()
+            .unwrap()
+            .protocols
+            .get(&target_address)
+            .map(f)
+            .ok_or_else(|| NotRegistered::Address(target_address).into())
+    }
+
+    fn with_pool_entry<R>(
+        &self,
+        pool_id: B256,
+        f: impl FnOnce(&ProtocolEntry<P, M>) -> R,
+    ) -> eyre::Result<R> {
+        self.registry
+            .read()
+            .unwrap()
+            .pools
+            .get(&pool_id)
+            .map(f)
+            .ok_or_else(|| NotRegistered::Pool(pool_id).into())
+    }
+}
+
+impl<P, M> DataContext<P, M> for FileDataContext<P, M>
+where
+    P: FromStr + Clone,
+    M: DeserializeOwned + Default + Clone,
+{
+    fn get_protocol(&self, target_address: Address) -> eyre::Result<P> {
+        self.with_entry(target_address, |entry| entry.protocol.clone())
+    }
+
+    fn get_protocol_tokens_sorted(&self, target_address: Address) -> eyre::Result<Vec<Address>> {
+        self.with_entry(target_address, |entry| sorted(&entry.tokens))
+    }
+
+    fn get_protocol_tokens(&self, target_address: Address) -> eyre::Result<Vec<Address>> {
+        self.with_entry(target_address, |entry| entry.tokens.clone())
+    }
+
+    fn get_metadata(&self, target_address: Address) -> eyre::Result<M> {
+        self.with_entry(target_address, |entry| entry.metadata.clone())
+    }
+
+    fn get_pool_tokens_sorted(&self, pool_id: B256) -> eyre::Result<Vec<Address>> {
+        self.with_pool_entry(pool_id, |entry| sorted(&entry.tokens))
+    }
+
+    fn get_pool_metadata(&self, pool_id: B256) -> eyre::Result<M> {
+        self.with_pool_entry(pool_id, |entry| entry.metadata.clone())
+    }
+
+    fn get_token_info(&self, token: Address) -> eyre::Result<TokenInfo> {
+        self.tokens.get_token_info(token)
+    }
+}
+
+fn sorted(tokens: &[Address]) -> Vec<Address> {
+    let mut tokens = tokens.to_vec();
+    tokens.sort_unstable();
+    tokens
+}
+
+fn modified_at(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path)
+        .and_then(|meta| meta.modified())
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{address, b256};
+    use serde_json::json;
+
+    use super::*;
+    use crate::context::{
+        is_not_registered,
+        test_utils::{TempPath, TestProtocol},
+    };
+
+    const PAIR: Address = address!("0000000000000000000000000000000000000001");
+    const TOKEN_A: Address = address!("0000000000000000000000000000000000000002");
+    const TOKEN_B: Address = address!("0000000000000000000000000000000000000003");
+    const MANAGER: Address = address!("0000000000000000000000000000000000000004");
+    const POOL_ID: B256 = b256!("0000000000000000000000000000000000000000000000000000000000000005");
+
+    fn registry(protocol: &str) -> String {
+        json!([
+            { "address": PAIR, "protocol": protocol, "tokens": [TOKEN_B, TOKEN_A] },
+            {
+                "address": MANAGER,
+                "protocol": "UniswapV4",
+                "tokens": [TOKEN_B, TOKEN_A],
+                "pool_id": POOL_ID
+            }
+        ])
+        .to_string()
+    }
+
+    /// moves the file's modification time so a rewrite within the timestamp
+    /// granularity of the file system is still noticed
+    fn touch(path: &TempPath, secs: u64) {
+        std::fs::File::options()
+            .write(true)
+            .open(path)
+            .unwrap()
+            .set_modified(SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(secs))
+            .unwrap();
+    }
+
+    #[test]
+    fn looks_up_addresses_and_pool_ids() {
+        let path = TempPath::with_contents("json", &registry("UniswapV2"));
+        let ctx = FileDataContext::<TestProtocol>::new(&path).unwrap();
+
+        assert_eq!(ctx.len(), 2);
+        assert_eq!(ctx.get_protocol(PAIR).unwrap(), TestProtocol::UniswapV2);
+        assert_eq!(
+            ctx.get_protocol_tokens_sorted(PAIR).unwrap(),
+            vec![TOKEN_A, TOKEN_B]
+        );
+        assert_eq!(
+            ctx.get_protocol_tokens(PAIR).unwrap(),
+            vec![TOKEN_B, TOKEN_A]
+        );
+        assert_eq!(
+            ctx.get_pool_tokens_sorted(POOL_ID).unwrap(),
+            vec![TOKEN_A, TOKEN_B]
+        );
+
+        // pool entries are only reachable through their id
+        assert!(is_not_registered(&ctx.get_protocol(MANAGER).unwrap_err()));
+        assert!(is_not_registered(
+            &ctx.get_pool_tokens_sorted(B256::ZERO).unwrap_err()
+        ));
+    }
+
+    #[test]
+    fn fails_on_unknown_protocol_names() {
+        let path = TempPath::with_contents("json", &registry("SushiSwap"));
+        assert!(FileDataContext::<TestProtocol>::new(&path).is_err());
+    }
+
+    #[test]
+    fn reloads_when_the_file_changes() {
+        let path = TempPath::with_contents("json", &registry("UniswapV2"));
+        touch(&path, 1);
+        let ctx = FileDataContext::<TestProtocol>::new(&path).unwrap();
+        assert!(!ctx.reload_if_changed().unwrap());
+
+        path.write(&json!([{ "address": TOKEN_A, "protocol": "UniswapV2" }]).to_string());
+        touch(&path, 2);
+        assert!(ctx.reload_if_changed().unwrap());
+        assert!(!ctx.reload_if_changed().unwrap());
+
+        assert_eq!(ctx.len(), 1);
+        assert!(is_not_registered(&ctx.get_protocol(PAIR).unwrap_err()));
+        assert_eq!(ctx.get_protocol(TOKEN_A).unwrap(), TestProtocol::UniswapV2);
+    }
+
+    #[test]
+    fn keeps_the_loaded_registry_when_a_reload_fails() {
+        let path = TempPath::with_contents("json", &registry("UniswapV2"));
+        touch(&path, 1);
+        let ctx = FileDataContext::<TestProtocol>::new(&path).unwrap();
+
+        path.write("not json");
+        touch(&path, 2);
+        assert!(ctx.reload_if_changed().is_err());
+
+        assert_eq!(ctx.len(), 2);
+        assert_eq!(ctx.get_protocol(PAIR).unwrap(), TestProtocol::UniswapV2);
+    }
+}
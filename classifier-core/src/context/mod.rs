@@ -0,0 +1,114 @@
+mod cached;
+mod file;
+mod registry;
+#[cfg(feature = "redb")]
+mod store;
+#[cfg(test)]
+mod test_utils;
+mod tokens;
+
+use alloy_primitives::{Address, B256};
+pub use cached::{CacheStats, CachedDataContext};
+pub use file::FileDataContext;
+pub use registry::{
+    ProtocolEntry, RegistryEntry, check_protocol_names, load_registry, resolve_pool_registry,
+    resolve_registry,
+};
+#[cfg(feature = "redb")]
+pub use store::RedbDataContext;
+pub use tokens::{TokenInfo, TokenList};
+
+/// Registry access handed to every classifier.
+///
+/// `T` is the protocol enum used for dispatch and `M` is the strongly typed
+/// pool / market metadata the classifiers of that collection expect, e.g. the
+/// coin list of a curve pool or the pool key of a uniswap v4 pool. collections
+/// that don't need anything beyond the sorted tokens can leave it as `()`.
+pub trait DataContext<T, M = ()> {
+    /// fails with [`NotRegistered`] for addresses that aren't a protocol, other
+    /// errors are treated as transient by wrapping contexts
+    fn get_protocol(&self, target_address: Address) -> eyre::Result<T>;
+
+    fn get_protocol_tokens_sorted(&self, target_address: Address) -> eyre::Result<Vec<Address>>;
+
+    /// the tokens of a protocol in the order the protocol itself indexes them,
+    /// e.g. the coin order of a curve pool. contexts that only know the sorted
+    /// tokens can rely on the default which always errors.
+    fn get_protocol_tokens(&self, target_address: Address) -> eyre::Result<Vec<Address>> {
+        Err(eyre::eyre!(
+            "no token order available for {target_address:?}"
+        ))
+    }
+
+    /// the typed metadata of a protocol. contexts without metadata can rely on
+    /// the default which always errors.
+    fn get_metadata(&self, target_address: Address) -> eyre::Result<M> {
+        Err(eyre::eyre!("no metadata available for {target_address:?}"))
+    }
+
+    /// tokens of a pool that lives inside a singleton and is identified by its
+    /// pool id rather than an address, e.g. a uniswap v4 pool. contexts without
+    /// such pools can rely on the default which always errors.
+    fn get_pool_tokens_sorted(&self, pool_id: B256) -> eyre::Result<Vec<Address>> {
+        Err(NotRegistered::Pool(pool_id).into())
+    }
+
+    /// the metadata of a pool id keyed pool, see `get_pool_tokens_sorted`
+    fn get_pool_metadata(&self, pool_id: B256) -> eyre::Result<M> {
+        Err(NotRegistered::Pool(pool_id).into())
+    }
+
+    /// decimals, symbol and name of a token, used by classifiers that want to
+    /// output decimal normalized amounts. contexts without token data can rely
+    /// on the default which always errors.
+    fn get_token_info(&self, token: Address) -> eyre::Result<TokenInfo> {
+        Err(eyre::eyre!("no token info available for {token:?}"))
+    }
+
+    /// invoked once per block before classification with the deduplicated
+    /// targets of every non-static call, delegate call targets included, so
+    /// contexts backed by a remote store can load them in a single batch
+    /// instead of one lookup per trace. errors are logged and don't stop the
+    /// block from being classified.
+    fn prefetch(&self, _addresses: &[Address]) -> eyre::Result<()> {
+        Ok(())
+    }
+}
+
+/// The error of a lookup for an address or pool id that the context definitely
+/// has no entry for, as opposed to a lookup that failed to complete. wrappers
+/// such as [`CachedDataContext`] only remember misses that fail with it, see
+/// [`is_not_registered`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotRegistered {
+    Address(Address),
+    Pool(B256),
+}
+
+impl std::fmt::Display for NotRegistered {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Address(address) => write!(f, "{address:?} is not registered"),
+            Self::Pool(pool_id) => write!(f, "pool {pool_id:?} is not registered"),
+        }
+    }
+}
+
+impl std::error::Error for NotRegistered {}
+
+impl From<Address> for NotRegistered {
+    fn from(address: Address) -> Self {
+        Self::Address(address)
+    }
+}
+
+impl From<B256> for NotRegistered {
+    fn from(pool_id: B256) -> Self {
+        Self::Pool(pool_id)
+    }
+}
+
+/// whether `error` is a definite [`NotRegistered`] miss
+pub fn is_not_registered(error: &eyre::Report) -> bool {
+    error.downcast_ref::<NotRegistered>().is_some()
+}
@@ -0,0 +1,343 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs::File,
+    io::BufReader,
+    path::Path,
+    str::FromStr,
+};
+
+use alloy_primitives::{Address, B256};
+use serde::{Deserialize, de::DeserializeOwned};
+
+/// A single row of an on disk protocol registry, before the protocol name is
+/// mapped onto the user's protocol enum.
+///
+/// json registries are an array of these (or `{ "protocols": [..] }`), toml
+/// registries use `[[protocols]]` tables and csv registries expect the header
+/// `address,protocol,tokens,deployment_block,metadata` where `tokens` is `;`
+/// separated and `metadata` is a json encoded `M`, plus an optional `pool_id`
+/// column.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(bound(deserialize = "M: Deserialize<'de> + Default"))]
+pub struct RegistryEntry<M = ()> {
+    pub address: Address,
+    pub protocol: String,
+    #[serde(default)]
+    pub tokens: Vec<Address>,
+    #[serde(default)]
+    pub deployment_block: Option<u64>,
+    #[serde(default)]
+    pub metadata: M,
+    /// set for pools living inside a singleton such as the uniswap v4 pool
+    /// manager. `address` is then the singleton's and the entry is looked up
+    /// by its pool id instead.
+    #[serde(default)]
+    pub pool_id: Option<B256>,
+}
+
+/// A registry entry with its protocol resolved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProtocolEntry<P, M = ()> {
+    pub protocol: P,
+    /// the tokens in the order they are listed in the registry, for pools this
+    /// should be the pool's own token / coin order. `get_protocol_tokens`
+    /// returns them in this order, `get_protocol_tokens_sorted` sorted.
+    pub tokens: Vec<Address>,
+    pub deployment_block: Option<u64>,
+    pub metadata: M,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+#[serde(bound(deserialize = "M: Deserialize<'de> + Default"))]
+enum JsonRegistry<M> {
+    Protocols { protocols: Vec<RegistryEntry<M>> },
+    Entries(Vec<RegistryEntry<M>>),
+}
+
+#[derive(Deserialize)]
+#[serde(bound(deserialize = "M: Deserialize<'de> + Default"))]
+struct TomlRegistry<M> {
+    protocols: Vec<RegistryEntry<M>>,
+}
+
+#[derive(Deserialize)]
+struct CsvRegistryRow {
+    address: Address,
+    protocol: String,
+    #[serde(default)]
+    tokens: String,
+    #[serde(default)]
+    deployment_block: Option<u64>,
+    #[serde(default)]
+    metadata: Option<String>,
+    #[serde(default)]
+    pool_id: Option<B256>,
+}
+
+impl CsvRegistryRow {
+    fn into_entry<M: DeserializeOwned + Default>(self) -> eyre::Result<RegistryEntry<M>> {
+        let tokens = self
+            .tokens
+            .split(';')
+            .map(str::trim)
+            .filter(|token| !token.is_empty())
+            .map(Address::from_str)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let metadata = match self.metadata.as_deref().map(str::trim) {
+            Some(metadata) if !metadata.is_empty() => serde_json::from_str(metadata)?,
+            _ => M::default(),
+        };
+
+        Ok(RegistryEntry {
+            address: self.address,
+            protocol: self.protocol,
+            tokens,
+            deployment_block: self.deployment_block,
+            metadata,
+            pool_id: self.pool_id,
+        })
+    }
+}
+
+/// reads the raw registry entries, picking the format from the file extension
+pub fn load_registry<M>(path: impl AsRef<Path>) -> eyre::Result<Vec<RegistryEntry<M>>>
+where
+    M: DeserializeOwned + Default,
+{
+    let path = path.as_ref();
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("json") => {
+            let reader = BufReader::new(File::open(path)?);
+            Ok(match serde_json::from_reader(reader)? {
+                JsonRegistry::Protocols { protocols } => protocols,
+                JsonRegistry::Entries(entries) => entries,
+            })
+        }
+        Some("toml") => {
+            let registry: TomlRegistry<M> = toml::from_str(&std::fs::read_to_string(path)?)?;
+            Ok(registry.protocols)
+        }
+        Some("csv") => csv::Reader::from_path(path)?
+            .deserialize::<CsvRegistryRow>()
+            .map(|row| row?.into_entry())
+            .collect(),
+        _ => Err(eyre::eyre!(
+            "unsupported registry format for {}, expected .json, .toml or .csv",
+            path.display()
+        )),
+    }
+}
+
+/// maps every protocol name onto `P`. all names that `P` doesn't know are
+/// collected and reported together instead of failing on the first one. when
+/// an address is listed more than once the last entry wins. entries with a
+/// pool id are skipped, see [`resolve_pool_registry`].
+pub fn resolve_registry<P, M>(
+    entries: Vec<RegistryEntry<M>>,
+) -> eyre::Result<HashMap<Address, ProtocolEntry<P, M>>>
+where
+    P: FromStr,
+{
+    check_protocol_names::<P, M>(&entries)?;
+
+    Ok(entries
+        .into_iter()
+        .filter(|entry| entry.pool_id.is_none())
+        .filter_map(|entry| Some((entry.address, into_protocol_entry(entry)?)))
+        .collect())
+}
+
+/// the pool id keyed counterpart of [`resolve_registry`], only resolves the
+/// entries that have a pool id.
+pub fn resolve_pool_registry<P, M>(
+    entries: Vec<RegistryEntry<M>>,
+) -> eyre::Result<HashMap<B256, ProtocolEntry<P, M>>>
+where
+    P: FromStr,
+{
+    check_protocol_names::<P, M>(&entries)?;
+
+    Ok(entries
+        .into_iter()
+        .filter_map(|entry| Some((entry.pool_id?, into_protocol_entry(entry)?)))
+        .collect())
+}
+
+fn into_protocol_entry<P: FromStr, M>(entry: RegistryEntry<M>) -> Option<ProtocolEntry<P, M>> {
+    Some(ProtocolEntry {
+        protocol: P::from_str(&entry.protocol).ok()?,
+        tokens: entry.tokens,
+        deployment_block: entry.deployment_block,
+        metadata: entry.metadata,
+    })
+}
+
+/// errors with every protocol name in `entries` that `P` can't parse
+pub fn check_protocol_names<P, M>(entries: &[RegistryEntry<M>]) -> eyre::Result<()>
+where
+    P: FromStr,
+{
+    let mut unknown: BTreeMap<&str, usize> = BTreeMap::new();
+    for entry in entries {
+        if P::from_str(&entry.protocol).is_err() {
+            *unknown.entry(&entry.protocol).or_default() += 1;
+        }
+    }
+
+    if unknown.is_empty() {
+        return Ok(());
+    }
+
+    let names = unknown
+        .into_iter()
+        .map(|(name, count)| format!("{name} ({count} entries)"))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    Err(eyre::eyre!(
+        "registry contains unknown protocol names: {names}"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{address, b256};
+    use serde_json::json;
+
+    use super::*;
+    use crate::context::test_utils::{TempPath, TestProtocol};
+
+    const PAIR: Address = address!("0000000000000000000000000000000000000001");
+    const TOKEN_A: Address = address!("0000000000000000000000000000000000000002");
+    const TOKEN_B: Address = address!("0000000000000000000000000000000000000003");
+    const MANAGER: Address = address!("0000000000000000000000000000000000000004");
+    const POOL_ID: B256 = b256!("0000000000000000000000000000000000000000000000000000000000000005");
+
+    fn assert_entries(entries: &[RegistryEntry<serde_json::Value>]) {
+        assert_eq!(entries.len(), 2);
+
+        assert_eq!(entries[0].address, PAIR);
+        assert_eq!(entries[0].protocol, "UniswapV2");
+        assert_eq!(entries[0].tokens, vec![TOKEN_B, TOKEN_A]);
+        assert_eq!(entries[0].deployment_block, Some(10));
+        assert_eq!(entries[0].metadata, json!({ "fee": 30 }));
+        assert_eq!(entries[0].pool_id, None);
+
+        assert_eq!(entries[1].address, MANAGER);
+        assert_eq!(entries[1].protocol, "UniswapV4");
+        assert!(entries[1].tokens.is_empty());
+        assert_eq!(entries[1].deployment_block, None);
+        assert_eq!(entries[1].metadata, serde_json::Value::Null);
+        assert_eq!(entries[1].pool_id, Some(POOL_ID));
+    }
+
+    #[test]
+    fn loads_json_registries() {
+        let entries = json!([
+            {
+                "address": PAIR,
+                "protocol": "UniswapV2",
+                "tokens": [TOKEN_B, TOKEN_A],
+                "deployment_block": 10,
+                "metadata": { "fee": 30 }
+            },
+            { "address": MANAGER, "protocol": "UniswapV4", "pool_id": POOL_ID }
+        ]);
+
+        let path = TempPath::with_contents("json", &entries.to_string());
+        assert_entries(&load_registry(&path).unwrap());
+
+        let path = TempPath::with_contents("json", &json!({ "protocols": entries }).to_string());
+        assert_entries(&load_registry(&path).unwrap());
+    }
+
+    #[test]
+    fn loads_toml_registries() {
+        let path = TempPath::with_contents(
+            "toml",
+            &format!(
+                r#"
+                [[protocols]]
+                address = "{PAIR}"
+                protocol = "UniswapV2"
+                tokens = ["{TOKEN_B}", "{TOKEN_A}"]
+                deployment_block = 10
+                metadata = {{ fee = 30 }}
+
+                [[protocols]]
+                address = "{MANAGER}"
+                protocol = "UniswapV4"
+                pool_id = "{POOL_ID}"
+                "#
+            ),
+        );
+
+        assert_entries(&load_registry(&path).unwrap());
+    }
+
+    #[test]
+    fn loads_csv_registries() {
+        let path = TempPath::with_contents(
+            "csv",
+            &format!(
+                "address,protocol,tokens,deployment_block,metadata,pool_id\n\
+                 {PAIR},UniswapV2,{TOKEN_B}; {TOKEN_A},10,\"{{\"\"fee\"\": 30}}\",\n\
+                 {MANAGER},UniswapV4,,,,{POOL_ID}\n"
+            ),
+        );
+
+        assert_entries(&load_registry(&path).unwrap());
+    }
+
+    #[test]
+    fn rejects_unsupported_formats() {
+        let path = TempPath::with_contents("yaml", "");
+        assert!(load_registry::<()>(&path).is_err());
+    }
+
+    #[test]
+    fn resolves_address_and_pool_entries_separately() {
+        let path = TempPath::with_contents(
+            "json",
+            &json!([
+                { "address": PAIR, "protocol": "UniswapV2", "tokens": [TOKEN_A] },
+                { "address": PAIR, "protocol": "UniswapV2", "tokens": [TOKEN_B] },
+                { "address": MANAGER, "protocol": "UniswapV4", "pool_id": POOL_ID }
+            ])
+            .to_string(),
+        );
+        let entries = load_registry::<()>(&path).unwrap();
+
+        let protocols = resolve_registry::<TestProtocol, ()>(entries.clone()).unwrap();
+        assert_eq!(protocols.len(), 1);
+        // the last entry for an address wins
+        assert_eq!(protocols[&PAIR].tokens, vec![TOKEN_B]);
+
+        let pools = resolve_pool_registry::<TestProtocol, ()>(entries).unwrap();
+        assert_eq!(pools.len(), 1);
+        assert_eq!(pools[&POOL_ID].protocol, TestProtocol::UniswapV4);
+    }
+
+    #[test]
+    fn reports_every_unknown_protocol_name() {
+        let path = TempPath::with_contents(
+            "json",
+            &json!([
+                { "address": PAIR, "protocol": "SushiSwap" },
+                { "address": TOKEN_A, "protocol": "SushiSwap" },
+                { "address": TOKEN_B, "protocol": "Curve" },
+                { "address": MANAGER, "protocol": "UniswapV2" }
+            ])
+            .to_string(),
+        );
+        let entries = load_registry::<()>(&path).unwrap();
+
+        let error = resolve_registry::<TestProtocol, ()>(entries)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("Curve (1 entries)"), "{error}");
+        assert!(error.contains("SushiSwap (2 entries)"), "{error}");
+    }
+}
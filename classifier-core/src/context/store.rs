@@ -0,0 +1,506 @@
+use std::{
+    collections::HashMap,
+    path::Path,
+    str::FromStr,
+    sync::{Arc, RwLock},
+};
+
+use alloy_primitives::{Address, B256};
+use redb::{Database, ReadableTable, ReadableTableMetadata, TableDefinition};
+use serde::{Serialize, de::DeserializeOwned};
+
+use super::{
+    DataContext, NotRegistered, TokenInfo, TokenList,
+    registry::{ProtocolEntry, RegistryEntry, check_protocol_names, load_registry},
+};
+
+/// address, or pool id for pools inside a singleton -> encoded
+/// [`ProtocolEntry`]
+const PROTOCOLS: TableDefinition<&[u8], &[u8]> = TableDefinition::new("protocols");
+/// protocol id -> protocol name, so entries only store a single byte
+const PROTOCOL_NAMES: TableDefinition<u8, &str> = TableDefinition::new("protocol_names");
+
+/// `deployment_block` value used to encode `None`
+const UNKNOWN_BLOCK: u64 = u64::MAX;
+/// protocol id, token count, deployment block
+const HEADER_LEN: usize = 1 + 1 + 8;
+
+/// A [`DataContext`] backed by an embedded [redb](https://docs.rs/redb)
+/// database, for registries that are too large to rebuild in memory on every
+/// start.
+///
+/// Entries are stored as
+/// `[protocol id][token count][deployment block][tokens..][json metadata]`
+/// where the protocol id indexes a small name table that is resolved into `P`
+/// when the store is opened. Lookups each run in their own read transaction so
+/// the context can be shared freely between classification threads.
+pub struct RedbDataContext<P, M = ()> {
+    db: Database,
+    /// protocol id -> (name, protocol)
+    protocols: RwLock<Arc<Vec<(String, P)>>>,
+    tokens: TokenList,
+    _metadata: std::marker::PhantomData<fn() -> M>,
+}
+
+impl<P, M> RedbDataContext<P, M>
+where
+    P: FromStr + Clone,
+    M: Serialize + DeserializeOwned + Default,
+{
+    /// opens the store at `path`, creating it if it doesn't exist yet. fails if
+    /// the store references protocol names that `P` doesn't know.
+    pub fn open(path: impl AsRef<Path>) -> eyre::Result<Self> {
+        let db = Database::create(path)?;
+
+        let tx = db.begin_write()?;
+        tx.open_table(PROTOCOLS)?;
+        tx.open_table(PROTOCOL_NAMES)?;
+        tx.commit()?;
+
+        let this = Self {
+            db,
+            protocols: RwLock::new(Arc::new(Vec::new())),
+            tokens: TokenList::default(),
+            _metadata: std::marker::PhantomData,
+        };
+        this.load_protocol_names()?;
+
+        Ok(this)
+    }
+
+    /// token metadata returned from `get_token_info`
+    pub fn with_token_list(mut self, tokens: TokenList) -> Self {
+        self.tokens = tokens;
+        self
+    }
+
+    /// loads a registry file in any of the formats supported by
+    /// [`load_registry`] and imports it.
+    pub fn import_file(&self, path: impl AsRef<Path>) -> eyre::Result<usize> {
+        self.import(load_registry(path)?)
+    }
+
+    /// writes all entries in a single transaction, overwriting existing
+    /// addresses. nothing is written if any protocol name is unknown to `P`.
+    pub fn import(&self, entries: Vec<RegistryEntry<M>>) -> eyre::Result<usize> {
+        check_protocol_names::<P, M>(&entries)?;
+
+        let mut ids = self
+            .protocols
+            .read()
+            .unwrap()
+            .iter()
+            .enumerate()
+            .map(|(id, (name, _))| (name.clone(), id as u8))
+            .collect::<HashMap<_, _>>();
+
+        let tx = self.db.begin_write()?;
+        {
+            let mut names = tx.open_table(PROTOCOL_NAMES)?;
+            let mut table = tx.open_table(PROTOCOLS)?;
+
+            for entry in &entries {
+                let id = match ids.get(&entry.protocol) {
+                    Some(id) => *id,
+                    None => {
+                        let id = u8::try_from(ids.len())
+                            .map_err(|_| eyre::eyre!("store supports at most 256 protocols"))?;
+                        names.insert(id, entry.protocol.as_str())?;
+                        ids.insert(entry.protocol.clone(), id);
+                        id
+                    }
+                };
+
+                let key = match &entry.pool_id {
+                    Some(pool_id) => pool_id.as_slice(),
+                    None => entry.address.as_slice(),
+                };
+                table.insert(key, encode_entry(id, entry)?.as_slice())?;
+            }
+        }
+        tx.commit()?;
+
+        self.load_protocol_names()?;
+
+        Ok(entries.len())
+    }
+
+    pub fn remove(&self, target_address: Address) -> eyre::Result<bool> {
+        let tx = self.db.begin_write()?;
+        let removed = tx
+            .open_table(PROTOCOLS)?
+            .remove(target_address.as_slice())?
+            .is_some();
+        tx.commit()?;
+
+        Ok(removed)
+    }
+
+    pub fn get_entry(&self, target_address: Address) -> eyre::Result<Option<ProtocolEntry<P, M>>> {
+        let tx = self.db.begin_read()?;
+        let table = tx.open_table(PROTOCOLS)?;
+        let Some(value) = table.get(target_address.as_slice())? else {
+            return Ok(None);
+        };
+
+        let raw = value.value();
+        check_entry(target_address, raw)?;
+        self.decode_entry(raw).map(Some)
+    }
+
+    pub fn get_pool_entry(&self, pool_id: B256) -> eyre::Result<Option<ProtocolEntry<P, M>>> {
+        let tx = self.db.begin_read()?;
+        let table = tx.open_table(PROTOCOLS)?;
+        let Some(value) = table.get(pool_id.as_slice())? else {
+            return Ok(None);
+        };
+
+        let raw = value.value();
+        check_entry(pool_id, raw)?;
+        self.decode_entry(raw).map(Some)
+    }
+
+    pub fn len(&self) -> eyre::Result<u64> {
+        Ok(self.db.begin_read()?.open_table(PROTOCOLS)?.len()?)
+    }
+
+    pub fn is_empty(&self) -> eyre::Result<bool> {
+        Ok(self.db.begin_read()?.open_table(PROTOCOLS)?.is_empty()?)
+    }
+
+    fn load_protocol_names(&self) -> eyre::Result<()> {
+        let tx = self.db.begin_read()?;
+        let table = tx.open_table(PROTOCOL_NAMES)?;
+
+        let mut protocols = Vec::new();
+        let mut unknown = Vec::new();
+        for row in table.iter()? {
+            let (id, name) = row?;
+            let (id, name) = (id.value(), name.value().to_string());
+            if id as usize != protocols.len() {
+                return Err(eyre::eyre!("protocol name table is corrupted at id {id}"));
+            }
+
+            match P::from_str(&name) {
+                Ok(protocol) => protocols.push((name, protocol)),
+                Err(_) => unknown.push(name),
+            }
+        }
+
+        if !unknown.is_empty() {
+            return Err(eyre::eyre!(
+                "store contains unknown protocol names: {}",
+                unknown.join(", ")
+            ));
+        }
+
+        *self.protocols.write().unwrap() = Arc::new(protocols);
+
+        Ok(())
+    }
+
+    /// `key` is either an address or a pool id
+    fn with_raw_entry<K: AsRef<[u8]> + std::fmt::Debug + Copy + Into<NotRegistered>, R>(
+        &self,
+        key: K,
+        f: impl FnOnce(&[u8]) -> eyre::Result<R>,
+    ) -> eyre::Result<R> {
+        let tx = self.db.begin_read()?;
+        let table = tx.open_table(PROTOCOLS)?;
+        let value = table
+            .get(key.as_ref())?
+            .ok_or_else(|| eyre::Report::new(key.into()))?;
+
+        let raw = value.value();
+        check_entry(key, raw)?;
+        f(raw)
+    }
+
+    fn decode_protocol(&self, raw: &[u8]) -> eyre::Result<P> {
+        self.protocols
+            .read()
+            .unwrap()
+            .get(raw[0] as usize)
+            .map(|(_, protocol)| protocol.clone())
+            .ok_or(eyre::eyre!("unknown protocol id {}", raw[0]))
+    }
+
+    fn decode_entry(&self, raw: &[u8]) -> eyre::Result<ProtocolEntry<P, M>> {
+        let deployment_block = u64::from_be_bytes(raw[2..HEADER_LEN].try_into()?);
+
+        Ok(ProtocolEntry {
+            protocol: self.decode_protocol(raw)?,
+            tokens: decode_tokens(raw),
+            deployment_block: (deployment_block != UNKNOWN_BLOCK).then_some(deployment_block),
+            metadata: decode_metadata(raw)?,
+        })
+    }
+}
+
+impl<P, M> DataContext<P, M> for RedbDataContext<P, M>
+where
+    P: FromStr + Clone,
+    M: Serialize + DeserializeOwned + Default,
+{
+    fn get_protocol(&self, target_address: Address) -> eyre::Result<P> {
+        self.with_raw_entry(target_address, |raw| self.decode_protocol(raw))
+    }
+
+    fn get_protocol_tokens_sorted(&self, target_address: Address) -> eyre::Result<Vec<Address>> {
+        self.with_raw_entry(target_address, |raw| Ok(decode_sorted_tokens(raw)))
+    }
+
+    fn get_protocol_tokens(&self, target_address: Address) -> eyre::Result<Vec<Address>> {
+        self.with_raw_entry(target_address, |raw| Ok(decode_tokens(raw)))
+    }
+
+    fn get_metadata(&self, target_address: Address) -> eyre::Result<M> {
+        self.with_raw_entry(target_address, decode_metadata)
+    }
+
+    fn get_pool_tokens_sorted(&self, pool_id: B256) -> eyre::Result<Vec<Address>> {
+        self.with_raw_entry(pool_id, |raw| Ok(decode_sorted_tokens(raw)))
+    }
+
+    fn get_pool_metadata(&self, pool_id: B256) -> eyre::Result<M> {
+        self.with_raw_entry(pool_id, decode_metadata)
+    }
+
+    fn get_token_info(&self, token: Address) -> eyre::Result<TokenInfo> {
+        self.tokens.get_token_info(token)
+    }
+}
+
+fn encode_entry<M: Serialize>(protocol_id: u8, entry: &RegistryEntry<M>) -> eyre::Result<Vec<u8>> {
+    let token_count = u8::try_from(entry.tokens.len())
+        .map_err(|_| eyre::eyre!("{:?} has more than 255 tokens", entry.address))?;
+
+    let metadata = serde_json::to_vec(&entry.metadata)?;
+    // `()` and `None` metadata don't need to take up any space
+    let metadata = if metadata == b"null" {
+        &[][..]
+    } else {
+        &metadata[..]
+    };
+
+    let mut buf = Vec::with_capacity(HEADER_LEN + entry.tokens.len() * 20 + metadata.len());
+    buf.push(protocol_id);
+    buf.push(token_count);
+    buf.extend_from_slice(
+        &entry
+            .deployment_block
+            .unwrap_or(UNKNOWN_BLOCK)
+            .to_be_bytes(),
+    );
+    for token in &entry.tokens {
+        buf.extend_from_slice(token.as_slice());
+    }
+    buf.extend_from_slice(metadata);
+
+    Ok(buf)
+}
+
+fn check_entry(key: impl std::fmt::Debug, raw: &[u8]) -> eyre::Result<()> {
+    if raw.len() < HEADER_LEN || raw.len() < HEADER_LEN + raw[1] as usize * 20 {
+        return Err(eyre::eyre!("corrupted store entry for {key:?}"));
+    }
+
+    Ok(())
+}
+
+fn decode_tokens(raw: &[u8]) -> Vec<Address> {
+    raw[HEADER_LEN..HEADER_LEN + raw[1] as usize * 20]
+        .chunks_exact(20)
+        .map(Address::from_slice)
+        .collect()
+}
+
+fn decode_sorted_tokens(raw: &[u8]) -> Vec<Address> {
+    let mut tokens = decode_tokens(raw);
+    tokens.sort_unstable();
+    tokens
+}
+
+fn decode_metadata<M: DeserializeOwned + Default>(raw: &[u8]) -> eyre::Result<M> {
+    let metadata = &raw[HEADER_LEN + raw[1] as usize * 20..];
+    if metadata.is_empty() {
+        return Ok(M::default());
+    }
+
+    Ok(serde_json::from_slice(metadata)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{address, b256};
+    use serde::Deserialize;
+
+    use super::*;
+    use crate::context::{
+        is_not_registered,
+        test_utils::{TempPath, TestProtocol},
+    };
+
+    const PAIR: Address = address!("0000000000000000000000000000000000000001");
+    const TOKEN_A: Address = address!("0000000000000000000000000000000000000002");
+    const TOKEN_B: Address = address!("0000000000000000000000000000000000000003");
+    const MANAGER: Address = address!("0000000000000000000000000000000000000004");
+    const POOL_ID: B256 = b256!("0000000000000000000000000000000000000000000000000000000000000005");
+
+    #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+    struct Fee {
+        fee: u32,
+    }
+
+    fn entry(
+        address: Address,
+        protocol: &str,
+        tokens: Vec<Address>,
+        metadata: Option<Fee>,
+    ) -> RegistryEntry<Option<Fee>> {
+        RegistryEntry {
+            address,
+            protocol: protocol.to_string(),
+            tokens,
+            deployment_block: None,
+            metadata,
+            pool_id: None,
+        }
+    }
+
+    #[test]
+    fn round_trips_entries() {
+        let path = TempPath::new("redb");
+        let store = RedbDataContext::<TestProtocol, Option<Fee>>::open(&path).unwrap();
+
+        let pair = RegistryEntry {
+            deployment_block: Some(10),
+            ..entry(
+                PAIR,
+                "UniswapV2",
+                vec![TOKEN_B, TOKEN_A],
+                Some(Fee { fee: 30 }),
+            )
+        };
+        let pool = RegistryEntry {
+            pool_id: Some(POOL_ID),
+            ..entry(MANAGER, "UniswapV4", vec![TOKEN_A, TOKEN_B], None)
+        };
+        assert_eq!(store.import(vec![pair, pool]).unwrap(), 2);
+        assert_eq!(store.len().unwrap(), 2);
+
+        assert_eq!(
+            store.get_entry(PAIR).unwrap(),
+            Some(ProtocolEntry {
+                protocol: TestProtocol::UniswapV2,
+                tokens: vec![TOKEN_B, TOKEN_A],
+                deployment_block: Some(10),
+                metadata: Some(Fee { fee: 30 }),
+            })
+        );
+        assert_eq!(
+            store.get_protocol_tokens_sorted(PAIR).unwrap(),
+            vec![TOKEN_A, TOKEN_B]
+        );
+        assert_eq!(
+            store.get_protocol_tokens(PAIR).unwrap(),
+            vec![TOKEN_B, TOKEN_A]
+        );
+
+        let pool = store.get_pool_entry(POOL_ID).unwrap().unwrap();
+        assert_eq!(pool.protocol, TestProtocol::UniswapV4);
+        assert_eq!(pool.deployment_block, None);
+        assert_eq!(pool.metadata, None);
+        assert_eq!(store.get_pool_metadata(POOL_ID).unwrap(), None);
+
+        // pool entries are only reachable through their id
+        assert_eq!(store.get_entry(MANAGER).unwrap(), None);
+        assert!(is_not_registered(&store.get_protocol(MANAGER).unwrap_err()));
+        assert!(is_not_registered(
+            &store.get_pool_metadata(B256::ZERO).unwrap_err()
+        ));
+    }
+
+    #[test]
+    fn persists_entries_and_protocol_names() {
+        let path = TempPath::new("redb");
+        {
+            let store = RedbDataContext::<TestProtocol, Option<Fee>>::open(&path).unwrap();
+            store
+                .import(vec![
+                    entry(TOKEN_A, "UniswapV4", vec![], None),
+                    entry(PAIR, "UniswapV2", vec![TOKEN_A], None),
+                ])
+                .unwrap();
+        }
+
+        let store = RedbDataContext::<TestProtocol, Option<Fee>>::open(&path).unwrap();
+        assert_eq!(
+            store.get_protocol(TOKEN_A).unwrap(),
+            TestProtocol::UniswapV4
+        );
+        assert_eq!(store.get_protocol(PAIR).unwrap(), TestProtocol::UniswapV2);
+
+        // names that are already stored keep their id
+        store
+            .import(vec![entry(TOKEN_B, "UniswapV2", vec![], None)])
+            .unwrap();
+        assert_eq!(
+            store.get_protocol(TOKEN_B).unwrap(),
+            TestProtocol::UniswapV2
+        );
+        assert_eq!(
+            store.get_protocol(TOKEN_A).unwrap(),
+            TestProtocol::UniswapV4
+        );
+    }
+
+    #[test]
+    fn overwrites_and_removes_entries() {
+        let path = TempPath::new("redb");
+        let store = RedbDataContext::<TestProtocol, Option<Fee>>::open(&path).unwrap();
+
+        store
+            .import(vec![entry(PAIR, "UniswapV2", vec![TOKEN_A], None)])
+            .unwrap();
+        store
+            .import(vec![entry(
+                PAIR,
+                "UniswapV2",
+                vec![TOKEN_B],
+                Some(Fee { fee: 5 }),
+            )])
+            .unwrap();
+        assert_eq!(store.len().unwrap(), 1);
+        assert_eq!(store.get_protocol_tokens(PAIR).unwrap(), vec![TOKEN_B]);
+        assert_eq!(store.get_metadata(PAIR).unwrap(), Some(Fee { fee: 5 }));
+
+        assert!(store.remove(PAIR).unwrap());
+        assert!(!store.remove(PAIR).unwrap());
+        assert!(store.is_empty().unwrap());
+    }
+
+    #[test]
+    fn imports_nothing_with_unknown_protocol_names() {
+        let path = TempPath::new("redb");
+        let store = RedbDataContext::<TestProtocol, Option<Fee>>::open(&path).unwrap();
+
+        assert!(
+            store
+                .import(vec![
+                    entry(PAIR, "UniswapV2", vec![], None),
+                    entry(TOKEN_A, "SushiSwap", vec![], None),
+                ])
+                .is_err()
+        );
+        assert!(store.is_empty().unwrap());
+    }
+
+    #[test]
+    fn rejects_corrupted_entries() {
+        assert!(check_entry(PAIR, &[0, 0]).is_err());
+        // a header announcing a token that isn't there
+        assert!(check_entry(PAIR, &[0, 1, 0, 0, 0, 0, 0, 0, 0, 0]).is_err());
+        assert!(check_entry(PAIR, &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0]).is_ok());
+    }
+}
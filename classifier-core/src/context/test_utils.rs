@@ -0,0 +1,60 @@
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TestProtocol {
+    UniswapV2,
+    UniswapV4,
+}
+
+impl FromStr for TestProtocol {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "UniswapV2" => Ok(Self::UniswapV2),
+            "UniswapV4" => Ok(Self::UniswapV4),
+            _ => Err(eyre::eyre!("unknown protocol {s}")),
+        }
+    }
+}
+
+/// a uniquely named path in the temp dir that is removed again on drop
+pub struct TempPath(PathBuf);
+
+impl TempPath {
+    pub fn new(extension: &str) -> Self {
+        static NEXT: AtomicUsize = AtomicUsize::new(0);
+
+        Self(std::env::temp_dir().join(format!(
+            "brontes-classifier-{}-{}.{extension}",
+            std::process::id(),
+            NEXT.fetch_add(1, Ordering::Relaxed)
+        )))
+    }
+
+    pub fn with_contents(extension: &str, contents: &str) -> Self {
+        let path = Self::new(extension);
+        path.write(contents);
+        path
+    }
+
+    pub fn write(&self, contents: &str) {
+        std::fs::write(&self.0, contents).unwrap();
+    }
+}
+
+impl AsRef<Path> for TempPath {
+    fn as_ref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempPath {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
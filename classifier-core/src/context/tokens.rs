@@ -0,0 +1,110 @@
+use std::{collections::HashMap, fs::File, io::BufReader, path::Path};
+
+use alloy_primitives::{Address, U256, utils::format_units};
+use serde::Deserialize;
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct TokenInfo {
+    pub address: Address,
+    pub decimals: u8,
+    pub symbol: String,
+    #[serde(default)]
+    pub name: String,
+}
+
+impl TokenInfo {
+    /// formats a raw token amount as an exact decimal string
+    pub fn format_amount(&self, amount: U256) -> eyre::Result<String> {
+        Ok(format_units(amount, self.decimals)?)
+    }
+
+    /// converts a raw token amount into a decimal normalized float. precision
+    /// is lost past the 53 bits of the mantissa
+    pub fn normalize_amount(&self, amount: U256) -> eyre::Result<f64> {
+        Ok(self.format_amount(amount)?.parse()?)
+    }
+}
+
+/// the json token lists we accept, either a bare array of tokens or the
+/// `{ "tokens": [..] }` layout used by the uniswap token list standard
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum JsonTokenList {
+    TokenList { tokens: Vec<TokenInfo> },
+    Tokens(Vec<TokenInfo>),
+}
+
+/// In memory token metadata loaded from a local token list. Meant to be held
+/// by a [`DataContext`](super::DataContext) implementation and returned from
+/// `get_token_info`.
+#[derive(Debug, Clone, Default)]
+pub struct TokenList {
+    tokens: HashMap<Address, TokenInfo>,
+}
+
+impl TokenList {
+    pub fn new(tokens: impl IntoIterator<Item = TokenInfo>) -> Self {
+        Self {
+            tokens: tokens
+                .into_iter()
+                .map(|token| (token.address, token))
+                .collect(),
+        }
+    }
+
+    /// loads the token list, picking the format from the file extension
+    pub fn from_file(path: impl AsRef<Path>) -> eyre::Result<Self> {
+        let path = path.as_ref();
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => Self::from_json_file(path),
+            Some("csv") => Self::from_csv_file(path),
+            _ => Err(eyre::eyre!(
+                "unsupported token list format for {}, expected .json or .csv",
+                path.display()
+            )),
+        }
+    }
+
+    pub fn from_json_file(path: impl AsRef<Path>) -> eyre::Result<Self> {
+        let reader = BufReader::new(File::open(path)?);
+        let tokens = match serde_json::from_reader(reader)? {
+            JsonTokenList::TokenList { tokens } => tokens,
+            JsonTokenList::Tokens(tokens) => tokens,
+        };
+
+        Ok(Self::new(tokens))
+    }
+
+    /// expects a header row of `address,decimals,symbol,name`, the name column
+    /// is optional
+    pub fn from_csv_file(path: impl AsRef<Path>) -> eyre::Result<Self> {
+        let tokens = csv::Reader::from_path(path)?
+            .deserialize()
+            .collect::<Result<Vec<TokenInfo>, _>>()?;
+
+        Ok(Self::new(tokens))
+    }
+
+    pub fn insert(&mut self, token: TokenInfo) -> Option<TokenInfo> {
+        self.tokens.insert(token.address, token)
+    }
+
+    pub fn get(&self, token: &Address) -> Option<&TokenInfo> {
+        self.tokens.get(token)
+    }
+
+    pub fn get_token_info(&self, token: Address) -> eyre::Result<TokenInfo> {
+        self.tokens
+            .get(&token)
+            .cloned()
+            .ok_or(eyre::eyre!("token {token:?} is not in the token list"))
+    }
+
+    pub fn len(&self) -> usize {
+        self.tokens.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tokens.is_empty()
+    }
+}
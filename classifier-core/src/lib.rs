@@ -3,6 +3,7 @@ pub mod context;
 pub mod types;
 
 use action::ActionCollection;
+use alloy_primitives::Address;
 use alloy_primitives::Log;
 use alloy_primitives::TxHash;
 use alloy_rpc_types_trace::parity::Action;
@@ -12,19 +13,38 @@ use brontes_tracer::TracingClient;
 use brontes_tracer::types::TransactionTraceWithLogs;
 use brontes_tracer::types::TxTrace;
 use context::DataContext;
+use types::CallFrameInfo;
 use types::ClassifiedBlock;
 use types::ClassifiedTrace;
 use types::ClassifiedTx;
+use types::collect_call_targets;
 use types::collect_delegated_traces;
+use types::is_proxy_forward;
+use types::proxy_implementation;
 
 #[allow(async_fn_in_trait)]
 pub trait TraceClassifier<A: ActionCollection> {
-    type DataProvider: DataContext<A::ProtocolContext>;
+    type DataProvider: DataContext<A::ProtocolContext, A::ProtocolMetadata>;
 
     fn data_provider(&self) -> &Self::DataProvider;
 
     fn eth_provider(&self) -> &TracingClient;
 
+    /// called by `classify_block` with every non-static call target of the
+    /// block before any classification runs. registries behind a database or
+    /// remote service can resolve the whole set in one batched async query and
+    /// seed the synchronous data provider with it (e.g. through
+    /// `CachedDataContext::insert_protocol` / `insert_unknown`), so `dispatch`
+    /// never has to block on a lookup. a failing prefetch is logged and the
+    /// block is classified without it.
+    async fn prefetch_protocols(
+        &self,
+        _block_number: u64,
+        _addresses: &[Address],
+    ) -> eyre::Result<()> {
+        Ok(())
+    }
+
     async fn classify_block(
         &self,
         block_number: u64,
@@ -40,6 +60,16 @@ pub trait TraceClassifier<A: ActionCollection> {
             });
         };
 
+        let call_targets = collect_call_targets(&tx_traces);
+        // the prefetch only warms the provider, dispatch still resolves anything it
+        // missed on its own
+        if let Err(e) = self.prefetch_protocols(block_number, &call_targets).await {
+            tracing::warn!(error=%e, block_number, "failed to prefetch the block's protocols");
+        }
+        if let Err(e) = self.data_provider().prefetch(&call_targets) {
+            tracing::warn!(error=%e, block_number, "data provider failed to prefetch the block");
+        }
+
         let transactions = tx_traces
             .into_iter()
             .enumerate()
@@ -102,7 +132,17 @@ pub trait TraceClassifier<A: ActionCollection> {
             return None;
         }
 
+        // a proxy forwarding its call to the implementation is classified through the
+        // proxy's own frame.
+        if is_proxy_forward(&trace, full_trace) {
+            return None;
+        }
+
         let mut call_info = trace.get_callframe_info();
+        let implementation_address = proxy_implementation(&trace, full_trace);
+        let mut child_logs = Vec::new();
+        let mut child_trace_indices = Vec::new();
+        let mut static_call_targets = Vec::new();
         // Add logs of delegated calls to the root trace, only if the delegated call is
         // from the same address / in the same call frame.
         if let Action::Call(root_call) = &trace.trace.action {
@@ -114,17 +154,31 @@ pub trait TraceClassifier<A: ActionCollection> {
             );
 
             for delegated_trace in delegated_traces {
-                if let Action::Call(delegated_call) = &delegated_trace.trace.action {
-                    if let CallType::DelegateCall = delegated_call.call_type {
-                        if delegated_call.from == root_call.to {
-                            let logs_internal = delegated_trace.logs.iter().collect::<Vec<&Log>>();
-                            call_info.delegate_logs.extend(logs_internal);
-                        }
+                let Action::Call(delegated_call) = &delegated_trace.trace.action else {
+                    continue;
+                };
+                if delegated_call.call_type != CallType::DelegateCall
+                    || delegated_call.from != root_call.to
+                {
+                    child_logs.extend(delegated_trace.logs.iter());
+                    if !delegated_trace.is_static_call() {
+                        child_trace_indices.push(delegated_trace.trace_idx);
+                    } else {
+                        static_call_targets.push(delegated_call.to);
                     }
+                    continue;
                 }
+
+                let logs_internal = delegated_trace.logs.iter().collect::<Vec<&Log>>();
+                call_info.delegate_logs.extend(logs_internal);
             }
         }
 
+        let mut call_info = CallFrameInfo::new(call_info, implementation_address);
+        call_info.child_logs = child_logs;
+        call_info.child_trace_indices = child_trace_indices;
+        call_info.static_call_targets = static_call_targets;
+
         A::default().dispatch(
             call_info,
             self.data_provider(),
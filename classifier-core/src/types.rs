@@ -1,7 +1,100 @@
-use alloy_primitives::{Address, TxHash};
+use std::{
+    collections::HashSet,
+    ops::{Deref, DerefMut},
+};
 
-use brontes_tracer::types::TransactionTraceWithLogs;
-pub use brontes_tracer::types::{CallFrameInfo, CallInfo};
+use alloy_primitives::{Address, Log, TxHash};
+use alloy_rpc_types_trace::parity::{Action, CallType};
+
+use brontes_tracer::types::{TransactionTraceWithLogs, TxTrace};
+
+/// The call frame handed to `dispatch`, the tracer's frame plus the
+/// implementation address when the target is a proxy that forwards the call
+/// through a delegate call.
+#[derive(Debug)]
+pub struct CallFrameInfo<'a> {
+    pub frame: brontes_tracer::types::CallFrameInfo<'a>,
+    pub implementation_address: Option<Address>,
+    /// the address the protocol was resolved by. this is the target address
+    /// unless the target isn't registered and dispatch fell back to the
+    /// implementation address.
+    pub protocol_address: Address,
+    /// logs emitted by the calls this frame made, in trace order. delegate
+    /// calls running in the frame's own context are in `delegate_logs`
+    /// instead.
+    pub child_logs: Vec<&'a Log>,
+    /// trace indices of the non-static calls this frame made, in trace order.
+    /// delegate calls running in the frame's own context aren't included.
+    pub child_trace_indices: Vec<u64>,
+    /// targets of the static calls this frame made, in trace order, e.g. the
+    /// tokens it read a balance of
+    pub static_call_targets: Vec<Address>,
+}
+
+impl<'a> CallFrameInfo<'a> {
+    pub fn new(
+        frame: brontes_tracer::types::CallFrameInfo<'a>,
+        implementation_address: Option<Address>,
+    ) -> Self {
+        Self {
+            protocol_address: frame.target_address,
+            frame,
+            implementation_address,
+            child_logs: Vec::new(),
+            child_trace_indices: Vec::new(),
+            static_call_targets: Vec::new(),
+        }
+    }
+
+    pub fn get_fixed_fields(&self) -> CallInfo {
+        CallInfo {
+            call: self.frame.get_fixed_fields(),
+            implementation_address: self.implementation_address,
+            protocol_address: self.protocol_address,
+            child_trace_indices: self.child_trace_indices.clone(),
+            static_call_targets: self.static_call_targets.clone(),
+        }
+    }
+}
+
+impl<'a> Deref for CallFrameInfo<'a> {
+    type Target = brontes_tracer::types::CallFrameInfo<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.frame
+    }
+}
+
+impl DerefMut for CallFrameInfo<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.frame
+    }
+}
+
+/// The fixed call fields passed to classifiers. `target_address` is always the
+/// called address, for proxies `implementation_address` is the contract the
+/// call was delegated to. registry lookups should go through
+/// `protocol_address`, the one of the two the protocol was resolved by.
+#[derive(Debug, Clone)]
+pub struct CallInfo {
+    pub call: brontes_tracer::types::CallInfo,
+    pub implementation_address: Option<Address>,
+    pub protocol_address: Address,
+    /// trace indices of the calls made below this one, e.g. the pool swaps of
+    /// a router call
+    pub child_trace_indices: Vec<u64>,
+    /// targets of the static calls made below this one, e.g. the token a
+    /// singleton read its balance of
+    pub static_call_targets: Vec<Address>,
+}
+
+impl Deref for CallInfo {
+    type Target = brontes_tracer::types::CallInfo;
+
+    fn deref(&self) -> &Self::Target {
+        &self.call
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct ClassifiedBlock<A> {
@@ -38,3 +131,234 @@ pub fn collect_delegated_traces<'a>(
         }
     }
 }
+
+/// whether `trace` is a delegate call that forwards its parent's calldata
+/// unchanged, i.e. a proxy handing the call to its implementation. a delegate
+/// call runs as its caller, so a forward made by an implementation that is a
+/// proxy itself comes from the address the parent ran as
+pub fn is_proxy_forward(
+    trace: &TransactionTraceWithLogs,
+    full_trace: &[TransactionTraceWithLogs],
+) -> bool {
+    let Action::Call(call) = &trace.trace.action else {
+        return false;
+    };
+    let Some((_, parent_address)) = trace.trace.trace_address.split_last() else {
+        return false;
+    };
+    if call.call_type != CallType::DelegateCall {
+        return false;
+    }
+
+    full_trace
+        .iter()
+        .find(|parent| parent.trace.trace_address == parent_address)
+        .is_some_and(|parent| match &parent.trace.action {
+            Action::Call(parent_call) => {
+                let parent_context = if parent_call.call_type == CallType::DelegateCall {
+                    parent_call.from
+                } else {
+                    parent_call.to
+                };
+                parent_context == call.from && parent_call.input == call.input
+            }
+            _ => false,
+        })
+}
+
+/// the implementation a proxy forwards `trace` to, the target of its first
+/// direct delegate call forwarding the calldata. delegate calls into libraries
+/// aren't, and forwards through several proxies resolve to the innermost one
+pub fn proxy_implementation(
+    trace: &TransactionTraceWithLogs,
+    full_trace: &[TransactionTraceWithLogs],
+) -> Option<Address> {
+    let trace_address = &trace.trace.trace_address;
+    let forward = full_trace.iter().find(|subtrace| {
+        let subtrace_address = &subtrace.trace.trace_address;
+        subtrace_address.starts_with(trace_address)
+            && subtrace_address.len() == trace_address.len() + 1
+            && is_proxy_forward(subtrace, full_trace)
+    })?;
+
+    match &forward.trace.action {
+        Action::Call(call) => proxy_implementation(forward, full_trace).or(Some(call.to)),
+        _ => None,
+    }
+}
+
+/// the deduplicated targets of every non-static call in the given
+/// transactions, sorted by address. delegate calls are included, their target
+/// being the implementation the caller forwards to
+pub fn collect_call_targets(tx_traces: &[TxTrace]) -> Vec<Address> {
+    let targets = tx_traces
+        .iter()
+        .flat_map(|tx_trace| tx_trace.trace.iter())
+        .filter_map(|trace| match &trace.trace.action {
+            Action::Call(call) if call.call_type != CallType::StaticCall => Some(call.to),
+            _ => None,
+        })
+        .collect::<HashSet<_>>();
+
+    let mut targets = targets.into_iter().collect::<Vec<_>>();
+    targets.sort_unstable();
+    targets
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{Bytes, U256, address};
+    use alloy_rpc_types_trace::parity::{CallAction, TransactionTrace};
+
+    use super::*;
+
+    const USER: Address = address!("1000000000000000000000000000000000000001");
+    const PROXY: Address = address!("2000000000000000000000000000000000000002");
+    const IMPLEMENTATION: Address = address!("3000000000000000000000000000000000000003");
+    const LIBRARY: Address = address!("4000000000000000000000000000000000000004");
+    const INNER_IMPLEMENTATION: Address = address!("5000000000000000000000000000000000000005");
+
+    fn calldata() -> Bytes {
+        Bytes::from_static(&[0xa9, 0x05, 0x9c, 0xbb, 1, 2, 3])
+    }
+
+    fn library_calldata() -> Bytes {
+        Bytes::from_static(&[0x12, 0x34, 0x56, 0x78])
+    }
+
+    fn call(
+        trace_address: &[usize],
+        call_type: CallType,
+        from: Address,
+        to: Address,
+        input: Bytes,
+    ) -> TransactionTraceWithLogs {
+        TransactionTraceWithLogs {
+            trace: TransactionTrace {
+                action: Action::Call(CallAction {
+                    from,
+                    call_type,
+                    gas: 0,
+                    input,
+                    to,
+                    value: U256::ZERO,
+                }),
+                error: None,
+                result: None,
+                subtraces: 0,
+                trace_address: trace_address.to_vec(),
+            },
+            logs: Vec::new(),
+            msg_sender: from,
+            trace_idx: 0,
+        }
+    }
+
+    #[test]
+    fn resolves_the_implementation_a_proxy_forwards_to() {
+        let trace = vec![
+            call(&[], CallType::Call, USER, PROXY, calldata()),
+            call(
+                &[0],
+                CallType::DelegateCall,
+                PROXY,
+                IMPLEMENTATION,
+                calldata(),
+            ),
+        ];
+
+        assert!(!is_proxy_forward(&trace[0], &trace));
+        assert!(is_proxy_forward(&trace[1], &trace));
+        assert_eq!(
+            proxy_implementation(&trace[0], &trace),
+            Some(IMPLEMENTATION)
+        );
+    }
+
+    #[test]
+    fn skips_library_delegate_calls() {
+        let trace = vec![
+            call(&[], CallType::Call, USER, PROXY, calldata()),
+            call(
+                &[0],
+                CallType::DelegateCall,
+                PROXY,
+                LIBRARY,
+                library_calldata(),
+            ),
+            call(
+                &[1],
+                CallType::DelegateCall,
+                PROXY,
+                IMPLEMENTATION,
+                calldata(),
+            ),
+        ];
+
+        assert!(!is_proxy_forward(&trace[1], &trace));
+        assert_eq!(
+            proxy_implementation(&trace[0], &trace),
+            Some(IMPLEMENTATION)
+        );
+
+        // a contract that only calls into a library isn't a proxy
+        assert_eq!(proxy_implementation(&trace[0], &trace[..2]), None);
+    }
+
+    #[test]
+    fn skips_delegate_calls_made_by_a_sub_call() {
+        // the call to the implementation is made by a regular sub call of the
+        // proxy, not the proxy's own frame
+        let trace = vec![
+            call(&[], CallType::Call, USER, PROXY, calldata()),
+            call(&[0], CallType::Call, PROXY, LIBRARY, calldata()),
+            call(
+                &[0, 0],
+                CallType::DelegateCall,
+                LIBRARY,
+                IMPLEMENTATION,
+                calldata(),
+            ),
+        ];
+
+        assert!(!is_proxy_forward(&trace[1], &trace));
+        assert_eq!(proxy_implementation(&trace[0], &trace), None);
+    }
+
+    #[test]
+    fn follows_nested_forwards_to_the_innermost_implementation() {
+        // the implementation is a proxy itself, its forward runs as the outer
+        // proxy. the library call further down isn't a forward.
+        let trace = vec![
+            call(&[], CallType::Call, USER, PROXY, calldata()),
+            call(
+                &[0],
+                CallType::DelegateCall,
+                PROXY,
+                IMPLEMENTATION,
+                calldata(),
+            ),
+            call(
+                &[0, 0],
+                CallType::DelegateCall,
+                PROXY,
+                INNER_IMPLEMENTATION,
+                calldata(),
+            ),
+            call(
+                &[0, 0, 0],
+                CallType::DelegateCall,
+                PROXY,
+                LIBRARY,
+                library_calldata(),
+            ),
+        ];
+
+        assert!(is_proxy_forward(&trace[2], &trace));
+        assert!(!is_proxy_forward(&trace[3], &trace));
+        assert_eq!(
+            proxy_implementation(&trace[0], &trace),
+            Some(INNER_IMPLEMENTATION)
+        );
+    }
+}
@@ -1,16 +1,24 @@
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
-use syn::{Ident, Index, Token, parenthesized, parse::Parse};
+use syn::{Ident, Index, Token, Type, parenthesized, parse::Parse};
 
-use super::ACTION_SIG_NAME;
+use super::{ACTION_SIG_NAME, action_impl::parse_protocol_metadata};
 
 #[derive(Debug)]
 pub struct ActionDispatch {
     // required for all
     struct_name: Ident,
     protocol_enum: Ident,
+    protocol_metadata: Type,
     output_type: Ident,
     rest: Vec<Ident>,
+    /// classifiers matched on the selector alone when the call target isn't a
+    /// registered protocol or none of the registered classifiers match, in the
+    /// order they're tried
+    fallback: Vec<Ident>,
+    /// classifiers of calls without calldata that send value to a registered
+    /// protocol, matched on the protocol alone
+    receive: Vec<Ident>,
 }
 
 impl ActionDispatch {
@@ -18,11 +26,14 @@ impl ActionDispatch {
         let Self {
             struct_name,
             protocol_enum,
+            protocol_metadata,
             output_type,
             rest,
+            fallback,
+            receive,
         } = self;
 
-        if rest.is_empty() {
+        if rest.is_empty() && fallback.is_empty() && receive.is_empty() {
             // Generate a compile_error! invocation as part of the output TokenStream
             return Err(syn::Error::new(
                 Span::call_site(),
@@ -40,13 +51,54 @@ impl ActionDispatch {
             })
             .unzip();
 
+        let fallback_var_name = fallback
+            .iter()
+            .enumerate()
+            .map(|(i, ident)| Ident::new(&format!("FALLBACK_{i}"), ident.span()))
+            .collect::<Vec<_>>();
+        let fallback_const_fns = fallback
+            .iter()
+            .map(|ident| Ident::new(&format!("{ACTION_SIG_NAME}_{}", ident), ident.span()))
+            .collect::<Vec<_>>();
+
+        let receive_var_name = receive
+            .iter()
+            .enumerate()
+            .map(|(i, ident)| Ident::new(&format!("RECEIVE_{i}"), ident.span()))
+            .collect::<Vec<_>>();
+        let receive_const_fns = receive
+            .iter()
+            .map(|ident| Ident::new(&format!("{ACTION_SIG_NAME}_{}", ident), ident.span()))
+            .collect::<Vec<_>>();
+
         let (i, name): (Vec<Index>, Vec<&Ident>) = rest
             .iter()
+            .chain(fallback.iter())
+            .chain(receive.iter())
             .enumerate()
             .map(|(i, n)| (Index::from(i), n))
             .unzip();
 
-        let match_stmt = expand_match_dispatch(&rest, &var_name, i);
+        let (i, rest_i) = i.split_at(rest.len());
+        let (fallback_i, receive_i) = rest_i.split_at(fallback.len());
+        let match_stmt = expand_match_dispatch(&rest, &var_name, i.to_vec());
+        let fallback_stmt =
+            expand_fallback_dispatch(&fallback, &fallback_var_name, fallback_i.to_vec());
+        let receive_stmt = expand_receive_dispatch(&receive, &receive_var_name, receive_i.to_vec());
+        let selector_check = if receive.is_empty() {
+            quote!(if call_info.call_data.len() < 4 {
+                return None;
+            })
+        } else {
+            quote!(
+                // plain value transfers run the target's receive function
+                let is_receive = call_info.call_data.is_empty()
+                    && !call_info.msg_value.is_zero();
+                if call_info.call_data.len() < 4 && !is_receive {
+                    return None
+                }
+            )
+        };
 
         let o = quote!(
 
@@ -62,10 +114,14 @@ impl ActionDispatch {
             impl ::brontes_classifier::action::ActionCollection for #struct_name {
                 type DispatchOut = #output_type;
                 type ProtocolContext = #protocol_enum;
+                type ProtocolMetadata = #protocol_metadata;
 
-                fn dispatch<DB: brontes_classifier::context::DataContext<#protocol_enum>>(
+                fn dispatch<DB: brontes_classifier::context::DataContext<
+                    #protocol_enum,
+                    #protocol_metadata
+                >>(
                     &self,
-                    call_info: ::brontes_classifier::types::CallFrameInfo<'_>,
+                    mut call_info: ::brontes_classifier::types::CallFrameInfo<'_>,
                     data_ctx: &DB,
                     block: u64,
                     tx_hash: alloy_primitives::TxHash,
@@ -73,13 +129,42 @@ impl ActionDispatch {
                 ) -> Option<#output_type> {
 
 
-                    let protocol_fetched: #protocol_enum =
-                        ::brontes_classifier::context::DataContext::get_protocol(data_ctx, call_info.target_address).ok()?;
-                    let protocol_byte = protocol_fetched.to_byte();
+                    #selector_check
 
-                    if call_info.call_data.len() < 4 {
-                        return None
-                    }
+                    #(
+                        const #var_name: [u8; 5] = #const_fns();
+                    )*
+                    #(
+                        const #fallback_var_name: [u8; 5] = #fallback_const_fns();
+                    )*
+                    #(
+                        const #receive_var_name: [u8; 5] = #receive_const_fns();
+                    )*
+
+                    // resolve by the called address first and fall back to the
+                    // implementation for proxies that delegate the call
+                    let protocol_fetched: Option<#protocol_enum> =
+                        match ::brontes_classifier::context::DataContext::get_protocol(
+                            data_ctx,
+                            call_info.target_address
+                        ) {
+                            Ok(protocol) => Some(protocol),
+                            Err(_) => match call_info.implementation_address.map(|implementation| (
+                                implementation,
+                                ::brontes_classifier::context::DataContext::get_protocol(
+                                    data_ctx,
+                                    implementation
+                                ),
+                            )) {
+                                Some((implementation, Ok(protocol))) => {
+                                    call_info.protocol_address = implementation;
+                                    Some(protocol)
+                                }
+                                _ => None,
+                            },
+                        };
+
+                    #receive_stmt
 
                     let hex_selector = ::alloy_primitives::Bytes::copy_from_slice(
                         &call_info.call_data[0..4]);
@@ -87,17 +172,17 @@ impl ActionDispatch {
                     let sig = ::alloy_primitives::FixedBytes::<4>::from_slice(
                         &call_info.call_data[0..4]).0;
 
-                    let mut sig_w_byte= [0u8; 5];
-                    sig_w_byte[0..4].copy_from_slice(&sig);
-                    sig_w_byte[4] = protocol_byte;
-
+                    if let Some(protocol_fetched) = protocol_fetched {
+                        let mut sig_w_byte= [0u8; 5];
+                        sig_w_byte[0..4].copy_from_slice(&sig);
+                        sig_w_byte[4] = protocol_fetched.to_byte();
 
-                    #(
-                        const #var_name: [u8; 5] = #const_fns();
-                    )*;
+                        #match_stmt
+                    }
 
-                    #match_stmt
+                    #fallback_stmt
 
+                    None
                 }
             }
         );
@@ -114,16 +199,42 @@ impl Parse for ActionDispatch {
         let struct_name: Ident = paren_input.parse()?;
         paren_input.parse::<Token![,]>()?;
         let protocol_enum: Ident = paren_input.parse()?;
+        let protocol_metadata = parse_protocol_metadata(&paren_input)?;
 
         input.parse::<Token![=]>()?;
         input.parse::<Token![>]>()?;
 
         let output_type: Ident = input.parse()?;
-        input.parse::<Token![|]>()?;
 
-        let mut rest = vec![input.parse::<Ident>()?];
-        while input.parse::<Token![,]>().is_ok() {
+        // collections made up of fallback classifiers only can skip the list
+        let mut rest = Vec::new();
+        if input.parse::<Token![|]>().is_ok() {
             rest.push(input.parse::<Ident>()?);
+            while input.parse::<Token![,]>().is_ok() {
+                rest.push(input.parse::<Ident>()?);
+            }
+        }
+
+        let mut fallback = Vec::new();
+        let mut receive = Vec::new();
+        while input.parse::<Token![;]>().is_ok() {
+            let keyword: Ident = input.parse()?;
+            let section = match keyword.to_string().as_str() {
+                "fallback" if fallback.is_empty() => &mut fallback,
+                "receive" if receive.is_empty() => &mut receive,
+                _ => {
+                    return Err(syn::Error::new(
+                        keyword.span(),
+                        "expected a single `fallback:` and `receive:` section each",
+                    ));
+                }
+            };
+            input.parse::<Token![:]>()?;
+
+            section.push(input.parse::<Ident>()?);
+            while input.parse::<Token![,]>().is_ok() {
+                section.push(input.parse::<Ident>()?);
+            }
         }
 
         if !input.is_empty() {
@@ -136,8 +247,11 @@ impl Parse for ActionDispatch {
         Ok(Self {
             rest,
             protocol_enum,
+            protocol_metadata,
             output_type,
             struct_name,
+            fallback,
+            receive,
         })
     }
 }
@@ -152,9 +266,9 @@ fn expand_match_dispatch(
         #(
             #var_name => {
                 let target_address = call_info.target_address;
-                ::brontes_classifier::action::IntoAction::decode_call_trace(
+                return ::brontes_classifier::action::IntoAction::decode_call_trace(
                         &self.#var_idx,
-                        call_info,
+                        &call_info,
                         block,
                         tx_idx,
                         data_ctx
@@ -177,9 +291,72 @@ fn expand_match_dispatch(
                 hex_selector,
                 target_address.0,
             );
+            }
+        }
+    )
+}
+
+fn expand_fallback_dispatch(
+    reg_name: &[Ident],
+    var_name: &[Ident],
+    var_idx: Vec<Index>,
+) -> TokenStream {
+    quote!(
+        #(
+            // fallbacks sharing a selector are tried in order until one decodes
+            if sig[..] == #var_name[0..4] {
+                match ::brontes_classifier::action::IntoAction::decode_call_trace(
+                        &self.#var_idx,
+                        &call_info,
+                        block,
+                        tx_idx,
+                        data_ctx
+                    ) {
+                    Ok(action) => return Some(action),
+                    Err(e) => ::tracing::debug!(error=%e, ?tx_hash,
+                        "fallback classifier: {} failed on function sig: {:?} for address: {:?}",
+                        stringify!(#reg_name),
+                        hex_selector,
+                        call_info.target_address.0,
+                    ),
+                }
+            }
+        )*
+    )
+}
+
+fn expand_receive_dispatch(
+    reg_name: &[Ident],
+    var_name: &[Ident],
+    var_idx: Vec<Index>,
+) -> TokenStream {
+    if reg_name.is_empty() {
+        return quote!();
+    }
 
-                None
+    quote!(
+        if is_receive {
+            let protocol_fetched = protocol_fetched?;
+            #(
+            if protocol_fetched.to_byte() == #var_name[4] {
+                let target_address = call_info.target_address;
+                return ::brontes_classifier::action::IntoAction::decode_call_trace(
+                        &self.#var_idx,
+                        &call_info,
+                        block,
+                        tx_idx,
+                        data_ctx
+                    ).inspect_err(|e| {
+                        ::tracing::warn!(error=%e, ?tx_hash,
+                            "receive classifier: {} failed for address: {:?}",
+                            stringify!(#reg_name),
+                            target_address.0,
+                        );
+                    }).ok()
             }
+            )*
+
+            return None
         }
     )
 }
@@ -1,8 +1,9 @@
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use syn::{
-    Error, ExprClosure, Ident, LitBool, Path, Token, bracketed, parenthesized,
+    Error, ExprClosure, Ident, LitBool, Path, Token, Type, bracketed, parenthesized,
     parse::Parse,
+    parse_quote,
     spanned::Spanned,
     token::{Paren, Star},
 };
@@ -12,6 +13,9 @@ use super::{ACTION_SIG_NAME, data_preparation::CallDataParsing, logs::LogConfig}
 pub struct ActionMacro {
     output_type: Ident,
     protocol_enum: Ident,
+    /// the metadata type the data context resolves for this collection,
+    /// defaults to `()` when not specified
+    protocol_metadata: Type,
     // required for all
     protocol_path: Path,
     path_to_call: Path,
@@ -26,6 +30,8 @@ pub struct ActionMacro {
     give_call_data: bool,
     // whether we pass down logs from delegate call in the same call frame
     include_delegated_logs: bool,
+    /// whether we pass down the logs of the calls made by the call frame
+    include_child_logs: bool,
     /// The closure that we use to construct the normalized type
     call_function: ExprClosure,
 }
@@ -35,6 +41,7 @@ impl ActionMacro {
         let Self {
             output_type,
             protocol_enum,
+            protocol_metadata,
             exchange_name_w_call,
             protocol_path,
             action_type,
@@ -43,6 +50,7 @@ impl ActionMacro {
             give_logs,
             give_call_data,
             include_delegated_logs,
+            include_child_logs,
             give_returns,
             call_function,
         } = self;
@@ -52,6 +60,7 @@ impl ActionMacro {
             give_call_data,
             give_returns,
             include_delegated_logs,
+            include_child_logs,
             &exchange_name_w_call,
             &action_type,
             &path_to_call,
@@ -75,7 +84,13 @@ impl ActionMacro {
         call.value_mut().ident = Ident::new(&solidity, call.span());
         return_import.segments.push(call.into_value());
 
-        let combined_output = quote! { #output_type::#action_type(result) };
+        // naming the output enum itself as the action type lets a classifier pick
+        // the variant, e.g. when one call can add or remove liquidity
+        let combined_output = if action_type == output_type {
+            quote! { result }
+        } else {
+            quote! { #output_type::#action_type(result) }
+        };
 
         Ok(quote!(
             #[allow(unused_imports)]
@@ -101,10 +116,14 @@ impl ActionMacro {
             impl ::brontes_classifier::action::IntoAction for #exchange_name_w_call {
                 type DecodeOut = #output_type;
                 type ProtocolContext = #protocol_enum;
+                type ProtocolMetadata = #protocol_metadata;
 
-                fn decode_call_trace<DB: ::brontes_classifier::context::DataContext<#protocol_enum>>(
+                fn decode_call_trace<DB: ::brontes_classifier::context::DataContext<
+                    #protocol_enum,
+                    #protocol_metadata
+                >>(
                     &self,
-                    call_info: ::brontes_classifier::types::CallFrameInfo<'_>,
+                    call_info: &::brontes_classifier::types::CallFrameInfo<'_>,
                     block: u64,
                     tx_idx: u64,
                     db_ctx: &DB
@@ -124,6 +143,7 @@ impl Parse for ActionMacro {
         let protocol_enum = content.parse()?;
         content.parse::<Token![,]>()?;
         let output_type = content.parse()?;
+        let protocol_metadata = parse_protocol_metadata(&content)?;
 
         input.parse::<Token![,]>()?;
         let protocol_path = parse_protocol_path(&mut input)?;
@@ -138,10 +158,11 @@ impl Parse for ActionMacro {
         let possible_logs = parse_logs(&mut input)?;
         input.parse::<Token![,]>()?;
 
-        let (logs, return_data, call_data, include_delegated_logs) = parse_config(&mut input)?;
+        let (logs, return_data, call_data, include_delegated_logs, include_child_logs) =
+            parse_config(&mut input)?;
         let call_function = parse_closure(&mut input)?;
 
-        let uppercase_path_to_call = uppercase_first_char(
+        let camel_case_call = camel_case_call_name(
             &path_to_call.segments[path_to_call.segments.len() - 1]
                 .ident
                 .to_string(),
@@ -151,7 +172,7 @@ impl Parse for ActionMacro {
             &format!(
                 "{}{}",
                 protocol_path.segments[protocol_path.segments.len() - 1].ident,
-                uppercase_path_to_call
+                camel_case_call
             ),
             Span::call_site(),
         );
@@ -159,6 +180,7 @@ impl Parse for ActionMacro {
         Ok(Self {
             output_type,
             protocol_enum,
+            protocol_metadata,
             path_to_call,
             give_returns: return_data,
             log_types: possible_logs,
@@ -166,6 +188,7 @@ impl Parse for ActionMacro {
             give_logs: logs,
             give_call_data: call_data,
             include_delegated_logs,
+            include_child_logs,
             action_type,
             protocol_path,
             exchange_name_w_call,
@@ -173,6 +196,26 @@ impl Parse for ActionMacro {
     }
 }
 
+/// parses the optional trailing metadata type of the `(Protocol, Output,
+/// Metadata)` header, falling back to `()`
+pub fn parse_protocol_metadata(input: syn::parse::ParseStream) -> syn::Result<Type> {
+    if input.is_empty() {
+        return Ok(parse_quote!(()));
+    }
+
+    input.parse::<Token![,]>()?;
+    let protocol_metadata: Type = input.parse()?;
+
+    if !input.is_empty() {
+        return Err(syn::Error::new(
+            input.span(),
+            "unexpected input after the metadata type",
+        ));
+    }
+
+    Ok(protocol_metadata)
+}
+
 fn parse_closure(input: &mut syn::parse::ParseStream) -> syn::Result<ExprClosure> {
     let call_function: ExprClosure = input.parse()?;
     if call_function.asyncness.is_some() {
@@ -200,11 +243,14 @@ fn parse_closure(input: &mut syn::parse::ParseStream) -> syn::Result<ExprClosure
     Ok(call_function)
 }
 
-fn parse_config(input: &mut syn::parse::ParseStream) -> syn::Result<(bool, bool, bool, bool)> {
+fn parse_config(
+    input: &mut syn::parse::ParseStream,
+) -> syn::Result<(bool, bool, bool, bool, bool)> {
     let mut logs = false;
     let mut return_data = false;
     let mut call_data = false;
     let mut include_delegated_logs = false;
+    let mut include_child_logs = false;
 
     while !input.peek(Token![|]) {
         let arg: Ident = input.parse()?;
@@ -216,12 +262,13 @@ fn parse_config(input: &mut syn::parse::ParseStream) -> syn::Result<(bool, bool,
             "call_data" => call_data = enabled.value(),
             "return_data" => return_data = enabled.value(),
             "include_delegated_logs" => include_delegated_logs = enabled.value(),
+            "include_child_logs" => include_child_logs = enabled.value(),
             _ => {
                 return Err(Error::new(
                     arg.span(),
                     format!(
                         "{} is not a valid config option, valid options are: \n logs , call_data, \
-                         return_data , include_delegated_logs",
+                         return_data , include_delegated_logs, include_child_logs",
                         arg,
                     ),
                 ));
@@ -230,7 +277,13 @@ fn parse_config(input: &mut syn::parse::ParseStream) -> syn::Result<(bool, bool,
         input.parse::<Token![,]>()?;
     }
 
-    Ok((logs, return_data, call_data, include_delegated_logs))
+    Ok((
+        logs,
+        return_data,
+        call_data,
+        include_delegated_logs,
+        include_child_logs,
+    ))
 }
 
 pub fn parse_protocol_path(input: &mut syn::parse::ParseStream) -> syn::Result<Path> {
@@ -334,10 +387,16 @@ fn parse_logs(input: &mut syn::parse::ParseStream) -> syn::Result<Vec<LogConfig>
     Ok(log_types)
 }
 
-fn uppercase_first_char(s: &str) -> String {
-    let mut c = s.chars();
-    match c.next() {
-        None => String::new(),
-        Some(f) => f.to_uppercase().collect::<String>() + c.as_str(),
-    }
+/// upper camel cases the call name, snake cased solidity names such as
+/// `exchange_underlying` (or its overloads `exchange_underlying_0`) included
+fn camel_case_call_name(s: &str) -> String {
+    s.split('_')
+        .map(|part| {
+            let mut c = part.chars();
+            match c.next() {
+                None => String::new(),
+                Some(f) => f.to_uppercase().collect::<String>() + c.as_str(),
+            }
+        })
+        .collect()
 }
@@ -22,6 +22,7 @@ impl<'a> CallDataParsing<'a> {
         call_data: bool,
         return_data: bool,
         include_delegated_logs: bool,
+        include_child_logs: bool,
         exchange_name: &'a Ident,
         action_type: &'a Ident,
         fn_call_path: &'a Path,
@@ -37,6 +38,7 @@ impl<'a> CallDataParsing<'a> {
                 fn_call_path,
                 log_config,
                 include_delegated_logs,
+                include_child_logs,
             ))
         } else {
             None
@@ -26,6 +26,7 @@ pub struct LogData<'a> {
     mod_path: Path,
     log_config: &'a [LogConfig],
     include_delegated_logs: bool,
+    include_child_logs: bool,
 }
 
 impl<'a> LogData<'a> {
@@ -35,6 +36,7 @@ impl<'a> LogData<'a> {
         fn_call_path: &'a Path,
         log_config: &'a [LogConfig],
         include_delegated_logs: bool,
+        include_child_logs: bool,
     ) -> Self {
         let mut mod_path = fn_call_path.clone();
         mod_path.segments.pop().unwrap();
@@ -46,6 +48,7 @@ impl<'a> LogData<'a> {
             log_config,
             mod_path,
             include_delegated_logs,
+            include_child_logs,
         }
     }
 
@@ -145,9 +148,26 @@ impl<'a> LogData<'a> {
             })
             .collect::<Vec<_>>();
 
+        // a log missing is only worth a warning when none of its fallbacks were
+        // decoded either
+        let (group_found, group_checks): (Vec<_>, Vec<_>) = log_ident
+            .iter()
+            .enumerate()
+            .map(|(i, names)| {
+                let found = Ident::new(&format!("log_group_{i}_found"), Span::call_site());
+                let fields = names
+                    .iter()
+                    .map(|name| Ident::new(&(name.to_string() + "_field"), Span::call_site()))
+                    .collect::<Vec<_>>();
+
+                (found, quote!(#(self.[<#fields:snake>].is_some())||*))
+            })
+            .unzip();
+
         let return_struct_build_fields = log_ident
             .iter()
-            .flat_map(|names| {
+            .zip(group_found.iter())
+            .flat_map(|(names, found)| {
                 names
                     .iter()
                     .map(|name| {
@@ -159,7 +179,9 @@ impl<'a> LogData<'a> {
                         );
 
                         quote!([<#field:snake>]: self.[<#field:snake>].ok_or_else(|| {
-                                ::tracing::warn!(?call_info, "{}", #message);
+                                if !#found {
+                                    ::tracing::warn!(?call_info, "{}", #message);
+                                }
                                 ::eyre::eyre!("call_info: {:?}, {}",call_info, #message)
                         }))
                     })
@@ -186,27 +208,58 @@ impl<'a> LogData<'a> {
 
         let log_field = log_field.iter().flatten().collect::<Vec<_>>();
 
+        // the address of the contract that emitted each decoded log
+        let log_emitter = log_ident
+            .iter()
+            .flat_map(|names| emitter_idents(names))
+            .collect::<Vec<_>>();
+        let log_emitter_ty = log_repeating
+            .iter()
+            .zip(log_ident.iter())
+            .flat_map(|(repeating, names)| {
+                names
+                    .iter()
+                    .map(|_| {
+                        if *repeating {
+                            quote!(Vec<::alloy_primitives::Address>)
+                        } else {
+                            quote!(::alloy_primitives::Address)
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
         (
             quote!(
                 ::paste::paste!(
                     #[allow(non_camel_case_types)]
                     struct [<#log_return_builder_struct_name:camel>] {
                         #(
-                            [<#log_field:snake>]: Option<#log_field_ty>
-                        ),*
+                            [<#log_field:snake>]: Option<#log_field_ty>,
+                        )*
+                        #(
+                            [<#log_emitter:snake>]: Option<#log_emitter_ty>,
+                        )*
                     }
 
-                    #[allow(non_camel_case_types)]
+                    #[allow(non_camel_case_types, dead_code)]
                     struct #log_return_struct_name {
-                        #(#res_struct_fields),*
+                        #(#res_struct_fields,)*
+                        #(
+                            [<#log_emitter:snake>]: ::eyre::Result<#log_emitter_ty>,
+                        )*
                     }
 
                     impl [<#log_return_builder_struct_name:camel>] {
                         fn new() -> Self {
                             Self {
                                 #(
-                                    [<#log_field:snake>]: None
-                                ),*
+                                    [<#log_field:snake>]: None,
+                                )*
+                                #(
+                                    [<#log_emitter:snake>]: None,
+                                )*
                             }
                         }
 
@@ -215,10 +268,20 @@ impl<'a> LogData<'a> {
                             call_info: &::brontes_classifier::types::CallFrameInfo<'_>
                             )
                             -> #log_return_struct_name {
+                                #(
+                                    let #group_found = #group_checks;
+                                )*
                                 #log_return_struct_name {
                                 #(
-                                    #return_struct_build_fields
-                                ),*
+                                    #return_struct_build_fields,
+                                )*
+                                #(
+                                    [<#log_emitter:snake>]: self.[<#log_emitter:snake>]
+                                        .ok_or_else(|| ::eyre::eyre!(
+                                            "log {} was not found",
+                                            stringify!(#log_emitter)
+                                        )),
+                                )*
                             }
                         }
                     }
@@ -240,12 +303,25 @@ impl<'a> LogData<'a> {
 
         let mut stream = TokenStream::new();
 
-        if self.include_delegated_logs {
+        if self.include_delegated_logs || self.include_child_logs {
+            let delegate_logs = self
+                .include_delegated_logs
+                .then(|| quote!(merged_logs.extend(call_info.delegate_logs.iter());));
+            // logs of the frame itself come first, followed by the ones of the calls it made
+            // in trace order
+            let child_logs = self
+                .include_child_logs
+                .then(|| quote!(merged_logs.extend(call_info.child_logs.iter());));
+
             stream.extend(quote!(
-                 let mut merged_logs: Vec<&alloy_primitives::Log> =
-                     Vec::with_capacity(call_info.logs.len() + call_info.delegate_logs.len());
+                 let mut merged_logs: Vec<&alloy_primitives::Log> = Vec::with_capacity(
+                     call_info.logs.len()
+                        + call_info.delegate_logs.len()
+                        + call_info.child_logs.len()
+                 );
                  merged_logs.extend(call_info.logs.iter());
-                 merged_logs.extend(call_info.delegate_logs.iter());
+                 #delegate_logs
+                 #child_logs
 
                  let logs = merged_logs.as_slice();
             ));
@@ -281,10 +357,12 @@ impl<'a> LogData<'a> {
                         indexes,
                         log_field_name
                             .iter()
-                            .map(|field| {
+                            .zip(emitter_idents(log_name))
+                            .map(|(field, emitter)| {
                                 quote!(
                                     ::paste::paste!(
                                         log_res.[<#field:snake>] = Some(decoded_result);
+                                        log_res.[<#emitter:snake>] = Some(log.address);
                                     );
                                 )
                             })
@@ -346,7 +424,7 @@ impl<'a> LogData<'a> {
                 i += 1;
             }
             // move the index to where we finished
-            repeating_modifier += i - 1;
+            repeating_modifier += i.saturating_sub(1);
         )
     }
 
@@ -359,16 +437,19 @@ impl<'a> LogData<'a> {
         indexes: &Index,
     ) -> TokenStream {
         let next_log = log_names.get(enum_i + 1);
+        let log_emitter = emitter_idents(log_name);
         let parse = self.parse_ignore_before(
             next_log,
             log_name,
             indexes,
             log_field_name
                 .iter()
-                .map(|field| {
+                .zip(log_emitter.iter())
+                .map(|(field, emitter)| {
                     quote!(
                         ::paste::paste!(
                             [<#field:snake _res>].push(decoded_result);
+                            [<#emitter:snake _res>].push(log.address);
                         );
                     )
                 })
@@ -378,6 +459,7 @@ impl<'a> LogData<'a> {
             #(
                 ::paste::paste!(
                     let mut [<#log_field_name:snake _res>] = Vec::new();
+                    let mut [<#log_emitter:snake _res>] = Vec::new();
                 );
             )*
 
@@ -385,6 +467,7 @@ impl<'a> LogData<'a> {
             #(
                 ::paste::paste!(
                     log_res.[<#log_field_name:snake>] = Some([<#log_field_name:snake _res>]);
+                    log_res.[<#log_emitter:snake>] = Some([<#log_emitter:snake _res>]);
                 );
             )*
         )
@@ -397,10 +480,12 @@ impl<'a> LogData<'a> {
         indexes: &Index,
     ) -> TokenStream {
         let mod_path = &self.mod_path;
+        let log_emitter = emitter_idents(log_name);
         quote!(
             #(
                 ::paste::paste!(
                     let mut [<#log_field_name:snake _res>] = Vec::new();
+                    let mut [<#log_emitter:snake _res>] = Vec::new();
                 );
             )*
 
@@ -420,6 +505,7 @@ impl<'a> LogData<'a> {
                                     any_parsed = true;
                                     ::paste::paste!(
                                         [<#log_field_name:snake _res>].push(decoded);
+                                        [<#log_emitter:snake _res>].push(log.address);
                                     );
                             }
                         )*
@@ -440,6 +526,7 @@ impl<'a> LogData<'a> {
                     ::paste::paste!(
                         repeating_modifier + = [<#log_field_name:snake _res>].len();
                         log_res.[<#log_field_name:snake>] = Some([<#log_field_name:snake _res>]);
+                        log_res.[<#log_emitter:snake>] = Some([<#log_emitter:snake _res>]);
                     );
                 )*
         )
@@ -452,6 +539,7 @@ impl<'a> LogData<'a> {
         indexes: &Index,
     ) -> TokenStream {
         let mod_path = &self.mod_path;
+        let log_emitter = emitter_idents(log_name);
         quote!(
         'possible: {
                 if let Some(log) = &logs.get(#indexes + repeating_modifier) {
@@ -461,6 +549,7 @@ impl<'a> LogData<'a> {
                             as ::alloy_sol_types::SolEvent>
                             ::decode_log_data(&log.data, false) {
                                 log_res.[<#log_field_name:snake>] = Some(decoded);
+                                log_res.[<#log_emitter:snake>] = Some(log.address);
                                 break 'possible
                         }
                     )*
@@ -493,7 +582,10 @@ impl ToTokens for LogData<'_> {
             self.generate_decoded_log_struct(&log_names, &log_field_names, &is_repeatings);
 
         let log_result = quote!(
-            if call_info.logs.is_empty() && call_info.delegate_logs.is_empty() {
+            if call_info.logs.is_empty()
+                && call_info.delegate_logs.is_empty()
+                && call_info.child_logs.is_empty()
+            {
                 ::tracing::error!(?call_info, "tried to decode using logs when no logs where found \
                                   for call");
             }
@@ -512,3 +604,12 @@ impl ToTokens for LogData<'_> {
         tokens.extend(log_result)
     }
 }
+
+/// `<Log>_emitter` field idents, holding the address each decoded log was
+/// emitted by
+fn emitter_idents(log_names: &[Ident]) -> Vec<Ident> {
+    log_names
+        .iter()
+        .map(|name| Ident::new(&(name.to_string() + "_emitter"), Span::call_site()))
+        .collect()
+}
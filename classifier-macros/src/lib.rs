@@ -18,9 +18,11 @@ use crate::action_classifier::{ActionDispatch, ActionMacro};
 ///     Protocol::UniswapV2,
 ///     crate::UniswapV2::swapCall,
 ///
-/// becomes: UniswapV2swapCall.
+/// becomes: UniswapV2SwapCall.
 /// This is done to avoid naming conflicts between classifiers as this is name
-/// will always be unique.
+/// will always be unique. snake cased calls are camel cased, e.g.
+/// `exchange_underlying_0Call` on `Protocol::CurveStable` becomes
+/// `CurveStableExchangeUnderlying0Call`.
 ///
 /// The Array of log types are expected to be in the order that they are emitted
 /// in. Otherwise the decoding will fail
@@ -87,6 +89,26 @@ use crate::action_classifier::{ActionDispatch, ActionMacro};
 ///  call_data: true
 ///  ````
 ///  ```|index, from_address, target_address, return_data, log_data|```
+///
+/// # Output Variant
+/// the result of the closure is wrapped in the `CallType` variant of the output
+/// enum. when a call can produce different actions, pass the output enum itself
+/// as the call type (e.g. `Actions`) and return the variant from the closure.
+///
+/// # Log Sources
+/// by default only the logs emitted by the call frame itself are decoded.
+/// `include_delegated_logs: true` adds the logs of delegate calls running in
+/// the frame's context and `include_child_logs: true` the logs of every call
+/// the frame made, e.g. the token transfers below a router. merged logs are
+/// ordered as the frame's own logs, then delegated logs, then child logs.
+///
+/// # Protocol Metadata
+/// the leading `(ProtocolEnum, OutputEnum)` tuple optionally takes a third
+/// type, `(ProtocolEnum, OutputEnum, MetadataType)`. the `db_ctx` handed to the
+/// closure is then bound by `DataContext<ProtocolEnum, MetadataType>` so
+/// `db_ctx.get_metadata(address)?` returns the typed pool information. when
+/// omitted the metadata type is `()`. it must match the metadata type given to
+/// `action_dispatch!`.
 pub fn action_impl(input: TokenStream) -> TokenStream {
     parse_macro_input!(input as ActionMacro)
         .expand()
@@ -129,6 +151,40 @@ pub fn action_impl(input: TokenStream) -> TokenStream {
 ///
 /// action_dispatch!(ClassifierDispatch, UniswapV2swapCall);
 /// ```
+///
+/// the protocol of a call is resolved by its target address, if the target
+/// isn't registered the implementation address of a delegate call proxy is
+/// tried next.
+///
+/// the optional metadata type is passed as the last element of the leading
+/// tuple, e.g. `(ClassifierDispatch, Protocol, PoolMetadata) => Actions | ..`
+///
+/// # Fallback Classifiers
+/// classifiers listed after `; fallback:` are matched on the function selector
+/// alone, for calls that should be classified on any address such as erc20
+/// transfers. they are tried when the target isn't a registered protocol or
+/// none of the registered classifiers match the call. fallbacks sharing a
+/// selector are tried in the order they're listed until one of them decodes
+/// the call, e.g. erc721 `transferFrom` before the erc20 one.
+/// ```ignore
+/// action_dispatch!(
+///     (ClassifierDispatch, Protocol) => Actions | UniswapV2SwapCall;
+///     fallback: Erc20TransferCall, Erc20TransferFromCall
+/// );
+/// ```
+///
+/// # Receive Classifiers
+/// calls without calldata that send value run the target's receive function
+/// and have no selector to dispatch on. classifiers listed after `; receive:`
+/// are matched on the protocol of the registered target alone, e.g. the weth
+/// deposit for eth sent to weth. they can't take `call_data: true`.
+/// ```ignore
+/// action_dispatch!(
+///     (ClassifierDispatch, Protocol) => Actions | WrappedNativeDepositCall;
+///     fallback: Erc20TransferCall;
+///     receive: WrappedNativeDepositCall
+/// );
+/// ```
 pub fn action_dispatch(input: TokenStream) -> TokenStream {
     parse_macro_input!(input as ActionDispatch)
         .expand()
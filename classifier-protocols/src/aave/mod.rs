@@ -0,0 +1,120 @@
+mod v2;
+mod v3;
+
+use alloy_primitives::{Address, U256};
+use brontes_classifier::types::CallInfo;
+use brontes_classifier_types::{
+    NormalizedBorrow, NormalizedDeposit, NormalizedLiquidation, NormalizedRepay, NormalizedWithdraw,
+};
+pub use v2::*;
+pub use v3::*;
+
+use crate::Protocol;
+
+// the registered address of an aave market is its pool proxy, every call is
+// delegated to the pool implementation which emits the events. the classifiers
+// read the events through `include_delegated_logs` so amounts are the ones the
+// pool settled on, e.g. a `type(uint256).max` withdraw or repay.
+
+fn aave_deposit(
+    protocol: Protocol,
+    info: &CallInfo,
+    reserve: Address,
+    on_behalf_of: Address,
+    amount: U256,
+) -> NormalizedDeposit<Protocol> {
+    // atokens are minted 1:1 with the deposit, no separate share amount
+    NormalizedDeposit {
+        protocol,
+        trace_index: info.trace_idx,
+        pool: info.target_address,
+        from: info.from_address,
+        recipient: on_behalf_of,
+        asset: reserve,
+        amount,
+        shares: None,
+    }
+}
+
+fn aave_withdraw(
+    protocol: Protocol,
+    info: &CallInfo,
+    reserve: Address,
+    to: Address,
+    amount: U256,
+) -> NormalizedWithdraw<Protocol> {
+    NormalizedWithdraw {
+        protocol,
+        trace_index: info.trace_idx,
+        pool: info.target_address,
+        from: info.from_address,
+        owner: info.from_address,
+        recipient: to,
+        asset: reserve,
+        amount,
+        shares: None,
+    }
+}
+
+fn aave_borrow(
+    protocol: Protocol,
+    info: &CallInfo,
+    reserve: Address,
+    on_behalf_of: Address,
+    amount: U256,
+) -> NormalizedBorrow<Protocol> {
+    NormalizedBorrow {
+        protocol,
+        trace_index: info.trace_idx,
+        pool: info.target_address,
+        from: info.from_address,
+        borrower: on_behalf_of,
+        recipient: info.from_address,
+        asset: reserve,
+        amount,
+    }
+}
+
+fn aave_repay(
+    protocol: Protocol,
+    info: &CallInfo,
+    reserve: Address,
+    borrower: Address,
+    amount: U256,
+) -> NormalizedRepay<Protocol> {
+    NormalizedRepay {
+        protocol,
+        trace_index: info.trace_idx,
+        pool: info.target_address,
+        from: info.from_address,
+        borrower,
+        asset: reserve,
+        amount,
+    }
+}
+
+/// both versions share the `LiquidationCall` event
+#[allow(clippy::too_many_arguments)]
+fn aave_liquidation(
+    protocol: Protocol,
+    info: &CallInfo,
+    collateral_asset: Address,
+    debt_asset: Address,
+    user: Address,
+    debt_to_cover: U256,
+    liquidated_collateral_amount: U256,
+    liquidator: Address,
+) -> NormalizedLiquidation<Protocol> {
+    NormalizedLiquidation {
+        protocol,
+        trace_index: info.trace_idx,
+        pool: info.target_address,
+        liquidator,
+        debtor: user,
+        collateral_asset,
+        debt_asset,
+        covered_debt: debt_to_cover,
+        liquidated_collateral: liquidated_collateral_amount,
+        collateral_share_token: None,
+    }
+}
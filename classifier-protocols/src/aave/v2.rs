@@ -0,0 +1,215 @@
+use alloy_primitives::U256;
+use alloy_sol_types::sol;
+use brontes_classifier::{action_impl, types::CallInfo};
+use brontes_classifier_types::NormalizedFlashLoan;
+
+use super::{aave_borrow, aave_deposit, aave_liquidation, aave_repay, aave_withdraw};
+use crate::{Actions, Protocol, ProtocolMetadata};
+
+sol! {
+    interface IAaveV2LendingPool {
+        event Deposit(
+            address indexed reserve,
+            address user,
+            address indexed onBehalfOf,
+            uint256 amount,
+            uint16 indexed referral
+        );
+        event Withdraw(
+            address indexed reserve,
+            address indexed user,
+            address indexed to,
+            uint256 amount
+        );
+        event Borrow(
+            address indexed reserve,
+            address user,
+            address indexed onBehalfOf,
+            uint256 amount,
+            uint256 borrowRateMode,
+            uint256 borrowRate,
+            uint16 indexed referral
+        );
+        event Repay(
+            address indexed reserve,
+            address indexed user,
+            address indexed repayer,
+            uint256 amount
+        );
+        event LiquidationCall(
+            address indexed collateralAsset,
+            address indexed debtAsset,
+            address indexed user,
+            uint256 debtToCover,
+            uint256 liquidatedCollateralAmount,
+            address liquidator,
+            bool receiveAToken
+        );
+        event FlashLoan(
+            address indexed target,
+            address indexed initiator,
+            address indexed asset,
+            uint256 amount,
+            uint256 premium,
+            uint16 referralCode
+        );
+
+        function deposit(
+            address asset,
+            uint256 amount,
+            address onBehalfOf,
+            uint16 referralCode
+        ) external;
+        function withdraw(address asset, uint256 amount, address to) external returns (uint256);
+        function borrow(
+            address asset,
+            uint256 amount,
+            uint256 interestRateMode,
+            uint16 referralCode,
+            address onBehalfOf
+        ) external;
+        function repay(
+            address asset,
+            uint256 amount,
+            uint256 rateMode,
+            address onBehalfOf
+        ) external returns (uint256);
+        function liquidationCall(
+            address collateralAsset,
+            address debtAsset,
+            address user,
+            uint256 debtToCover,
+            bool receiveAToken
+        ) external;
+        function flashLoan(
+            address receiverAddress,
+            address[] assets,
+            uint256[] amounts,
+            uint256[] modes,
+            address onBehalfOf,
+            bytes params,
+            uint16 referralCode
+        ) external;
+    }
+}
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::AaveV2,
+    IAaveV2LendingPool::depositCall,
+    Deposit,
+    [..Deposit],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: AaveV2DepositCallLogs, _db_ctx: &DB| {
+        let deposit = log_data.deposit_field?;
+        Ok(aave_deposit(
+            Protocol::AaveV2,
+            &info,
+            deposit.reserve,
+            deposit.onBehalfOf,
+            deposit.amount,
+        ))
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::AaveV2,
+    IAaveV2LendingPool::withdrawCall,
+    Withdraw,
+    [..Withdraw],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: AaveV2WithdrawCallLogs, _db_ctx: &DB| {
+        let withdraw = log_data.withdraw_field?;
+        Ok(aave_withdraw(Protocol::AaveV2, &info, withdraw.reserve, withdraw.to, withdraw.amount))
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::AaveV2,
+    IAaveV2LendingPool::borrowCall,
+    Borrow,
+    [..Borrow],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: AaveV2BorrowCallLogs, _db_ctx: &DB| {
+        let borrow = log_data.borrow_field?;
+        Ok(aave_borrow(Protocol::AaveV2, &info, borrow.reserve, borrow.onBehalfOf, borrow.amount))
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::AaveV2,
+    IAaveV2LendingPool::repayCall,
+    Repay,
+    [..Repay],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: AaveV2RepayCallLogs, _db_ctx: &DB| {
+        let repay = log_data.repay_field?;
+        Ok(aave_repay(Protocol::AaveV2, &info, repay.reserve, repay.user, repay.amount))
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::AaveV2,
+    IAaveV2LendingPool::liquidationCallCall,
+    Liquidation,
+    [..LiquidationCall],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: AaveV2LiquidationCallCallLogs, _db_ctx: &DB| {
+        let liquidation = log_data.liquidation_call_field?;
+        Ok(aave_liquidation(
+            Protocol::AaveV2,
+            &info,
+            liquidation.collateralAsset,
+            liquidation.debtAsset,
+            liquidation.user,
+            liquidation.debtToCover,
+            liquidation.liquidatedCollateralAmount,
+            liquidation.liquidator,
+        ))
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::AaveV2,
+    IAaveV2LendingPool::flashLoanCall,
+    FlashLoan,
+    [..FlashLoan*],
+    call_data: true,
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo,
+    call_data: flashLoanCall,
+    log_data: AaveV2FlashLoanCallLogs,
+    _db_ctx: &DB| {
+        let loans = log_data.flash_loan_field?;
+
+        // assets with a non zero mode are kept as debt instead of repaid, the
+        // event still reports a premium for them which is never paid
+        let fees = loans
+            .iter()
+            .zip(&call_data.modes)
+            .map(|(loan, mode)| if mode.is_zero() { loan.premium } else { U256::ZERO })
+            .collect();
+
+        Ok(NormalizedFlashLoan {
+            protocol: Protocol::AaveV2,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            pool: info.target_address,
+            receiver: call_data.receiverAddress,
+            assets: loans.iter().map(|loan| loan.asset).collect(),
+            amounts: loans.iter().map(|loan| loan.amount).collect(),
+            fees,
+        })
+    }
+);
@@ -0,0 +1,259 @@
+use alloy_sol_types::sol;
+use brontes_classifier::{action_impl, types::CallInfo};
+use brontes_classifier_types::NormalizedFlashLoan;
+
+use super::{aave_borrow, aave_deposit, aave_liquidation, aave_repay, aave_withdraw};
+use crate::{Actions, Protocol, ProtocolMetadata};
+
+sol! {
+    interface IAaveV3Pool {
+        event Supply(
+            address indexed reserve,
+            address user,
+            address indexed onBehalfOf,
+            uint256 amount,
+            uint16 indexed referralCode
+        );
+        event Withdraw(
+            address indexed reserve,
+            address indexed user,
+            address indexed to,
+            uint256 amount
+        );
+        event Borrow(
+            address indexed reserve,
+            address user,
+            address indexed onBehalfOf,
+            uint256 amount,
+            uint8 interestRateMode,
+            uint256 borrowRate,
+            uint16 indexed referralCode
+        );
+        event Repay(
+            address indexed reserve,
+            address indexed user,
+            address indexed repayer,
+            uint256 amount,
+            bool useATokens
+        );
+        event LiquidationCall(
+            address indexed collateralAsset,
+            address indexed debtAsset,
+            address indexed user,
+            uint256 debtToCover,
+            uint256 liquidatedCollateralAmount,
+            address liquidator,
+            bool receiveAToken
+        );
+        event FlashLoan(
+            address indexed target,
+            address initiator,
+            address indexed asset,
+            uint256 amount,
+            uint8 interestRateMode,
+            uint256 premium,
+            uint16 indexed referralCode
+        );
+
+        function supply(
+            address asset,
+            uint256 amount,
+            address onBehalfOf,
+            uint16 referralCode
+        ) external;
+        // the v2 entry point, kept by v3 pools as an alias of `supply`
+        function deposit(
+            address asset,
+            uint256 amount,
+            address onBehalfOf,
+            uint16 referralCode
+        ) external;
+        function withdraw(address asset, uint256 amount, address to) external returns (uint256);
+        function borrow(
+            address asset,
+            uint256 amount,
+            uint256 interestRateMode,
+            uint16 referralCode,
+            address onBehalfOf
+        ) external;
+        function repay(
+            address asset,
+            uint256 amount,
+            uint256 interestRateMode,
+            address onBehalfOf
+        ) external returns (uint256);
+        function liquidationCall(
+            address collateralAsset,
+            address debtAsset,
+            address user,
+            uint256 debtToCover,
+            bool receiveAToken
+        ) external;
+        function flashLoan(
+            address receiverAddress,
+            address[] assets,
+            uint256[] amounts,
+            uint256[] interestRateModes,
+            address onBehalfOf,
+            bytes params,
+            uint16 referralCode
+        ) external;
+        function flashLoanSimple(
+            address receiverAddress,
+            address asset,
+            uint256 amount,
+            bytes params,
+            uint16 referralCode
+        ) external;
+    }
+}
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::AaveV3,
+    IAaveV3Pool::supplyCall,
+    Deposit,
+    [..Supply],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: AaveV3SupplyCallLogs, _db_ctx: &DB| {
+        let supply = log_data.supply_field?;
+        Ok(aave_deposit(Protocol::AaveV3, &info, supply.reserve, supply.onBehalfOf, supply.amount))
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::AaveV3,
+    IAaveV3Pool::depositCall,
+    Deposit,
+    [..Supply],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: AaveV3DepositCallLogs, _db_ctx: &DB| {
+        let supply = log_data.supply_field?;
+        Ok(aave_deposit(Protocol::AaveV3, &info, supply.reserve, supply.onBehalfOf, supply.amount))
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::AaveV3,
+    IAaveV3Pool::withdrawCall,
+    Withdraw,
+    [..Withdraw],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: AaveV3WithdrawCallLogs, _db_ctx: &DB| {
+        let withdraw = log_data.withdraw_field?;
+        Ok(aave_withdraw(Protocol::AaveV3, &info, withdraw.reserve, withdraw.to, withdraw.amount))
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::AaveV3,
+    IAaveV3Pool::borrowCall,
+    Borrow,
+    [..Borrow],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: AaveV3BorrowCallLogs, _db_ctx: &DB| {
+        let borrow = log_data.borrow_field?;
+        Ok(aave_borrow(Protocol::AaveV3, &info, borrow.reserve, borrow.onBehalfOf, borrow.amount))
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::AaveV3,
+    IAaveV3Pool::repayCall,
+    Repay,
+    [..Repay],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: AaveV3RepayCallLogs, _db_ctx: &DB| {
+        let repay = log_data.repay_field?;
+        Ok(aave_repay(Protocol::AaveV3, &info, repay.reserve, repay.user, repay.amount))
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::AaveV3,
+    IAaveV3Pool::liquidationCallCall,
+    Liquidation,
+    [..LiquidationCall],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: AaveV3LiquidationCallCallLogs, _db_ctx: &DB| {
+        let liquidation = log_data.liquidation_call_field?;
+        Ok(aave_liquidation(
+            Protocol::AaveV3,
+            &info,
+            liquidation.collateralAsset,
+            liquidation.debtAsset,
+            liquidation.user,
+            liquidation.debtToCover,
+            liquidation.liquidatedCollateralAmount,
+            liquidation.liquidator,
+        ))
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::AaveV3,
+    IAaveV3Pool::flashLoanCall,
+    FlashLoan,
+    [..FlashLoan*],
+    call_data: true,
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo,
+    call_data: flashLoanCall,
+    log_data: AaveV3FlashLoanCallLogs,
+    _db_ctx: &DB| {
+        // v3 reports a zero premium for assets kept as debt
+        let loans = log_data.flash_loan_field?;
+
+        Ok(NormalizedFlashLoan {
+            protocol: Protocol::AaveV3,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            pool: info.target_address,
+            receiver: call_data.receiverAddress,
+            assets: loans.iter().map(|loan| loan.asset).collect(),
+            amounts: loans.iter().map(|loan| loan.amount).collect(),
+            fees: loans.iter().map(|loan| loan.premium).collect(),
+        })
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::AaveV3,
+    IAaveV3Pool::flashLoanSimpleCall,
+    FlashLoan,
+    [..FlashLoan],
+    call_data: true,
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo,
+    call_data: flashLoanSimpleCall,
+    log_data: AaveV3FlashLoanSimpleCallLogs,
+    _db_ctx: &DB| {
+        let loan = log_data.flash_loan_field?;
+
+        Ok(NormalizedFlashLoan {
+            protocol: Protocol::AaveV3,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            pool: info.target_address,
+            receiver: call_data.receiverAddress,
+            assets: vec![loan.asset],
+            amounts: vec![loan.amount],
+            fees: vec![loan.premium],
+        })
+    }
+);
@@ -0,0 +1,196 @@
+mod one_inch_v5;
+mod one_inch_v6;
+mod paraswap;
+mod universal_router;
+mod zero_x;
+
+use alloy_primitives::{Address, U256};
+use brontes_classifier::types::CallInfo;
+use brontes_classifier_types::{NATIVE_TOKEN, NormalizedAggregatorSwap, NormalizedSwap};
+pub use one_inch_v5::*;
+pub use one_inch_v6::*;
+pub use paraswap::*;
+pub use universal_router::*;
+pub use zero_x::*;
+
+use crate::Protocol;
+
+// routers wrap the pool swaps of a route in a single call. the classifiers
+// below only report the caller's net trade, the pools the route went through
+// are classified on their own traces which the action points at.
+
+#[allow(clippy::too_many_arguments)]
+fn aggregated_swap(
+    protocol: Protocol,
+    info: &CallInfo,
+    recipient: Address,
+    token_in: Address,
+    token_out: Address,
+    amount_in: U256,
+    amount_out: U256,
+) -> NormalizedAggregatorSwap<Protocol> {
+    NormalizedAggregatorSwap {
+        swap: NormalizedSwap {
+            protocol,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            recipient: or_caller(info, recipient),
+            pool: info.target_address,
+            pool_id: None,
+            token_in: router_token(token_in),
+            token_out: router_token(token_out),
+            amount_in,
+            amount_out,
+            msg_value: info.msg_value,
+        },
+        child_trace_indices: info.child_trace_indices.clone(),
+    }
+}
+
+/// routers mark eth with either the zero address or the native placeholder
+fn router_token(token: Address) -> Address {
+    if token == Address::ZERO {
+        NATIVE_TOKEN
+    } else {
+        token
+    }
+}
+
+/// an unset recipient means the caller
+fn or_caller(info: &CallInfo, recipient: Address) -> Address {
+    if recipient == Address::ZERO {
+        info.from_address
+    } else {
+        recipient
+    }
+}
+
+/// the first and last token of a packed uniswap v3 path,
+/// `token (20) | fee (3) | token (20) | ..`
+fn v3_path_ends(path: &[u8]) -> eyre::Result<(Address, Address)> {
+    if path.len() < 43 || !(path.len() - 20).is_multiple_of(23) {
+        return Err(eyre::eyre!(
+            "invalid uniswap v3 path of {} bytes",
+            path.len()
+        ));
+    }
+
+    Ok((
+        Address::from_slice(&path[..20]),
+        Address::from_slice(&path[path.len() - 20..]),
+    ))
+}
+
+/// a route over packed pools, e.g. 1inch `unoswap`, which don't name the tokens
+/// they route through. the missing tokens come from the `(token, from, to)`
+/// transfers below the router.
+#[allow(clippy::too_many_arguments)]
+fn packed_route_swap(
+    protocol: Protocol,
+    info: &CallInfo,
+    recipient: Address,
+    token_in: Option<Address>,
+    amount_in: U256,
+    amount_out: U256,
+    transfers: &[(Address, Address, Address)],
+    unwrapped: bool,
+) -> eyre::Result<NormalizedAggregatorSwap<Protocol>> {
+    let recipient = or_caller(info, recipient);
+
+    let token_in = match token_in {
+        Some(token) => token,
+        None => routed_token_in(
+            info,
+            transfers.iter().map(|(token, from, _)| (*token, *from)),
+        )?,
+    };
+    let token_out = routed_token_out(
+        recipient,
+        unwrapped,
+        transfers.iter().map(|(token, _, to)| (*token, *to)),
+    )?;
+
+    Ok(aggregated_swap(
+        protocol, info, recipient, token_in, token_out, amount_in, amount_out,
+    ))
+}
+
+/// the token the route took in when the call doesn't name it, eth when the
+/// call carried value, otherwise the token of the first transfer out of the
+/// caller
+fn routed_token_in(
+    info: &CallInfo,
+    mut transfers: impl Iterator<Item = (Address, Address)>,
+) -> eyre::Result<Address> {
+    if !info.msg_value.is_zero() {
+        return Ok(NATIVE_TOKEN);
+    }
+
+    transfers
+        .find(|(_, from)| *from == info.from_address)
+        .map(|(token, _)| token)
+        .ok_or_else(|| {
+            eyre::eyre!(
+                "no transfer out of the route's caller {:?}",
+                info.from_address
+            )
+        })
+}
+
+/// the token the route paid out when the call only names its input. a route
+/// unwrapping the wrapped native token pays out eth, otherwise the token of
+/// the last transfer to the recipient.
+fn routed_token_out(
+    recipient: Address,
+    unwrapped: bool,
+    transfers: impl DoubleEndedIterator<Item = (Address, Address)>,
+) -> eyre::Result<Address> {
+    if unwrapped {
+        return Ok(NATIVE_TOKEN);
+    }
+
+    transfers
+        .rev()
+        .find(|(_, to)| *to == recipient)
+        .map(|(token, _)| token)
+        .ok_or_else(|| eyre::eyre!("no transfer to the route's recipient {recipient:?}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::address;
+
+    use super::*;
+
+    const USDC: Address = address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+    const WETH: Address = address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2");
+    const DAI: Address = address!("6b175474e89094c44da98b954eedeac495271d0f");
+
+    /// `token (20) | fee (3) | token (20) | ..`
+    pub(super) fn v3_path(tokens: &[Address]) -> Vec<u8> {
+        let mut path = tokens[0].to_vec();
+        for token in &tokens[1..] {
+            path.extend_from_slice(&[0x00, 0x01, 0xf4]);
+            path.extend_from_slice(token.as_slice());
+        }
+        path
+    }
+
+    #[test]
+    fn reads_the_ends_of_v3_paths() {
+        assert_eq!(v3_path_ends(&v3_path(&[USDC, WETH])).unwrap(), (USDC, WETH));
+        assert_eq!(
+            v3_path_ends(&v3_path(&[USDC, WETH, DAI])).unwrap(),
+            (USDC, DAI)
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_v3_paths() {
+        let path = v3_path(&[USDC, WETH, DAI]);
+
+        for len in [0, 20, 23, 42, 44, 65] {
+            assert!(v3_path_ends(&path[..len]).is_err(), "{len} bytes");
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::sol;
+use brontes_classifier::{action_impl, types::CallInfo};
+use brontes_classifier_types::NormalizedAggregatorSwap;
+
+use super::{aggregated_swap, packed_route_swap};
+use crate::{Actions, Protocol, ProtocolMetadata};
+
+sol! {
+    interface IAggregationRouterV5 {
+        struct SwapDescription {
+            address srcToken;
+            address dstToken;
+            address srcReceiver;
+            address dstReceiver;
+            uint256 amount;
+            uint256 minReturnAmount;
+            uint256 flags;
+        }
+
+        // emitted by the tokens the route moves through
+        event Transfer(address indexed from, address indexed to, uint256 value);
+        event Withdrawal(address indexed src, uint256 wad);
+
+        function swap(
+            address executor,
+            SwapDescription desc,
+            bytes permit,
+            bytes data
+        ) external payable returns (uint256 returnAmount, uint256 spentAmount);
+        function unoswap(
+            address srcToken,
+            uint256 amount,
+            uint256 minReturn,
+            uint256[] pools
+        ) external payable returns (uint256 returnAmount);
+        function unoswapTo(
+            address recipient,
+            address srcToken,
+            uint256 amount,
+            uint256 minReturn,
+            uint256[] pools
+        ) external payable returns (uint256 returnAmount);
+        function uniswapV3Swap(
+            uint256 amount,
+            uint256 minReturn,
+            uint256[] pools
+        ) external payable returns (uint256 returnAmount);
+        function uniswapV3SwapTo(
+            address recipient,
+            uint256 amount,
+            uint256 minReturn,
+            uint256[] pools
+        ) external payable returns (uint256 returnAmount);
+    }
+}
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::OneInchV5,
+    IAggregationRouterV5::swapCall,
+    AggregatorSwap,
+    [],
+    call_data: true,
+    return_data: true,
+    |info: CallInfo, call_data: swapCall, return_data: swapReturn, _db_ctx: &DB| {
+        let desc = call_data.desc;
+        Ok(aggregated_swap(
+            Protocol::OneInchV5,
+            &info,
+            desc.dstReceiver,
+            desc.srcToken,
+            desc.dstToken,
+            return_data.spentAmount,
+            return_data.returnAmount,
+        ))
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::OneInchV5,
+    IAggregationRouterV5::unoswapCall,
+    AggregatorSwap,
+    [..(Transfer | Withdrawal)*],
+    call_data: true,
+    return_data: true,
+    logs: true,
+    include_child_logs: true,
+    |info: CallInfo,
+    call_data: unoswapCall,
+    return_data: unoswapReturn,
+    log_data: OneInchV5UnoswapCallLogs,
+    _db_ctx: &DB| {
+        unoswapped(
+            &info,
+            Address::ZERO,
+            Some(call_data.srcToken),
+            call_data.amount,
+            return_data.returnAmount,
+            log_data.transfer_field?,
+            log_data.transfer_emitter?,
+            !log_data.withdrawal_field?.is_empty(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::OneInchV5,
+    IAggregationRouterV5::unoswapToCall,
+    AggregatorSwap,
+    [..(Transfer | Withdrawal)*],
+    call_data: true,
+    return_data: true,
+    logs: true,
+    include_child_logs: true,
+    |info: CallInfo,
+    call_data: unoswapToCall,
+    return_data: unoswapToReturn,
+    log_data: OneInchV5UnoswapToCallLogs,
+    _db_ctx: &DB| {
+        unoswapped(
+            &info,
+            call_data.recipient,
+            Some(call_data.srcToken),
+            call_data.amount,
+            return_data.returnAmount,
+            log_data.transfer_field?,
+            log_data.transfer_emitter?,
+            !log_data.withdrawal_field?.is_empty(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::OneInchV5,
+    IAggregationRouterV5::uniswapV3SwapCall,
+    AggregatorSwap,
+    [..(Transfer | Withdrawal)*],
+    call_data: true,
+    return_data: true,
+    logs: true,
+    include_child_logs: true,
+    |info: CallInfo,
+    call_data: uniswapV3SwapCall,
+    return_data: uniswapV3SwapReturn,
+    log_data: OneInchV5UniswapV3SwapCallLogs,
+    _db_ctx: &DB| {
+        unoswapped(
+            &info,
+            Address::ZERO,
+            None,
+            call_data.amount,
+            return_data.returnAmount,
+            log_data.transfer_field?,
+            log_data.transfer_emitter?,
+            !log_data.withdrawal_field?.is_empty(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::OneInchV5,
+    IAggregationRouterV5::uniswapV3SwapToCall,
+    AggregatorSwap,
+    [..(Transfer | Withdrawal)*],
+    call_data: true,
+    return_data: true,
+    logs: true,
+    include_child_logs: true,
+    |info: CallInfo,
+    call_data: uniswapV3SwapToCall,
+    return_data: uniswapV3SwapToReturn,
+    log_data: OneInchV5UniswapV3SwapToCallLogs,
+    _db_ctx: &DB| {
+        unoswapped(
+            &info,
+            call_data.recipient,
+            None,
+            call_data.amount,
+            return_data.returnAmount,
+            log_data.transfer_field?,
+            log_data.transfer_emitter?,
+            !log_data.withdrawal_field?.is_empty(),
+        )
+    }
+);
+
+#[allow(clippy::too_many_arguments)]
+fn unoswapped(
+    info: &CallInfo,
+    recipient: Address,
+    token_in: Option<Address>,
+    amount_in: U256,
+    amount_out: U256,
+    transfers: Vec<IAggregationRouterV5::Transfer>,
+    emitters: Vec<Address>,
+    unwrapped: bool,
+) -> eyre::Result<NormalizedAggregatorSwap<Protocol>> {
+    let transfers = transfers
+        .iter()
+        .zip(emitters)
+        .map(|(transfer, token)| (token, transfer.from, transfer.to))
+        .collect::<Vec<_>>();
+
+    packed_route_swap(
+        Protocol::OneInchV5,
+        info,
+        recipient,
+        token_in,
+        amount_in,
+        amount_out,
+        &transfers,
+        unwrapped,
+    )
+}
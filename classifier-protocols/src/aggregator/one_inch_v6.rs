@@ -0,0 +1,499 @@
+use alloy_primitives::{Address, B256, U256};
+use alloy_sol_types::sol;
+use brontes_classifier::{action_impl, types::CallInfo};
+use brontes_classifier_types::NormalizedAggregatorSwap;
+
+use super::{aggregated_swap, packed_route_swap};
+use crate::{Actions, Protocol, ProtocolMetadata};
+
+sol! {
+    interface IAggregationRouterV6 {
+        struct SwapDescription {
+            address srcToken;
+            address dstToken;
+            address srcReceiver;
+            address dstReceiver;
+            uint256 amount;
+            uint256 minReturnAmount;
+            uint256 flags;
+        }
+
+        // emitted by the tokens the route moves through
+        event Transfer(address indexed from, address indexed to, uint256 value);
+        event Withdrawal(address indexed src, uint256 wad);
+
+        function swap(
+            address executor,
+            SwapDescription desc,
+            bytes data
+        ) external payable returns (uint256 returnAmount, uint256 spentAmount);
+
+        // the `unoswap` family packs its addresses into the low 160 bits of an
+        // `Address` (uint256) whose high bits carry flags, the dexes are
+        // packed pools
+        function unoswap(
+            uint256 token,
+            uint256 amount,
+            uint256 minReturn,
+            uint256 dex
+        ) external payable returns (uint256 returnAmount);
+        function unoswapTo(
+            uint256 to,
+            uint256 token,
+            uint256 amount,
+            uint256 minReturn,
+            uint256 dex
+        ) external payable returns (uint256 returnAmount);
+        function ethUnoswap(
+            uint256 minReturn,
+            uint256 dex
+        ) external payable returns (uint256 returnAmount);
+        function ethUnoswapTo(
+            uint256 to,
+            uint256 minReturn,
+            uint256 dex
+        ) external payable returns (uint256 returnAmount);
+        function unoswap2(
+            uint256 token,
+            uint256 amount,
+            uint256 minReturn,
+            uint256 dex,
+            uint256 dex2
+        ) external payable returns (uint256 returnAmount);
+        function unoswapTo2(
+            uint256 to,
+            uint256 token,
+            uint256 amount,
+            uint256 minReturn,
+            uint256 dex,
+            uint256 dex2
+        ) external payable returns (uint256 returnAmount);
+        function ethUnoswap2(
+            uint256 minReturn,
+            uint256 dex,
+            uint256 dex2
+        ) external payable returns (uint256 returnAmount);
+        function ethUnoswapTo2(
+            uint256 to,
+            uint256 minReturn,
+            uint256 dex,
+            uint256 dex2
+        ) external payable returns (uint256 returnAmount);
+        function unoswap3(
+            uint256 token,
+            uint256 amount,
+            uint256 minReturn,
+            uint256 dex,
+            uint256 dex2,
+            uint256 dex3
+        ) external payable returns (uint256 returnAmount);
+        function unoswapTo3(
+            uint256 to,
+            uint256 token,
+            uint256 amount,
+            uint256 minReturn,
+            uint256 dex,
+            uint256 dex2,
+            uint256 dex3
+        ) external payable returns (uint256 returnAmount);
+        function ethUnoswap3(
+            uint256 minReturn,
+            uint256 dex,
+            uint256 dex2,
+            uint256 dex3
+        ) external payable returns (uint256 returnAmount);
+        function ethUnoswapTo3(
+            uint256 to,
+            uint256 minReturn,
+            uint256 dex,
+            uint256 dex2,
+            uint256 dex3
+        ) external payable returns (uint256 returnAmount);
+    }
+}
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::OneInchV6,
+    IAggregationRouterV6::swapCall,
+    AggregatorSwap,
+    [],
+    call_data: true,
+    return_data: true,
+    |info: CallInfo, call_data: swapCall, return_data: swapReturn, _db_ctx: &DB| {
+        let desc = call_data.desc;
+        Ok(aggregated_swap(
+            Protocol::OneInchV6,
+            &info,
+            desc.dstReceiver,
+            desc.srcToken,
+            desc.dstToken,
+            return_data.spentAmount,
+            return_data.returnAmount,
+        ))
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::OneInchV6,
+    IAggregationRouterV6::unoswapCall,
+    AggregatorSwap,
+    [..(Transfer | Withdrawal)*],
+    call_data: true,
+    return_data: true,
+    logs: true,
+    include_child_logs: true,
+    |info: CallInfo,
+    call_data: unoswapCall,
+    return_data: unoswapReturn,
+    log_data: OneInchV6UnoswapCallLogs,
+    _db_ctx: &DB| {
+        unoswapped(
+            &info,
+            Address::ZERO,
+            Some(packed_address(call_data.token)),
+            call_data.amount,
+            return_data.returnAmount,
+            log_data.transfer_field?,
+            log_data.transfer_emitter?,
+            !log_data.withdrawal_field?.is_empty(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::OneInchV6,
+    IAggregationRouterV6::unoswapToCall,
+    AggregatorSwap,
+    [..(Transfer | Withdrawal)*],
+    call_data: true,
+    return_data: true,
+    logs: true,
+    include_child_logs: true,
+    |info: CallInfo,
+    call_data: unoswapToCall,
+    return_data: unoswapToReturn,
+    log_data: OneInchV6UnoswapToCallLogs,
+    _db_ctx: &DB| {
+        unoswapped(
+            &info,
+            packed_address(call_data.to),
+            Some(packed_address(call_data.token)),
+            call_data.amount,
+            return_data.returnAmount,
+            log_data.transfer_field?,
+            log_data.transfer_emitter?,
+            !log_data.withdrawal_field?.is_empty(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::OneInchV6,
+    IAggregationRouterV6::ethUnoswapCall,
+    AggregatorSwap,
+    [..(Transfer | Withdrawal)*],
+    return_data: true,
+    logs: true,
+    include_child_logs: true,
+    |info: CallInfo,
+    return_data: ethUnoswapReturn,
+    log_data: OneInchV6EthUnoswapCallLogs,
+    _db_ctx: &DB| {
+        unoswapped(
+            &info,
+            Address::ZERO,
+            None,
+            info.msg_value,
+            return_data.returnAmount,
+            log_data.transfer_field?,
+            log_data.transfer_emitter?,
+            !log_data.withdrawal_field?.is_empty(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::OneInchV6,
+    IAggregationRouterV6::ethUnoswapToCall,
+    AggregatorSwap,
+    [..(Transfer | Withdrawal)*],
+    call_data: true,
+    return_data: true,
+    logs: true,
+    include_child_logs: true,
+    |info: CallInfo,
+    call_data: ethUnoswapToCall,
+    return_data: ethUnoswapToReturn,
+    log_data: OneInchV6EthUnoswapToCallLogs,
+    _db_ctx: &DB| {
+        unoswapped(
+            &info,
+            packed_address(call_data.to),
+            None,
+            info.msg_value,
+            return_data.returnAmount,
+            log_data.transfer_field?,
+            log_data.transfer_emitter?,
+            !log_data.withdrawal_field?.is_empty(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::OneInchV6,
+    IAggregationRouterV6::unoswap2Call,
+    AggregatorSwap,
+    [..(Transfer | Withdrawal)*],
+    call_data: true,
+    return_data: true,
+    logs: true,
+    include_child_logs: true,
+    |info: CallInfo,
+    call_data: unoswap2Call,
+    return_data: unoswap2Return,
+    log_data: OneInchV6Unoswap2CallLogs,
+    _db_ctx: &DB| {
+        unoswapped(
+            &info,
+            Address::ZERO,
+            Some(packed_address(call_data.token)),
+            call_data.amount,
+            return_data.returnAmount,
+            log_data.transfer_field?,
+            log_data.transfer_emitter?,
+            !log_data.withdrawal_field?.is_empty(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::OneInchV6,
+    IAggregationRouterV6::unoswapTo2Call,
+    AggregatorSwap,
+    [..(Transfer | Withdrawal)*],
+    call_data: true,
+    return_data: true,
+    logs: true,
+    include_child_logs: true,
+    |info: CallInfo,
+    call_data: unoswapTo2Call,
+    return_data: unoswapTo2Return,
+    log_data: OneInchV6UnoswapTo2CallLogs,
+    _db_ctx: &DB| {
+        unoswapped(
+            &info,
+            packed_address(call_data.to),
+            Some(packed_address(call_data.token)),
+            call_data.amount,
+            return_data.returnAmount,
+            log_data.transfer_field?,
+            log_data.transfer_emitter?,
+            !log_data.withdrawal_field?.is_empty(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::OneInchV6,
+    IAggregationRouterV6::ethUnoswap2Call,
+    AggregatorSwap,
+    [..(Transfer | Withdrawal)*],
+    return_data: true,
+    logs: true,
+    include_child_logs: true,
+    |info: CallInfo,
+    return_data: ethUnoswap2Return,
+    log_data: OneInchV6EthUnoswap2CallLogs,
+    _db_ctx: &DB| {
+        unoswapped(
+            &info,
+            Address::ZERO,
+            None,
+            info.msg_value,
+            return_data.returnAmount,
+            log_data.transfer_field?,
+            log_data.transfer_emitter?,
+            !log_data.withdrawal_field?.is_empty(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::OneInchV6,
+    IAggregationRouterV6::ethUnoswapTo2Call,
+    AggregatorSwap,
+    [..(Transfer | Withdrawal)*],
+    call_data: true,
+    return_data: true,
+    logs: true,
+    include_child_logs: true,
+    |info: CallInfo,
+    call_data: ethUnoswapTo2Call,
+    return_data: ethUnoswapTo2Return,
+    log_data: OneInchV6EthUnoswapTo2CallLogs,
+    _db_ctx: &DB| {
+        unoswapped(
+            &info,
+            packed_address(call_data.to),
+            None,
+            info.msg_value,
+            return_data.returnAmount,
+            log_data.transfer_field?,
+            log_data.transfer_emitter?,
+            !log_data.withdrawal_field?.is_empty(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::OneInchV6,
+    IAggregationRouterV6::unoswap3Call,
+    AggregatorSwap,
+    [..(Transfer | Withdrawal)*],
+    call_data: true,
+    return_data: true,
+    logs: true,
+    include_child_logs: true,
+    |info: CallInfo,
+    call_data: unoswap3Call,
+    return_data: unoswap3Return,
+    log_data: OneInchV6Unoswap3CallLogs,
+    _db_ctx: &DB| {
+        unoswapped(
+            &info,
+            Address::ZERO,
+            Some(packed_address(call_data.token)),
+            call_data.amount,
+            return_data.returnAmount,
+            log_data.transfer_field?,
+            log_data.transfer_emitter?,
+            !log_data.withdrawal_field?.is_empty(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::OneInchV6,
+    IAggregationRouterV6::unoswapTo3Call,
+    AggregatorSwap,
+    [..(Transfer | Withdrawal)*],
+    call_data: true,
+    return_data: true,
+    logs: true,
+    include_child_logs: true,
+    |info: CallInfo,
+    call_data: unoswapTo3Call,
+    return_data: unoswapTo3Return,
+    log_data: OneInchV6UnoswapTo3CallLogs,
+    _db_ctx: &DB| {
+        unoswapped(
+            &info,
+            packed_address(call_data.to),
+            Some(packed_address(call_data.token)),
+            call_data.amount,
+            return_data.returnAmount,
+            log_data.transfer_field?,
+            log_data.transfer_emitter?,
+            !log_data.withdrawal_field?.is_empty(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::OneInchV6,
+    IAggregationRouterV6::ethUnoswap3Call,
+    AggregatorSwap,
+    [..(Transfer | Withdrawal)*],
+    return_data: true,
+    logs: true,
+    include_child_logs: true,
+    |info: CallInfo,
+    return_data: ethUnoswap3Return,
+    log_data: OneInchV6EthUnoswap3CallLogs,
+    _db_ctx: &DB| {
+        unoswapped(
+            &info,
+            Address::ZERO,
+            None,
+            info.msg_value,
+            return_data.returnAmount,
+            log_data.transfer_field?,
+            log_data.transfer_emitter?,
+            !log_data.withdrawal_field?.is_empty(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::OneInchV6,
+    IAggregationRouterV6::ethUnoswapTo3Call,
+    AggregatorSwap,
+    [..(Transfer | Withdrawal)*],
+    call_data: true,
+    return_data: true,
+    logs: true,
+    include_child_logs: true,
+    |info: CallInfo,
+    call_data: ethUnoswapTo3Call,
+    return_data: ethUnoswapTo3Return,
+    log_data: OneInchV6EthUnoswapTo3CallLogs,
+    _db_ctx: &DB| {
+        unoswapped(
+            &info,
+            packed_address(call_data.to),
+            None,
+            info.msg_value,
+            return_data.returnAmount,
+            log_data.transfer_field?,
+            log_data.transfer_emitter?,
+            !log_data.withdrawal_field?.is_empty(),
+        )
+    }
+);
+
+#[allow(clippy::too_many_arguments)]
+fn unoswapped(
+    info: &CallInfo,
+    recipient: Address,
+    token_in: Option<Address>,
+    amount_in: U256,
+    amount_out: U256,
+    transfers: Vec<IAggregationRouterV6::Transfer>,
+    emitters: Vec<Address>,
+    unwrapped: bool,
+) -> eyre::Result<NormalizedAggregatorSwap<Protocol>> {
+    let transfers = transfers
+        .iter()
+        .zip(emitters)
+        .map(|(transfer, token)| (token, transfer.from, transfer.to))
+        .collect::<Vec<_>>();
+
+    packed_route_swap(
+        Protocol::OneInchV6,
+        info,
+        recipient,
+        token_in,
+        amount_in,
+        amount_out,
+        &transfers,
+        unwrapped,
+    )
+}
+
+/// the address in the low 160 bits of a packed `Address`
+fn packed_address(packed: U256) -> Address {
+    Address::from_word(B256::from(packed))
+}
@@ -0,0 +1,191 @@
+use alloy_sol_types::sol;
+use brontes_classifier::{action_impl, types::CallInfo};
+use brontes_classifier_types::NormalizedAggregatorSwap;
+
+use super::aggregated_swap;
+use crate::{Actions, Protocol, ProtocolMetadata};
+
+sol! {
+    interface IAugustusSwapperV5 {
+        struct Route {
+            uint256 index;
+            address targetExchange;
+            uint256 percent;
+            bytes payload;
+            uint256 networkFee;
+        }
+
+        struct Adapter {
+            address adapter;
+            uint256 percent;
+            uint256 networkFee;
+            Route[] route;
+        }
+
+        struct Path {
+            address to;
+            uint256 totalNetworkFee;
+            Adapter[] adapters;
+        }
+
+        struct MegaSwapPath {
+            uint256 fromAmountPercent;
+            Path[] path;
+        }
+
+        struct SellData {
+            address fromToken;
+            uint256 fromAmount;
+            uint256 toAmount;
+            uint256 expectedAmount;
+            address beneficiary;
+            Path[] path;
+            address partner;
+            uint256 feePercent;
+            bytes permit;
+            uint256 deadline;
+            bytes16 uuid;
+        }
+
+        struct MegaSwapSellData {
+            address fromToken;
+            uint256 fromAmount;
+            uint256 toAmount;
+            uint256 expectedAmount;
+            address beneficiary;
+            MegaSwapPath[] path;
+            address partner;
+            uint256 feePercent;
+            bytes permit;
+            uint256 deadline;
+            bytes16 uuid;
+        }
+
+        struct SimpleData {
+            address fromToken;
+            address toToken;
+            uint256 fromAmount;
+            uint256 toAmount;
+            uint256 expectedAmount;
+            address[] callees;
+            bytes exchangeData;
+            uint256[] startIndexes;
+            uint256[] values;
+            address beneficiary;
+            address partner;
+            uint256 feePercent;
+            bytes permit;
+            uint256 deadline;
+            bytes16 uuid;
+        }
+
+        event SwappedV3(
+            bytes16 uuid,
+            address partner,
+            uint256 feePercent,
+            address initiator,
+            address indexed beneficiary,
+            address indexed srcToken,
+            address indexed destToken,
+            uint256 srcAmount,
+            uint256 receivedAmount,
+            uint256 expectedAmount
+        );
+        event BoughtV3(
+            bytes16 uuid,
+            address partner,
+            uint256 feePercent,
+            address initiator,
+            address indexed beneficiary,
+            address indexed srcToken,
+            address indexed destToken,
+            uint256 srcAmount,
+            uint256 receivedAmount,
+            uint256 expectedAmount
+        );
+
+        function simpleSwap(SimpleData data) external payable returns (uint256 receivedAmount);
+        function simpleBuy(SimpleData data) external payable;
+        function multiSwap(SellData data) external payable returns (uint256);
+        function megaSwap(MegaSwapSellData data) external payable returns (uint256);
+    }
+}
+
+// augustus delegates each entry point to the router registered for its
+// selector, which emits the swap event with the amounts the route settled on.
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::ParaswapV5,
+    IAugustusSwapperV5::simpleSwapCall,
+    AggregatorSwap,
+    [..SwappedV3],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: ParaswapV5SimpleSwapCallLogs, _db_ctx: &DB| {
+        Ok(swapped(&info, log_data.swapped_v3_field?))
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::ParaswapV5,
+    IAugustusSwapperV5::simpleBuyCall,
+    AggregatorSwap,
+    [..BoughtV3],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: ParaswapV5SimpleBuyCallLogs, _db_ctx: &DB| {
+        let bought = log_data.bought_v3_field?;
+        Ok(aggregated_swap(
+            Protocol::ParaswapV5,
+            &info,
+            bought.beneficiary,
+            bought.srcToken,
+            bought.destToken,
+            bought.srcAmount,
+            bought.receivedAmount,
+        ))
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::ParaswapV5,
+    IAugustusSwapperV5::multiSwapCall,
+    AggregatorSwap,
+    [..SwappedV3],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: ParaswapV5MultiSwapCallLogs, _db_ctx: &DB| {
+        Ok(swapped(&info, log_data.swapped_v3_field?))
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::ParaswapV5,
+    IAugustusSwapperV5::megaSwapCall,
+    AggregatorSwap,
+    [..SwappedV3],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: ParaswapV5MegaSwapCallLogs, _db_ctx: &DB| {
+        Ok(swapped(&info, log_data.swapped_v3_field?))
+    }
+);
+
+fn swapped(
+    info: &CallInfo,
+    swap: IAugustusSwapperV5::SwappedV3,
+) -> NormalizedAggregatorSwap<Protocol> {
+    aggregated_swap(
+        Protocol::ParaswapV5,
+        info,
+        swap.beneficiary,
+        swap.srcToken,
+        swap.destToken,
+        swap.srcAmount,
+        swap.receivedAmount,
+    )
+}
@@ -0,0 +1,316 @@
+use alloy_primitives::{Address, Bytes, U256, address};
+use alloy_sol_types::{SolValue, sol};
+use brontes_classifier::{action_impl, types::CallInfo};
+use brontes_classifier_types::{NATIVE_TOKEN, NormalizedAggregatorSwap};
+
+use super::{aggregated_swap, v3_path_ends};
+use crate::{Actions, Protocol, ProtocolMetadata};
+
+sol! {
+    interface IUniversalRouter {
+        // emitted by the tokens the route moves through
+        event Transfer(address indexed from, address indexed to, uint256 value);
+        event Withdrawal(address indexed src, uint256 wad);
+
+        function execute(bytes commands, bytes[] inputs, uint256 deadline) external payable;
+        function execute(bytes commands, bytes[] inputs) external payable;
+    }
+}
+
+// each byte of `commands` is a command whose abi encoded parameters are the
+// input at the same index. the route's ends come from the v2 and v3 swap
+// commands and the eth wraps around them, v4 swaps run through the pool
+// manager and are left to its classifier. amounts come from the transfers
+// below the router since exact in amounts may be the router's balance.
+
+const COMMAND_TYPE_MASK: u8 = 0x3f;
+const V3_SWAP_EXACT_IN: u8 = 0x00;
+const V3_SWAP_EXACT_OUT: u8 = 0x01;
+const SWEEP: u8 = 0x04;
+const V2_SWAP_EXACT_IN: u8 = 0x08;
+const V2_SWAP_EXACT_OUT: u8 = 0x09;
+const WRAP_ETH: u8 = 0x0b;
+const UNWRAP_WETH: u8 = 0x0c;
+
+/// recipient placeholders for the caller and the router itself
+const MSG_SENDER: Address = address!("0000000000000000000000000000000000000001");
+const ADDRESS_THIS: Address = address!("0000000000000000000000000000000000000002");
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::UniversalRouter,
+    IUniversalRouter::execute_0Call,
+    AggregatorSwap,
+    [..(Transfer | Withdrawal)*],
+    call_data: true,
+    logs: true,
+    include_child_logs: true,
+    |info: CallInfo,
+    call_data: execute_0Call,
+    log_data: UniversalRouterExecute0CallLogs,
+    _db_ctx: &DB| {
+        executed(
+            &info,
+            &call_data.commands,
+            &call_data.inputs,
+            log_data.transfer_field?,
+            log_data.transfer_emitter?,
+            log_data.withdrawal_field?,
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::UniversalRouter,
+    IUniversalRouter::execute_1Call,
+    AggregatorSwap,
+    [..(Transfer | Withdrawal)*],
+    call_data: true,
+    logs: true,
+    include_child_logs: true,
+    |info: CallInfo,
+    call_data: execute_1Call,
+    log_data: UniversalRouterExecute1CallLogs,
+    _db_ctx: &DB| {
+        executed(
+            &info,
+            &call_data.commands,
+            &call_data.inputs,
+            log_data.transfer_field?,
+            log_data.transfer_emitter?,
+            log_data.withdrawal_field?,
+        )
+    }
+);
+
+/// the ends of the route a command sequence swaps through
+#[derive(Default)]
+struct Route {
+    token_in: Option<Address>,
+    token_out: Option<Address>,
+    recipient: Address,
+    wraps_eth: bool,
+    unwraps_weth: bool,
+}
+
+impl Route {
+    fn hop(&mut self, token_in: Address, token_out: Address, recipient: Address) {
+        self.token_in.get_or_insert(token_in);
+        self.token_out = Some(token_out);
+        self.recipient = recipient;
+    }
+}
+
+fn decode_route(commands: &[u8], inputs: &[Bytes]) -> eyre::Result<Route> {
+    let mut route = Route::default();
+
+    for (command, input) in commands.iter().zip(inputs) {
+        match command & COMMAND_TYPE_MASK {
+            V3_SWAP_EXACT_IN => {
+                let (recipient, _, _, path, _) =
+                    <(Address, U256, U256, Bytes, bool)>::abi_decode_params(input, false)?;
+                let (token_in, token_out) = v3_path_ends(&path)?;
+                route.hop(token_in, token_out, recipient);
+            }
+            // exact out paths run from the output back to the input
+            V3_SWAP_EXACT_OUT => {
+                let (recipient, _, _, path, _) =
+                    <(Address, U256, U256, Bytes, bool)>::abi_decode_params(input, false)?;
+                let (token_out, token_in) = v3_path_ends(&path)?;
+                route.hop(token_in, token_out, recipient);
+            }
+            V2_SWAP_EXACT_IN | V2_SWAP_EXACT_OUT => {
+                let (recipient, _, _, path, _) =
+                    <(Address, U256, U256, Vec<Address>, bool)>::abi_decode_params(input, false)?;
+                let (Some(token_in), Some(token_out)) = (path.first(), path.last()) else {
+                    return Err(eyre::eyre!("universal router v2 swap without a path"));
+                };
+                route.hop(*token_in, *token_out, recipient);
+            }
+            WRAP_ETH if route.token_in.is_none() => route.wraps_eth = true,
+            UNWRAP_WETH if route.token_out.is_some() => {
+                let (recipient, _) = <(Address, U256)>::abi_decode_params(input, false)?;
+                route.unwraps_weth = true;
+                route.recipient = recipient;
+            }
+            SWEEP => {
+                let (token, recipient, _) =
+                    <(Address, Address, U256)>::abi_decode_params(input, false)?;
+                if route.token_out == Some(token) {
+                    route.recipient = recipient;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(route)
+}
+
+fn executed(
+    info: &CallInfo,
+    commands: &[u8],
+    inputs: &[Bytes],
+    transfers: Vec<IUniversalRouter::Transfer>,
+    emitters: Vec<Address>,
+    withdrawals: Vec<IUniversalRouter::Withdrawal>,
+) -> eyre::Result<NormalizedAggregatorSwap<Protocol>> {
+    let route = decode_route(commands, inputs)?;
+    let (Some(token_in), Some(token_out)) = (route.token_in, route.token_out) else {
+        return Err(eyre::eyre!(
+            "universal router commands without a v2 or v3 swap"
+        ));
+    };
+
+    let recipient = match route.recipient {
+        MSG_SENDER => info.from_address,
+        ADDRESS_THIS => info.target_address,
+        recipient => recipient,
+    };
+    let transferred = |token: Address, matches: &dyn Fn(&IUniversalRouter::Transfer) -> bool| {
+        transfers
+            .iter()
+            .zip(&emitters)
+            .filter(|(transfer, emitter)| **emitter == token && matches(transfer))
+            .fold(U256::ZERO, |acc, (transfer, _)| {
+                acc.saturating_add(transfer.value)
+            })
+    };
+
+    let (token_in, amount_in) = if route.wraps_eth {
+        (NATIVE_TOKEN, info.msg_value)
+    } else {
+        (
+            token_in,
+            transferred(token_in, &|transfer| transfer.from == info.from_address),
+        )
+    };
+    let (token_out, amount_out) = if route.unwraps_weth {
+        let unwrapped = withdrawals.iter().fold(U256::ZERO, |acc, withdrawal| {
+            acc.saturating_add(withdrawal.wad)
+        });
+        (NATIVE_TOKEN, unwrapped)
+    } else {
+        (
+            token_out,
+            transferred(token_out, &|transfer| transfer.to == recipient),
+        )
+    };
+
+    Ok(aggregated_swap(
+        Protocol::UniversalRouter,
+        info,
+        recipient,
+        token_in,
+        token_out,
+        amount_in,
+        amount_out,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aggregator::tests::v3_path;
+
+    const USDC: Address = address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+    const WETH: Address = address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2");
+    const DAI: Address = address!("6b175474e89094c44da98b954eedeac495271d0f");
+    const USER: Address = address!("1000000000000000000000000000000000000001");
+
+    /// the flag marking a command that may revert without reverting the route
+    const ALLOW_REVERT: u8 = 0x80;
+
+    fn v3_swap(recipient: Address, tokens: &[Address]) -> Bytes {
+        (
+            recipient,
+            U256::from(1),
+            U256::ZERO,
+            Bytes::from(v3_path(tokens)),
+            true,
+        )
+            .abi_encode_params()
+            .into()
+    }
+
+    fn v2_swap(recipient: Address, path: Vec<Address>) -> Bytes {
+        (recipient, U256::from(1), U256::ZERO, path, true)
+            .abi_encode_params()
+            .into()
+    }
+
+    #[test]
+    fn decodes_v3_routes() {
+        let route =
+            decode_route(&[V3_SWAP_EXACT_IN], &[v3_swap(USER, &[USDC, WETH, DAI])]).unwrap();
+        assert_eq!(route.token_in, Some(USDC));
+        assert_eq!(route.token_out, Some(DAI));
+        assert_eq!(route.recipient, USER);
+
+        // exact out paths start at the output
+        let route =
+            decode_route(&[V3_SWAP_EXACT_OUT], &[v3_swap(USER, &[DAI, WETH, USDC])]).unwrap();
+        assert_eq!(route.token_in, Some(USDC));
+        assert_eq!(route.token_out, Some(DAI));
+    }
+
+    #[test]
+    fn joins_the_hops_of_split_routes() {
+        let route = decode_route(
+            &[V3_SWAP_EXACT_IN | ALLOW_REVERT, V2_SWAP_EXACT_IN],
+            &[
+                v3_swap(ADDRESS_THIS, &[USDC, WETH]),
+                v2_swap(MSG_SENDER, vec![WETH, DAI]),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(route.token_in, Some(USDC));
+        assert_eq!(route.token_out, Some(DAI));
+        assert_eq!(route.recipient, MSG_SENDER);
+        assert!(!route.wraps_eth && !route.unwraps_weth);
+    }
+
+    #[test]
+    fn decodes_eth_wraps_around_the_swaps() {
+        let route = decode_route(
+            &[WRAP_ETH, V2_SWAP_EXACT_IN, UNWRAP_WETH],
+            &[
+                (ADDRESS_THIS, U256::from(1)).abi_encode_params().into(),
+                v2_swap(ADDRESS_THIS, vec![WETH, USDC, WETH]),
+                (USER, U256::ZERO).abi_encode_params().into(),
+            ],
+        )
+        .unwrap();
+
+        assert!(route.wraps_eth && route.unwraps_weth);
+        assert_eq!(route.recipient, USER);
+    }
+
+    #[test]
+    fn follows_sweeps_of_the_output_token() {
+        let sweep =
+            |token: Address| -> Bytes { (token, USER, U256::ZERO).abi_encode_params().into() };
+
+        let route = decode_route(
+            &[V3_SWAP_EXACT_IN, SWEEP, SWEEP],
+            &[v3_swap(ADDRESS_THIS, &[USDC, DAI]), sweep(USDC), sweep(DAI)],
+        )
+        .unwrap();
+        assert_eq!(route.recipient, USER);
+
+        let route = decode_route(
+            &[V3_SWAP_EXACT_IN, SWEEP],
+            &[v3_swap(ADDRESS_THIS, &[USDC, DAI]), sweep(USDC)],
+        )
+        .unwrap();
+        assert_eq!(route.recipient, ADDRESS_THIS);
+    }
+
+    #[test]
+    fn rejects_malformed_inputs() {
+        assert!(decode_route(&[V2_SWAP_EXACT_IN], &[v2_swap(USER, vec![])]).is_err());
+        assert!(decode_route(&[V3_SWAP_EXACT_IN], &[Bytes::new()]).is_err());
+    }
+}
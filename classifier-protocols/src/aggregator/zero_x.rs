@@ -0,0 +1,184 @@
+use alloy_sol_types::sol;
+use brontes_classifier::{action_impl, types::CallInfo};
+use brontes_classifier_types::NATIVE_TOKEN;
+
+use super::{aggregated_swap, v3_path_ends};
+use crate::{Actions, Protocol, ProtocolMetadata};
+
+sol! {
+    interface IZeroEx {
+        struct Transformation {
+            uint32 deploymentNonce;
+            bytes data;
+        }
+
+        event TransformedERC20(
+            address indexed taker,
+            address inputToken,
+            address outputToken,
+            uint256 inputTokenAmount,
+            uint256 outputTokenAmount
+        );
+
+        function transformERC20(
+            address inputToken,
+            address outputToken,
+            uint256 inputTokenAmount,
+            uint256 minOutputTokenAmount,
+            Transformation[] transformations
+        ) external payable returns (uint256 outputTokenAmount);
+        function sellToUniswap(
+            address[] tokens,
+            uint256 sellAmount,
+            uint256 minBuyAmount,
+            bool isSushi
+        ) external payable returns (uint256 buyAmount);
+        function sellEthForTokenToUniswapV3(
+            bytes encodedPath,
+            uint256 minBuyAmount,
+            address recipient
+        ) external payable returns (uint256 buyAmount);
+        function sellTokenForEthToUniswapV3(
+            bytes encodedPath,
+            uint256 sellAmount,
+            uint256 minBuyAmount,
+            address recipient
+        ) external returns (uint256 buyAmount);
+        function sellTokenForTokenToUniswapV3(
+            bytes encodedPath,
+            uint256 sellAmount,
+            uint256 minBuyAmount,
+            address recipient
+        ) external returns (uint256 buyAmount);
+    }
+}
+
+// the exchange proxy delegates every feature to its implementation, which
+// emits the events. eth is the native placeholder in token lists, the uniswap
+// v3 paths of the eth variants hold the wrapped native token instead.
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::ZeroEx,
+    IZeroEx::transformERC20Call,
+    AggregatorSwap,
+    [..TransformedERC20],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: ZeroExTransformERC20CallLogs, _db_ctx: &DB| {
+        let transformed = log_data.transformed_e_r_c20_field?;
+        Ok(aggregated_swap(
+            Protocol::ZeroEx,
+            &info,
+            transformed.taker,
+            transformed.inputToken,
+            transformed.outputToken,
+            transformed.inputTokenAmount,
+            transformed.outputTokenAmount,
+        ))
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::ZeroEx,
+    IZeroEx::sellToUniswapCall,
+    AggregatorSwap,
+    [],
+    call_data: true,
+    return_data: true,
+    |info: CallInfo,
+    call_data: sellToUniswapCall,
+    return_data: sellToUniswapReturn,
+    _db_ctx: &DB| {
+        let (Some(token_in), Some(token_out)) = (call_data.tokens.first(), call_data.tokens.last())
+        else {
+            return Err(eyre::eyre!("0x uniswap sell without a token path"));
+        };
+
+        Ok(aggregated_swap(
+            Protocol::ZeroEx,
+            &info,
+            info.from_address,
+            *token_in,
+            *token_out,
+            call_data.sellAmount,
+            return_data.buyAmount,
+        ))
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::ZeroEx,
+    IZeroEx::sellEthForTokenToUniswapV3Call,
+    AggregatorSwap,
+    [],
+    call_data: true,
+    return_data: true,
+    |info: CallInfo,
+    call_data: sellEthForTokenToUniswapV3Call,
+    return_data: sellEthForTokenToUniswapV3Return,
+    _db_ctx: &DB| {
+        let (_, token_out) = v3_path_ends(&call_data.encodedPath)?;
+        Ok(aggregated_swap(
+            Protocol::ZeroEx,
+            &info,
+            call_data.recipient,
+            NATIVE_TOKEN,
+            token_out,
+            info.msg_value,
+            return_data.buyAmount,
+        ))
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::ZeroEx,
+    IZeroEx::sellTokenForEthToUniswapV3Call,
+    AggregatorSwap,
+    [],
+    call_data: true,
+    return_data: true,
+    |info: CallInfo,
+    call_data: sellTokenForEthToUniswapV3Call,
+    return_data: sellTokenForEthToUniswapV3Return,
+    _db_ctx: &DB| {
+        let (token_in, _) = v3_path_ends(&call_data.encodedPath)?;
+        Ok(aggregated_swap(
+            Protocol::ZeroEx,
+            &info,
+            call_data.recipient,
+            token_in,
+            NATIVE_TOKEN,
+            call_data.sellAmount,
+            return_data.buyAmount,
+        ))
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::ZeroEx,
+    IZeroEx::sellTokenForTokenToUniswapV3Call,
+    AggregatorSwap,
+    [],
+    call_data: true,
+    return_data: true,
+    |info: CallInfo,
+    call_data: sellTokenForTokenToUniswapV3Call,
+    return_data: sellTokenForTokenToUniswapV3Return,
+    _db_ctx: &DB| {
+        let (token_in, token_out) = v3_path_ends(&call_data.encodedPath)?;
+        Ok(aggregated_swap(
+            Protocol::ZeroEx,
+            &info,
+            call_data.recipient,
+            token_in,
+            token_out,
+            call_data.sellAmount,
+            return_data.buyAmount,
+        ))
+    }
+);
@@ -0,0 +1,373 @@
+use alloy_primitives::{Address, B256, I256, U256};
+use alloy_sol_types::sol;
+use brontes_classifier::{action_impl, types::CallInfo};
+use brontes_classifier_types::{
+    NATIVE_TOKEN, NormalizedBurn, NormalizedFlashLoan, NormalizedMint, NormalizedMultiSwap,
+    NormalizedSwap,
+};
+
+use crate::{Actions, Protocol, ProtocolMetadata};
+
+sol! {
+    interface IBalancerV2Vault {
+        struct SingleSwap {
+            bytes32 poolId;
+            uint8 kind;
+            address assetIn;
+            address assetOut;
+            uint256 amount;
+            bytes userData;
+        }
+
+        struct BatchSwapStep {
+            bytes32 poolId;
+            uint256 assetInIndex;
+            uint256 assetOutIndex;
+            uint256 amount;
+            bytes userData;
+        }
+
+        struct FundManagement {
+            address sender;
+            bool fromInternalBalance;
+            address recipient;
+            bool toInternalBalance;
+        }
+
+        struct JoinPoolRequest {
+            address[] assets;
+            uint256[] maxAmountsIn;
+            bytes userData;
+            bool fromInternalBalance;
+        }
+
+        struct ExitPoolRequest {
+            address[] assets;
+            uint256[] minAmountsOut;
+            bytes userData;
+            bool toInternalBalance;
+        }
+
+        event Swap(
+            bytes32 indexed poolId,
+            address indexed tokenIn,
+            address indexed tokenOut,
+            uint256 amountIn,
+            uint256 amountOut
+        );
+        event PoolBalanceChanged(
+            bytes32 indexed poolId,
+            address indexed liquidityProvider,
+            address[] tokens,
+            int256[] deltas,
+            uint256[] protocolFeeAmounts
+        );
+        event FlashLoan(
+            address indexed recipient,
+            address indexed token,
+            uint256 amount,
+            uint256 feeAmount
+        );
+
+        function swap(
+            SingleSwap singleSwap,
+            FundManagement funds,
+            uint256 limit,
+            uint256 deadline
+        ) external payable returns (uint256 amountCalculated);
+        function batchSwap(
+            uint8 kind,
+            BatchSwapStep[] swaps,
+            address[] assets,
+            FundManagement funds,
+            int256[] limits,
+            uint256 deadline
+        ) external payable returns (int256[] assetDeltas);
+        function joinPool(
+            bytes32 poolId,
+            address sender,
+            address recipient,
+            JoinPoolRequest request
+        ) external payable;
+        function exitPool(
+            bytes32 poolId,
+            address sender,
+            address recipient,
+            ExitPoolRequest request
+        ) external;
+        function flashLoan(
+            address recipient,
+            address[] tokens,
+            uint256[] amounts,
+            bytes userData
+        ) external;
+    }
+}
+
+// every pool's tokens are held by the vault, which is the registered address.
+// the pool a call touches comes from its 32 byte pool id, the leading 20 bytes
+// of which are the pool's address. pools are registered under their id with
+// their tokens, leaving out the pool's own token.
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::BalancerV2,
+    IBalancerV2Vault::swapCall,
+    Swap,
+    [..Swap],
+    call_data: true,
+    logs: true,
+    |info: CallInfo,
+    call_data: swapCall,
+    log_data: BalancerV2SwapCallLogs,
+    _db_ctx: &DB| {
+        Ok(vault_swap(&info, call_data.funds.recipient, log_data.swap_field?))
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::BalancerV2,
+    IBalancerV2Vault::batchSwapCall,
+    MultiSwap,
+    [..Swap*],
+    call_data: true,
+    return_data: true,
+    logs: true,
+    |info: CallInfo,
+    call_data: batchSwapCall,
+    return_data: batchSwapReturn,
+    log_data: BalancerV2BatchSwapCallLogs,
+    _db_ctx: &DB| {
+        let recipient = call_data.funds.recipient;
+        let swaps = log_data
+            .swap_field?
+            .into_iter()
+            .map(|swap| vault_swap(&info, recipient, swap))
+            .collect();
+
+        let (tokens_in, amounts_in, tokens_out, amounts_out) =
+            asset_deltas(&call_data.assets, &return_data.assetDeltas);
+
+        Ok(NormalizedMultiSwap {
+            protocol: Protocol::BalancerV2,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            recipient,
+            tokens_in,
+            amounts_in,
+            tokens_out,
+            amounts_out,
+            swaps,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::BalancerV2,
+    IBalancerV2Vault::joinPoolCall,
+    Mint,
+    [..PoolBalanceChanged],
+    call_data: true,
+    logs: true,
+    |info: CallInfo,
+    call_data: joinPoolCall,
+    log_data: BalancerV2JoinPoolCallLogs,
+    db_ctx: &DB| {
+        let changed = log_data.pool_balance_changed_field?;
+        let pool_tokens = db_ctx.get_pool_tokens_sorted(changed.poolId)?;
+        let (tokens, amounts) = balance_changes(&pool_tokens, &changed.tokens, &changed.deltas);
+
+        Ok(NormalizedMint {
+            protocol: Protocol::BalancerV2,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            recipient: call_data.recipient,
+            pool: balancer_pool_address(changed.poolId),
+            pool_id: Some(changed.poolId),
+            tokens,
+            amounts,
+            position: None,
+        })
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::BalancerV2,
+    IBalancerV2Vault::exitPoolCall,
+    Burn,
+    [..PoolBalanceChanged],
+    call_data: true,
+    logs: true,
+    |info: CallInfo,
+    call_data: exitPoolCall,
+    log_data: BalancerV2ExitPoolCallLogs,
+    db_ctx: &DB| {
+        let changed = log_data.pool_balance_changed_field?;
+        let pool_tokens = db_ctx.get_pool_tokens_sorted(changed.poolId)?;
+        let (tokens, amounts) = balance_changes(&pool_tokens, &changed.tokens, &changed.deltas);
+
+        Ok(NormalizedBurn {
+            protocol: Protocol::BalancerV2,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            recipient: call_data.recipient,
+            pool: balancer_pool_address(changed.poolId),
+            pool_id: Some(changed.poolId),
+            tokens,
+            amounts,
+            position: None,
+        })
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::BalancerV2,
+    IBalancerV2Vault::flashLoanCall,
+    FlashLoan,
+    [..FlashLoan*],
+    call_data: true,
+    logs: true,
+    |info: CallInfo,
+    call_data: flashLoanCall,
+    log_data: BalancerV2FlashLoanCallLogs,
+    _db_ctx: &DB| {
+        let loans = log_data.flash_loan_field?;
+
+        Ok(NormalizedFlashLoan {
+            protocol: Protocol::BalancerV2,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            pool: info.target_address,
+            receiver: call_data.recipient,
+            assets: loans.iter().map(|loan| loan.token).collect(),
+            amounts: loans.iter().map(|loan| loan.amount).collect(),
+            fees: loans.iter().map(|loan| loan.feeAmount).collect(),
+        })
+    }
+);
+
+/// the address of a pool, the leading 20 bytes of its id
+pub fn balancer_pool_address(pool_id: B256) -> Address {
+    Address::from_slice(&pool_id[..20])
+}
+
+/// the vault reports the wrapped native token in its events
+fn vault_swap(
+    info: &CallInfo,
+    recipient: Address,
+    swap: IBalancerV2Vault::Swap,
+) -> NormalizedSwap<Protocol> {
+    NormalizedSwap {
+        protocol: Protocol::BalancerV2,
+        trace_index: info.trace_idx,
+        from: info.from_address,
+        recipient,
+        pool: balancer_pool_address(swap.poolId),
+        pool_id: Some(swap.poolId),
+        token_in: swap.tokenIn,
+        token_out: swap.tokenOut,
+        amount_in: swap.amountIn,
+        amount_out: swap.amountOut,
+        msg_value: info.msg_value,
+    }
+}
+
+/// the zero address stands for eth in the vault's asset lists
+fn asset_token(asset: Address) -> Address {
+    if asset == Address::ZERO {
+        NATIVE_TOKEN
+    } else {
+        asset
+    }
+}
+
+/// splits the asset deltas of a batch swap into the tokens and amounts paid in
+/// and out. positive deltas were paid into the vault, negative ones paid out
+fn asset_deltas(
+    assets: &[Address],
+    deltas: &[I256],
+) -> (Vec<Address>, Vec<U256>, Vec<Address>, Vec<U256>) {
+    let (mut tokens_in, mut amounts_in, mut tokens_out, mut amounts_out) =
+        (Vec::new(), Vec::new(), Vec::new(), Vec::new());
+    for (asset, delta) in assets.iter().zip(deltas) {
+        if delta.is_positive() {
+            tokens_in.push(asset_token(*asset));
+            amounts_in.push(delta.unsigned_abs());
+        } else if delta.is_negative() {
+            tokens_out.push(asset_token(*asset));
+            amounts_out.push(delta.unsigned_abs());
+        }
+    }
+
+    (tokens_in, amounts_in, tokens_out, amounts_out)
+}
+
+/// the tokens and absolute amounts of a balance change, leaving out the pool's
+/// own token which composable pools list among their tokens but isn't
+/// registered as one of the pool's tokens
+fn balance_changes(
+    pool_tokens: &[Address],
+    tokens: &[Address],
+    deltas: &[I256],
+) -> (Vec<Address>, Vec<U256>) {
+    tokens
+        .iter()
+        .zip(deltas)
+        .filter(|(token, _)| pool_tokens.contains(token))
+        .map(|(token, delta)| (*token, delta.unsigned_abs()))
+        .unzip()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::address;
+
+    use super::*;
+
+    const WETH: Address = address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2");
+    const DAI: Address = address!("6b175474e89094c44da98b954eedeac495271d0f");
+    const USDC: Address = address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+    /// the pool token of a composable pool over dai and usdc
+    const BPT: Address = address!("79c58f70905f734641735bc61e45c19dd9ad60bc");
+
+    fn delta(amount: i64) -> I256 {
+        I256::try_from(amount).unwrap()
+    }
+
+    #[test]
+    fn maps_the_zero_address_to_eth() {
+        assert_eq!(asset_token(Address::ZERO), NATIVE_TOKEN);
+        assert_eq!(asset_token(WETH), WETH);
+    }
+
+    #[test]
+    fn splits_asset_deltas_by_sign() {
+        // eth in, the intermediate dai nets out to zero, usdc out
+        let (tokens_in, amounts_in, tokens_out, amounts_out) = asset_deltas(
+            &[Address::ZERO, DAI, USDC],
+            &[delta(5), delta(0), delta(-9)],
+        );
+
+        assert_eq!(tokens_in, vec![NATIVE_TOKEN]);
+        assert_eq!(amounts_in, vec![U256::from(5)]);
+        assert_eq!(tokens_out, vec![USDC]);
+        assert_eq!(amounts_out, vec![U256::from(9)]);
+    }
+
+    #[test]
+    fn leaves_the_pool_token_out_of_balance_changes() {
+        let (tokens, amounts) = balance_changes(
+            &[DAI, USDC],
+            &[DAI, BPT, USDC],
+            &[delta(-3), delta(0), delta(4)],
+        );
+
+        assert_eq!(tokens, vec![DAI, USDC]);
+        assert_eq!(amounts, vec![U256::from(3), U256::from(4)]);
+    }
+}
@@ -0,0 +1,164 @@
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::sol;
+use brontes_classifier::{action_impl, context::DataContext, types::CallInfo};
+use brontes_classifier_types::{BridgeDirection, NATIVE_TOKEN, NormalizedBridge};
+
+use super::bridged;
+use crate::{Actions, Protocol, ProtocolMetadata};
+
+sol! {
+    interface IArbitrumInbox {
+        event InboxMessageDelivered(uint256 indexed messageNum, bytes data);
+
+        function depositEth() external payable returns (uint256);
+    }
+
+    interface IL1GatewayRouter {
+        // emitted by the gateway the router forwards the token to
+        event DepositInitiated(
+            address l1Token,
+            address indexed _from,
+            address indexed _to,
+            uint256 indexed _sequenceNumber,
+            uint256 _amount
+        );
+
+        function outboundTransfer(
+            address _token,
+            address _to,
+            uint256 _amount,
+            uint256 _maxGas,
+            uint256 _gasPriceBid,
+            bytes _data
+        ) external payable returns (bytes);
+        function outboundTransferCustomRefund(
+            address _token,
+            address _refundTo,
+            address _to,
+            uint256 _amount,
+            uint256 _maxGas,
+            uint256 _gasPriceBid,
+            bytes _data
+        ) external payable returns (bytes);
+    }
+
+    interface IL1ArbitrumGateway {
+        event WithdrawalFinalized(
+            address l1Token,
+            address indexed _from,
+            address indexed _to,
+            uint256 indexed _exitNum,
+            uint256 _amount
+        );
+
+        function finalizeInboundTransfer(
+            address _token,
+            address _from,
+            address _to,
+            uint256 _amount,
+            bytes _data
+        ) external payable;
+    }
+}
+
+// eth is deposited through the inbox, tokens through the gateway router which
+// hands them to the gateway registered for the token. the router is the
+// registered entry point for token deposits and the gateways for the
+// withdrawals the outbox finalizes, eth withdrawals are plain outbox calls
+// and aren't classified.
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::ArbitrumInbox,
+    IArbitrumInbox::depositEthCall,
+    Bridge,
+    [..InboxMessageDelivered],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: ArbitrumInboxDepositEthCallLogs, db_ctx: &DB| {
+        // `dest (20) | value (32)`, dest being the aliased caller for contracts
+        let data = log_data.inbox_message_delivered_field?.data;
+        if data.len() < 52 {
+            return Err(eyre::eyre!("arbitrum eth deposit message of {} bytes", data.len()));
+        }
+
+        bridged(
+            Protocol::ArbitrumInbox,
+            &info,
+            db_ctx,
+            BridgeDirection::Deposit,
+            info.from_address,
+            Address::from_slice(&data[..20]),
+            NATIVE_TOKEN,
+            U256::from_be_slice(&data[20..52]),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::ArbitrumGatewayRouter,
+    IL1GatewayRouter::outboundTransferCall,
+    Bridge,
+    [..DepositInitiated],
+    logs: true,
+    include_child_logs: true,
+    |info: CallInfo, log_data: ArbitrumGatewayRouterOutboundTransferCallLogs, db_ctx: &DB| {
+        deposit_initiated(&info, db_ctx, log_data.deposit_initiated_field?)
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::ArbitrumGatewayRouter,
+    IL1GatewayRouter::outboundTransferCustomRefundCall,
+    Bridge,
+    [..DepositInitiated],
+    logs: true,
+    include_child_logs: true,
+    |info: CallInfo,
+    log_data: ArbitrumGatewayRouterOutboundTransferCustomRefundCallLogs,
+    db_ctx: &DB| {
+        deposit_initiated(&info, db_ctx, log_data.deposit_initiated_field?)
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::ArbitrumGateway,
+    IL1ArbitrumGateway::finalizeInboundTransferCall,
+    Bridge,
+    [..WithdrawalFinalized],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: ArbitrumGatewayFinalizeInboundTransferCallLogs, db_ctx: &DB| {
+        let finalized = log_data.withdrawal_finalized_field?;
+        bridged(
+            Protocol::ArbitrumGateway,
+            &info,
+            db_ctx,
+            BridgeDirection::Withdraw,
+            finalized._from,
+            finalized._to,
+            finalized.l1Token,
+            finalized._amount,
+        )
+    }
+);
+
+fn deposit_initiated<DB: DataContext<Protocol, ProtocolMetadata>>(
+    info: &CallInfo,
+    db_ctx: &DB,
+    initiated: IL1GatewayRouter::DepositInitiated,
+) -> eyre::Result<NormalizedBridge<Protocol>> {
+    bridged(
+        Protocol::ArbitrumGatewayRouter,
+        info,
+        db_ctx,
+        BridgeDirection::Deposit,
+        initiated._from,
+        initiated._to,
+        initiated.l1Token,
+        initiated._amount,
+    )
+}
@@ -0,0 +1,120 @@
+mod arbitrum;
+mod optimism;
+mod zksync;
+
+use alloy_primitives::{Address, U256};
+pub use arbitrum::*;
+use brontes_classifier::{context::DataContext, types::CallInfo};
+use brontes_classifier_types::{BridgeDirection, NormalizedBridge};
+pub use optimism::*;
+pub use zksync::*;
+
+use crate::{Protocol, ProtocolMetadata};
+
+// the l1 side of canonical l2 bridges. one bridge abi serves every chain built
+// on the same stack, the l2 a contract connects to comes from its registry
+// metadata.
+
+#[allow(clippy::too_many_arguments)]
+fn bridged<DB: DataContext<Protocol, ProtocolMetadata>>(
+    protocol: Protocol,
+    info: &CallInfo,
+    db_ctx: &DB,
+    direction: BridgeDirection,
+    from: Address,
+    recipient: Address,
+    token: Address,
+    amount: U256,
+) -> eyre::Result<NormalizedBridge<Protocol>> {
+    // chains on the same stack share the bridge implementation and only the
+    // called proxy tells them apart, a bridge resolved through its
+    // implementation has no chain
+    let bridge = info.target_address;
+    let l2_chain_id = db_ctx
+        .get_metadata(bridge)
+        .ok()
+        .and_then(|metadata| metadata.l2_chain_id)
+        .ok_or_else(|| eyre::eyre!("bridge {bridge:?} must be registered with its l2 chain id"))?;
+
+    Ok(NormalizedBridge {
+        protocol,
+        trace_index: info.trace_idx,
+        bridge: info.target_address,
+        direction,
+        from,
+        recipient,
+        token,
+        amount,
+        l2_chain_id,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::address;
+    use brontes_classifier_types::NATIVE_TOKEN;
+
+    use super::*;
+    use crate::test_utils::{TestRegistry, call_info};
+
+    const OP_BRIDGE: Address = address!("99c9fc46f92e8a1c0dec1b1747d010903e884be1");
+    const BASE_BRIDGE: Address = address!("3154cf16ccdb4c6d922629664174b904d80f2c35");
+    const IMPLEMENTATION: Address = address!("64b5a5ed26dcb17370ff4d33a8d503f0fbd06cff");
+    const USER: Address = address!("1000000000000000000000000000000000000001");
+
+    fn op_bridge(chain_id: u64) -> ProtocolMetadata {
+        ProtocolMetadata {
+            l2_chain_id: Some(chain_id),
+            ..Default::default()
+        }
+    }
+
+    fn registry() -> TestRegistry {
+        TestRegistry::default()
+            .with_metadata(OP_BRIDGE, Protocol::OpStandardBridge, &[], op_bridge(10))
+            .with_metadata(
+                BASE_BRIDGE,
+                Protocol::OpStandardBridge,
+                &[],
+                op_bridge(8453),
+            )
+            .with(IMPLEMENTATION, Protocol::OpStandardBridge, &[])
+    }
+
+    fn bridge_eth(db: &TestRegistry, info: &CallInfo) -> eyre::Result<NormalizedBridge<Protocol>> {
+        bridged(
+            Protocol::OpStandardBridge,
+            info,
+            db,
+            BridgeDirection::Deposit,
+            USER,
+            USER,
+            NATIVE_TOKEN,
+            U256::from(5),
+        )
+    }
+
+    #[test]
+    fn reads_the_chain_of_the_called_proxy() {
+        let db = registry();
+
+        for (bridge, chain_id) in [(OP_BRIDGE, 10), (BASE_BRIDGE, 8453)] {
+            let mut info = call_info(USER, bridge);
+            info.implementation_address = Some(IMPLEMENTATION);
+
+            let deposit = bridge_eth(&db, &info).unwrap();
+            assert_eq!(deposit.bridge, bridge);
+            assert_eq!(deposit.l2_chain_id, chain_id);
+        }
+    }
+
+    #[test]
+    fn requires_the_proxy_to_be_registered() {
+        // resolved by the shared implementation, the chain is unknown
+        let mut info = call_info(USER, address!("2000000000000000000000000000000000000002"));
+        info.implementation_address = Some(IMPLEMENTATION);
+        info.protocol_address = IMPLEMENTATION;
+
+        assert!(bridge_eth(&registry(), &info).is_err());
+    }
+}
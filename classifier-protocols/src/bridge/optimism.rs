@@ -0,0 +1,344 @@
+use alloy_sol_types::sol;
+use brontes_classifier::{action_impl, context::DataContext, types::CallInfo};
+use brontes_classifier_types::{BridgeDirection, NATIVE_TOKEN, NormalizedBridge};
+
+use super::bridged;
+use crate::{Actions, Protocol, ProtocolMetadata};
+
+sol! {
+    interface IL1StandardBridge {
+        event ETHBridgeInitiated(
+            address indexed from,
+            address indexed to,
+            uint256 amount,
+            bytes extraData
+        );
+        event ERC20BridgeInitiated(
+            address indexed localToken,
+            address indexed remoteToken,
+            address indexed from,
+            address to,
+            uint256 amount,
+            bytes extraData
+        );
+        event ETHBridgeFinalized(
+            address indexed from,
+            address indexed to,
+            uint256 amount,
+            bytes extraData
+        );
+        event ERC20BridgeFinalized(
+            address indexed localToken,
+            address indexed remoteToken,
+            address indexed from,
+            address to,
+            uint256 amount,
+            bytes extraData
+        );
+
+        function depositETH(uint32 _minGasLimit, bytes _extraData) external payable;
+        function depositETHTo(
+            address _to,
+            uint32 _minGasLimit,
+            bytes _extraData
+        ) external payable;
+        function bridgeETH(uint32 _minGasLimit, bytes _extraData) external payable;
+        function bridgeETHTo(
+            address _to,
+            uint32 _minGasLimit,
+            bytes _extraData
+        ) external payable;
+        function depositERC20(
+            address _l1Token,
+            address _l2Token,
+            uint256 _amount,
+            uint32 _minGasLimit,
+            bytes _extraData
+        ) external;
+        function depositERC20To(
+            address _l1Token,
+            address _l2Token,
+            address _to,
+            uint256 _amount,
+            uint32 _minGasLimit,
+            bytes _extraData
+        ) external;
+        function bridgeERC20(
+            address _localToken,
+            address _remoteToken,
+            uint256 _amount,
+            uint32 _minGasLimit,
+            bytes _extraData
+        ) external;
+        function bridgeERC20To(
+            address _localToken,
+            address _remoteToken,
+            address _to,
+            uint256 _amount,
+            uint32 _minGasLimit,
+            bytes _extraData
+        ) external;
+        function finalizeBridgeETH(
+            address _from,
+            address _to,
+            uint256 _amount,
+            bytes _extraData
+        ) external payable;
+        function finalizeBridgeERC20(
+            address _localToken,
+            address _remoteToken,
+            address _from,
+            address _to,
+            uint256 _amount,
+            bytes _extraData
+        ) external;
+    }
+}
+
+// the standard bridge of the op stack, shared by optimism, base and the other
+// op chains. the legacy `deposit*` entry points emit the same bridge events as
+// the `bridge*` ones, withdrawals are finalized by the l1 messenger.
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::OpStandardBridge,
+    IL1StandardBridge::depositETHCall,
+    Bridge,
+    [..ETHBridgeInitiated],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: OpStandardBridgeDepositETHCallLogs, db_ctx: &DB| {
+        eth_initiated(&info, db_ctx, log_data.e_t_h_bridge_initiated_field?)
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::OpStandardBridge,
+    IL1StandardBridge::depositETHToCall,
+    Bridge,
+    [..ETHBridgeInitiated],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: OpStandardBridgeDepositETHToCallLogs, db_ctx: &DB| {
+        eth_initiated(&info, db_ctx, log_data.e_t_h_bridge_initiated_field?)
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::OpStandardBridge,
+    IL1StandardBridge::bridgeETHCall,
+    Bridge,
+    [..ETHBridgeInitiated],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: OpStandardBridgeBridgeETHCallLogs, db_ctx: &DB| {
+        eth_initiated(&info, db_ctx, log_data.e_t_h_bridge_initiated_field?)
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::OpStandardBridge,
+    IL1StandardBridge::bridgeETHToCall,
+    Bridge,
+    [..ETHBridgeInitiated],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: OpStandardBridgeBridgeETHToCallLogs, db_ctx: &DB| {
+        eth_initiated(&info, db_ctx, log_data.e_t_h_bridge_initiated_field?)
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::OpStandardBridge,
+    IL1StandardBridge::depositERC20Call,
+    Bridge,
+    [..ERC20BridgeInitiated],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: OpStandardBridgeDepositERC20CallLogs, db_ctx: &DB| {
+        erc20_initiated(&info, db_ctx, log_data.e_r_c20_bridge_initiated_field?)
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::OpStandardBridge,
+    IL1StandardBridge::depositERC20ToCall,
+    Bridge,
+    [..ERC20BridgeInitiated],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: OpStandardBridgeDepositERC20ToCallLogs, db_ctx: &DB| {
+        erc20_initiated(&info, db_ctx, log_data.e_r_c20_bridge_initiated_field?)
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::OpStandardBridge,
+    IL1StandardBridge::bridgeERC20Call,
+    Bridge,
+    [..ERC20BridgeInitiated],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: OpStandardBridgeBridgeERC20CallLogs, db_ctx: &DB| {
+        erc20_initiated(&info, db_ctx, log_data.e_r_c20_bridge_initiated_field?)
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::OpStandardBridge,
+    IL1StandardBridge::bridgeERC20ToCall,
+    Bridge,
+    [..ERC20BridgeInitiated],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: OpStandardBridgeBridgeERC20ToCallLogs, db_ctx: &DB| {
+        erc20_initiated(&info, db_ctx, log_data.e_r_c20_bridge_initiated_field?)
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::OpStandardBridge,
+    IL1StandardBridge::finalizeBridgeETHCall,
+    Bridge,
+    [..ETHBridgeFinalized],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: OpStandardBridgeFinalizeBridgeETHCallLogs, db_ctx: &DB| {
+        let finalized = log_data.e_t_h_bridge_finalized_field?;
+        bridged(
+            Protocol::OpStandardBridge,
+            &info,
+            db_ctx,
+            BridgeDirection::Withdraw,
+            finalized.from,
+            finalized.to,
+            NATIVE_TOKEN,
+            finalized.amount,
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::OpStandardBridge,
+    IL1StandardBridge::finalizeBridgeERC20Call,
+    Bridge,
+    [..ERC20BridgeFinalized],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: OpStandardBridgeFinalizeBridgeERC20CallLogs, db_ctx: &DB| {
+        let finalized = log_data.e_r_c20_bridge_finalized_field?;
+        bridged(
+            Protocol::OpStandardBridge,
+            &info,
+            db_ctx,
+            BridgeDirection::Withdraw,
+            finalized.from,
+            finalized.to,
+            finalized.localToken,
+            finalized.amount,
+        )
+    }
+);
+
+fn eth_initiated<DB: DataContext<Protocol, ProtocolMetadata>>(
+    info: &CallInfo,
+    db_ctx: &DB,
+    initiated: IL1StandardBridge::ETHBridgeInitiated,
+) -> eyre::Result<NormalizedBridge<Protocol>> {
+    bridged(
+        Protocol::OpStandardBridge,
+        info,
+        db_ctx,
+        BridgeDirection::Deposit,
+        initiated.from,
+        initiated.to,
+        NATIVE_TOKEN,
+        initiated.amount,
+    )
+}
+
+fn erc20_initiated<DB: DataContext<Protocol, ProtocolMetadata>>(
+    info: &CallInfo,
+    db_ctx: &DB,
+    initiated: IL1StandardBridge::ERC20BridgeInitiated,
+) -> eyre::Result<NormalizedBridge<Protocol>> {
+    bridged(
+        Protocol::OpStandardBridge,
+        info,
+        db_ctx,
+        BridgeDirection::Deposit,
+        initiated.from,
+        initiated.to,
+        initiated.localToken,
+        initiated.amount,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{Address, Bytes, U256, address};
+
+    use super::*;
+    use crate::test_utils::{TestRegistry, call_info};
+
+    const BRIDGE: Address = address!("99c9fc46f92e8a1c0dec1b1747d010903e884be1");
+    const USDC: Address = address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+    const OP_USDC: Address = address!("7f5c764cbc14f9669b88837ca1490cca17c31607");
+    const FROM: Address = address!("1000000000000000000000000000000000000001");
+    const TO: Address = address!("2000000000000000000000000000000000000002");
+
+    fn registry() -> TestRegistry {
+        TestRegistry::default().with_metadata(
+            BRIDGE,
+            Protocol::OpStandardBridge,
+            &[],
+            ProtocolMetadata {
+                l2_chain_id: Some(10),
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn eth_deposits_bridge_the_native_token() {
+        let initiated = IL1StandardBridge::ETHBridgeInitiated {
+            from: FROM,
+            to: TO,
+            amount: U256::from(7),
+            extraData: Bytes::new(),
+        };
+
+        let deposit = eth_initiated(&call_info(FROM, BRIDGE), &registry(), initiated).unwrap();
+        assert_eq!(deposit.direction, BridgeDirection::Deposit);
+        assert_eq!((deposit.from, deposit.recipient), (FROM, TO));
+        assert_eq!(deposit.token, NATIVE_TOKEN);
+        assert_eq!(deposit.amount, U256::from(7));
+        assert_eq!(deposit.l2_chain_id, 10);
+    }
+
+    #[test]
+    fn erc20_deposits_bridge_the_l1_token() {
+        let initiated = IL1StandardBridge::ERC20BridgeInitiated {
+            localToken: USDC,
+            remoteToken: OP_USDC,
+            from: FROM,
+            to: TO,
+            amount: U256::from(7),
+            extraData: Bytes::new(),
+        };
+
+        let deposit = erc20_initiated(&call_info(FROM, BRIDGE), &registry(), initiated).unwrap();
+        assert_eq!((deposit.from, deposit.recipient), (FROM, TO));
+        assert_eq!(deposit.token, USDC);
+        assert_eq!(deposit.bridge, BRIDGE);
+    }
+}
@@ -0,0 +1,183 @@
+use alloy_primitives::Address;
+use alloy_sol_types::sol;
+use brontes_classifier::{action_impl, context::DataContext, types::CallInfo};
+use brontes_classifier_types::{BridgeDirection, NATIVE_TOKEN, NormalizedBridge};
+
+use super::bridged;
+use crate::{Actions, Protocol, ProtocolMetadata};
+
+sol! {
+    interface IZkSyncMailbox {
+        event EthWithdrawalFinalized(address indexed to, uint256 amount);
+
+        function requestL2Transaction(
+            address _contractL2,
+            uint256 _l2Value,
+            bytes _calldata,
+            uint256 _l2GasLimit,
+            uint256 _l2GasPerPubdataByteLimit,
+            bytes[] _factoryDeps,
+            address _refundRecipient
+        ) external payable returns (bytes32 canonicalTxHash);
+        function finalizeEthWithdrawal(
+            uint256 _l2BatchNumber,
+            uint256 _l2MessageIndex,
+            uint16 _l2TxNumberInBatch,
+            bytes _message,
+            bytes32[] _merkleProof
+        ) external;
+    }
+
+    interface IZkSyncL1ERC20Bridge {
+        event DepositInitiated(
+            bytes32 indexed l2DepositTxHash,
+            address indexed from,
+            address indexed to,
+            address l1Token,
+            uint256 amount
+        );
+        event WithdrawalFinalized(address indexed to, address indexed l1Token, uint256 amount);
+
+        function deposit(
+            address _l2Receiver,
+            address _l1Token,
+            uint256 _amount,
+            uint256 _l2TxGasLimit,
+            uint256 _l2TxGasPerPubdataByte
+        ) external payable returns (bytes32 txHash);
+        function deposit(
+            address _l2Receiver,
+            address _l1Token,
+            uint256 _amount,
+            uint256 _l2TxGasLimit,
+            uint256 _l2TxGasPerPubdataByte,
+            address _refundRecipient
+        ) external payable returns (bytes32 txHash);
+        function finalizeWithdrawal(
+            uint256 _l2BatchNumber,
+            uint256 _l2MessageIndex,
+            uint16 _l2TxNumberInBatch,
+            bytes _message,
+            bytes32[] _merkleProof
+        ) external;
+    }
+}
+
+// eth moves through the era diamond's mailbox facet and tokens through the
+// erc20 bridge, which sends its own l2 transactions through the mailbox. only
+// mailbox requests without calldata are eth deposits. neither side reports
+// the l2 sender of a withdrawal.
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::ZkSyncMailbox,
+    IZkSyncMailbox::requestL2TransactionCall,
+    Bridge,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: requestL2TransactionCall, db_ctx: &DB| {
+        if !call_data._calldata.is_empty() {
+            return Err(eyre::eyre!("zksync l2 transaction with calldata isn't an eth deposit"));
+        }
+
+        bridged(
+            Protocol::ZkSyncMailbox,
+            &info,
+            db_ctx,
+            BridgeDirection::Deposit,
+            info.from_address,
+            call_data._contractL2,
+            NATIVE_TOKEN,
+            call_data._l2Value,
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::ZkSyncMailbox,
+    IZkSyncMailbox::finalizeEthWithdrawalCall,
+    Bridge,
+    [..EthWithdrawalFinalized],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: ZkSyncMailboxFinalizeEthWithdrawalCallLogs, db_ctx: &DB| {
+        let finalized = log_data.eth_withdrawal_finalized_field?;
+        bridged(
+            Protocol::ZkSyncMailbox,
+            &info,
+            db_ctx,
+            BridgeDirection::Withdraw,
+            Address::ZERO,
+            finalized.to,
+            NATIVE_TOKEN,
+            finalized.amount,
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::ZkSyncErc20Bridge,
+    IZkSyncL1ERC20Bridge::deposit_0Call,
+    Bridge,
+    [..DepositInitiated],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: ZkSyncErc20BridgeDeposit0CallLogs, db_ctx: &DB| {
+        deposit_initiated(&info, db_ctx, log_data.deposit_initiated_field?)
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::ZkSyncErc20Bridge,
+    IZkSyncL1ERC20Bridge::deposit_1Call,
+    Bridge,
+    [..DepositInitiated],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: ZkSyncErc20BridgeDeposit1CallLogs, db_ctx: &DB| {
+        deposit_initiated(&info, db_ctx, log_data.deposit_initiated_field?)
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::ZkSyncErc20Bridge,
+    IZkSyncL1ERC20Bridge::finalizeWithdrawalCall,
+    Bridge,
+    [..WithdrawalFinalized],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: ZkSyncErc20BridgeFinalizeWithdrawalCallLogs, db_ctx: &DB| {
+        let finalized = log_data.withdrawal_finalized_field?;
+        bridged(
+            Protocol::ZkSyncErc20Bridge,
+            &info,
+            db_ctx,
+            BridgeDirection::Withdraw,
+            Address::ZERO,
+            finalized.to,
+            finalized.l1Token,
+            finalized.amount,
+        )
+    }
+);
+
+fn deposit_initiated<DB: DataContext<Protocol, ProtocolMetadata>>(
+    info: &CallInfo,
+    db_ctx: &DB,
+    initiated: IZkSyncL1ERC20Bridge::DepositInitiated,
+) -> eyre::Result<NormalizedBridge<Protocol>> {
+    bridged(
+        Protocol::ZkSyncErc20Bridge,
+        info,
+        db_ctx,
+        BridgeDirection::Deposit,
+        initiated.from,
+        initiated.to,
+        initiated.l1Token,
+        initiated.amount,
+    )
+}
@@ -0,0 +1,9 @@
+mod v2;
+mod v3;
+
+pub use v2::*;
+pub use v3::*;
+
+// the asset a market lends out is its only registered token. ctokens are
+// registered with their underlying, cether with the native token, and comets
+// with their base token.
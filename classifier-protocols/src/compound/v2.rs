@@ -0,0 +1,292 @@
+use alloy_primitives::Address;
+use alloy_sol_types::sol;
+use brontes_classifier::{action_impl, context::DataContext, types::CallInfo};
+use brontes_classifier_types::{
+    NormalizedBorrow, NormalizedDeposit, NormalizedLiquidation, NormalizedRepay, NormalizedWithdraw,
+};
+
+use crate::{Actions, Protocol, ProtocolMetadata, registered_token};
+
+sol! {
+    interface ICToken {
+        event Mint(address minter, uint256 mintAmount, uint256 mintTokens);
+        event Redeem(address redeemer, uint256 redeemAmount, uint256 redeemTokens);
+        event Borrow(
+            address borrower,
+            uint256 borrowAmount,
+            uint256 accountBorrows,
+            uint256 totalBorrows
+        );
+        event RepayBorrow(
+            address payer,
+            address borrower,
+            uint256 repayAmount,
+            uint256 accountBorrows,
+            uint256 totalBorrows
+        );
+        event LiquidateBorrow(
+            address liquidator,
+            address borrower,
+            uint256 repayAmount,
+            address cTokenCollateral,
+            uint256 seizeTokens
+        );
+
+        // the payable overloads are cether's, which takes eth as call value
+        function mint(uint256 mintAmount) external returns (uint256);
+        function mint() external payable;
+        function redeem(uint256 redeemTokens) external returns (uint256);
+        function redeemUnderlying(uint256 redeemAmount) external returns (uint256);
+        function borrow(uint256 borrowAmount) external returns (uint256);
+        function repayBorrow(uint256 repayAmount) external returns (uint256);
+        function repayBorrow() external payable;
+        function repayBorrowBehalf(
+            address borrower,
+            uint256 repayAmount
+        ) external returns (uint256);
+        function repayBorrowBehalf(address borrower) external payable;
+        function liquidateBorrow(
+            address borrower,
+            uint256 repayAmount,
+            address cTokenCollateral
+        ) external returns (uint256);
+        function liquidateBorrow(address borrower, address cTokenCollateral) external payable;
+    }
+}
+
+// the market is the ctoken itself. erc20 markets delegate every call to their
+// implementation, which emits the events. failing calls return an error code
+// instead of reverting and emit no event, so they aren't classified.
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CompoundV2,
+    ICToken::mint_0Call,
+    Deposit,
+    [..Mint],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: CompoundV2Mint0CallLogs, db_ctx: &DB| {
+        let asset = registered_token(db_ctx, info.target_address)?;
+        Ok(minted(&info, asset, log_data.mint_field?))
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CompoundV2,
+    ICToken::mint_1Call,
+    Deposit,
+    [..Mint],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: CompoundV2Mint1CallLogs, db_ctx: &DB| {
+        let asset = registered_token(db_ctx, info.target_address)?;
+        Ok(minted(&info, asset, log_data.mint_field?))
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CompoundV2,
+    ICToken::redeemCall,
+    Withdraw,
+    [..Redeem],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: CompoundV2RedeemCallLogs, db_ctx: &DB| {
+        let asset = registered_token(db_ctx, info.target_address)?;
+        Ok(redeemed(&info, asset, log_data.redeem_field?))
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CompoundV2,
+    ICToken::redeemUnderlyingCall,
+    Withdraw,
+    [..Redeem],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: CompoundV2RedeemUnderlyingCallLogs, db_ctx: &DB| {
+        let asset = registered_token(db_ctx, info.target_address)?;
+        Ok(redeemed(&info, asset, log_data.redeem_field?))
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CompoundV2,
+    ICToken::borrowCall,
+    Borrow,
+    [..Borrow],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: CompoundV2BorrowCallLogs, db_ctx: &DB| {
+        let borrow = log_data.borrow_field?;
+
+        Ok(NormalizedBorrow {
+            protocol: Protocol::CompoundV2,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            from: info.from_address,
+            borrower: borrow.borrower,
+            recipient: borrow.borrower,
+            asset: registered_token(db_ctx, info.target_address)?,
+            amount: borrow.borrowAmount,
+        })
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CompoundV2,
+    ICToken::repayBorrow_0Call,
+    Repay,
+    [..RepayBorrow],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: CompoundV2RepayBorrow0CallLogs, db_ctx: &DB| {
+        let asset = registered_token(db_ctx, info.target_address)?;
+        Ok(repaid(&info, asset, log_data.repay_borrow_field?))
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CompoundV2,
+    ICToken::repayBorrow_1Call,
+    Repay,
+    [..RepayBorrow],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: CompoundV2RepayBorrow1CallLogs, db_ctx: &DB| {
+        let asset = registered_token(db_ctx, info.target_address)?;
+        Ok(repaid(&info, asset, log_data.repay_borrow_field?))
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CompoundV2,
+    ICToken::repayBorrowBehalf_0Call,
+    Repay,
+    [..RepayBorrow],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: CompoundV2RepayBorrowBehalf0CallLogs, db_ctx: &DB| {
+        let asset = registered_token(db_ctx, info.target_address)?;
+        Ok(repaid(&info, asset, log_data.repay_borrow_field?))
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CompoundV2,
+    ICToken::repayBorrowBehalf_1Call,
+    Repay,
+    [..RepayBorrow],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: CompoundV2RepayBorrowBehalf1CallLogs, db_ctx: &DB| {
+        let asset = registered_token(db_ctx, info.target_address)?;
+        Ok(repaid(&info, asset, log_data.repay_borrow_field?))
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CompoundV2,
+    ICToken::liquidateBorrow_0Call,
+    Liquidation,
+    [..LiquidateBorrow],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: CompoundV2LiquidateBorrow0CallLogs, db_ctx: &DB| {
+        liquidated(db_ctx, &info, log_data.liquidate_borrow_field?)
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CompoundV2,
+    ICToken::liquidateBorrow_1Call,
+    Liquidation,
+    [..LiquidateBorrow],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: CompoundV2LiquidateBorrow1CallLogs, db_ctx: &DB| {
+        liquidated(db_ctx, &info, log_data.liquidate_borrow_field?)
+    }
+);
+
+/// `shares` are the ctokens minted for the deposit
+fn minted(info: &CallInfo, asset: Address, mint: ICToken::Mint) -> NormalizedDeposit<Protocol> {
+    NormalizedDeposit {
+        protocol: Protocol::CompoundV2,
+        trace_index: info.trace_idx,
+        pool: info.target_address,
+        from: info.from_address,
+        recipient: mint.minter,
+        asset,
+        amount: mint.mintAmount,
+        shares: Some(mint.mintTokens),
+    }
+}
+
+/// `shares` are the ctokens burnt for the withdrawal
+fn redeemed(
+    info: &CallInfo,
+    asset: Address,
+    redeem: ICToken::Redeem,
+) -> NormalizedWithdraw<Protocol> {
+    NormalizedWithdraw {
+        protocol: Protocol::CompoundV2,
+        trace_index: info.trace_idx,
+        pool: info.target_address,
+        from: info.from_address,
+        owner: redeem.redeemer,
+        recipient: redeem.redeemer,
+        asset,
+        amount: redeem.redeemAmount,
+        shares: Some(redeem.redeemTokens),
+    }
+}
+
+fn repaid(
+    info: &CallInfo,
+    asset: Address,
+    repay: ICToken::RepayBorrow,
+) -> NormalizedRepay<Protocol> {
+    NormalizedRepay {
+        protocol: Protocol::CompoundV2,
+        trace_index: info.trace_idx,
+        pool: info.target_address,
+        from: repay.payer,
+        borrower: repay.borrower,
+        asset,
+        amount: repay.repayAmount,
+    }
+}
+
+/// the collateral is seized as ctokens of the collateral market. the collateral
+/// asset is that market's underlying, the seized amount stays in ctokens and is
+/// marked as such through `collateral_share_token`
+fn liquidated<DB: DataContext<Protocol, ProtocolMetadata>>(
+    db_ctx: &DB,
+    info: &CallInfo,
+    liquidation: ICToken::LiquidateBorrow,
+) -> eyre::Result<NormalizedLiquidation<Protocol>> {
+    Ok(NormalizedLiquidation {
+        protocol: Protocol::CompoundV2,
+        trace_index: info.trace_idx,
+        pool: info.target_address,
+        liquidator: liquidation.liquidator,
+        debtor: liquidation.borrower,
+        collateral_asset: registered_token(db_ctx, liquidation.cTokenCollateral)?,
+        debt_asset: registered_token(db_ctx, info.target_address)?,
+        covered_debt: liquidation.repayAmount,
+        liquidated_collateral: liquidation.seizeTokens,
+        collateral_share_token: Some(liquidation.cTokenCollateral),
+    })
+}
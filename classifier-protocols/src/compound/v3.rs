@@ -0,0 +1,602 @@
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::sol;
+use brontes_classifier::{action_impl, types::CallInfo};
+use brontes_classifier_types::{
+    NormalizedBorrow, NormalizedDeposit, NormalizedLiquidation, NormalizedMultiLiquidation,
+    NormalizedRepay, NormalizedWithdraw,
+};
+
+use crate::{Actions, Protocol, ProtocolMetadata, registered_token};
+
+sol! {
+    interface IComet {
+        event Supply(address indexed from, address indexed dst, uint256 amount);
+        event SupplyCollateral(
+            address indexed from,
+            address indexed dst,
+            address indexed asset,
+            uint256 amount
+        );
+        event Withdraw(address indexed src, address indexed to, uint256 amount);
+        event WithdrawCollateral(
+            address indexed src,
+            address indexed to,
+            address indexed asset,
+            uint256 amount
+        );
+        event Transfer(address indexed from, address indexed to, uint256 amount);
+        event AbsorbDebt(
+            address indexed absorber,
+            address indexed borrower,
+            uint256 basePaidOut,
+            uint256 usdValue
+        );
+        event AbsorbCollateral(
+            address indexed absorber,
+            address indexed borrower,
+            address indexed asset,
+            uint256 collateralAbsorbed,
+            uint256 usdValue
+        );
+        event BuyCollateral(
+            address indexed buyer,
+            address indexed asset,
+            uint256 baseAmount,
+            uint256 collateralAmount
+        );
+
+        function supply(address asset, uint256 amount) external;
+        function supplyTo(address dst, address asset, uint256 amount) external;
+        function supplyFrom(address from, address dst, address asset, uint256 amount) external;
+        function withdraw(address asset, uint256 amount) external;
+        function withdrawTo(address to, address asset, uint256 amount) external;
+        function withdrawFrom(address src, address to, address asset, uint256 amount) external;
+        function absorb(address absorber, address[] accounts) external;
+        function buyCollateral(
+            address asset,
+            uint256 minAmount,
+            uint256 baseAmount,
+            address recipient
+        ) external;
+    }
+}
+
+// a comet lends out a single base token against several collaterals and is
+// called through its proxy. base supplies first repay the account's debt and
+// withdrawals past its balance borrow, only the part going to or from the
+// balance is followed by a `Transfer` of the comet's own balance token.
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CompoundV3,
+    IComet::supplyCall,
+    Actions,
+    [..(Supply | SupplyCollateral), ..Transfer*],
+    call_data: true,
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, call_data: supplyCall, log_data: CompoundV3SupplyCallLogs, _db_ctx: &DB| {
+        comet_supply(
+            &info,
+            call_data.asset,
+            log_data.supply_field,
+            log_data.supply_collateral_field,
+            log_data.transfer_field?,
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CompoundV3,
+    IComet::supplyToCall,
+    Actions,
+    [..(Supply | SupplyCollateral), ..Transfer*],
+    call_data: true,
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, call_data: supplyToCall, log_data: CompoundV3SupplyToCallLogs, _db_ctx: &DB| {
+        comet_supply(
+            &info,
+            call_data.asset,
+            log_data.supply_field,
+            log_data.supply_collateral_field,
+            log_data.transfer_field?,
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CompoundV3,
+    IComet::supplyFromCall,
+    Actions,
+    [..(Supply | SupplyCollateral), ..Transfer*],
+    call_data: true,
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo,
+    call_data: supplyFromCall,
+    log_data: CompoundV3SupplyFromCallLogs,
+    _db_ctx: &DB| {
+        comet_supply(
+            &info,
+            call_data.asset,
+            log_data.supply_field,
+            log_data.supply_collateral_field,
+            log_data.transfer_field?,
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CompoundV3,
+    IComet::withdrawCall,
+    Actions,
+    [..(Withdraw | WithdrawCollateral), ..Transfer*],
+    call_data: true,
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, call_data: withdrawCall, log_data: CompoundV3WithdrawCallLogs, _db_ctx: &DB| {
+        comet_withdraw(
+            &info,
+            call_data.asset,
+            log_data.withdraw_field,
+            log_data.withdraw_collateral_field,
+            log_data.transfer_field?,
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CompoundV3,
+    IComet::withdrawToCall,
+    Actions,
+    [..(Withdraw | WithdrawCollateral), ..Transfer*],
+    call_data: true,
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo,
+    call_data: withdrawToCall,
+    log_data: CompoundV3WithdrawToCallLogs,
+    _db_ctx: &DB| {
+        comet_withdraw(
+            &info,
+            call_data.asset,
+            log_data.withdraw_field,
+            log_data.withdraw_collateral_field,
+            log_data.transfer_field?,
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CompoundV3,
+    IComet::withdrawFromCall,
+    Actions,
+    [..(Withdraw | WithdrawCollateral), ..Transfer*],
+    call_data: true,
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo,
+    call_data: withdrawFromCall,
+    log_data: CompoundV3WithdrawFromCallLogs,
+    _db_ctx: &DB| {
+        comet_withdraw(
+            &info,
+            call_data.asset,
+            log_data.withdraw_field,
+            log_data.withdraw_collateral_field,
+            log_data.transfer_field?,
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CompoundV3,
+    IComet::absorbCall,
+    MultiLiquidation,
+    [..(AbsorbCollateral | AbsorbDebt)*],
+    call_data: true,
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, call_data: absorbCall, log_data: CompoundV3AbsorbCallLogs, db_ctx: &DB| {
+        let base = registered_token(db_ctx, info.target_address)?;
+        let liquidations = absorbed(
+            &info,
+            call_data.absorber,
+            base,
+            &log_data.absorb_collateral_field?,
+            log_data.absorb_debt_field?,
+        );
+
+        Ok(NormalizedMultiLiquidation {
+            protocol: Protocol::CompoundV3,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            liquidator: call_data.absorber,
+            liquidations,
+        })
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CompoundV3,
+    IComet::buyCollateralCall,
+    Liquidation,
+    [..BuyCollateral],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: CompoundV3BuyCollateralCallLogs, db_ctx: &DB| {
+        let bought = log_data.buy_collateral_field?;
+
+        // the second half of an absorb, the buyer pays base to take over the
+        // collateral the comet seized, so the comet is the debtor
+        Ok(NormalizedLiquidation {
+            protocol: Protocol::CompoundV3,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            liquidator: bought.buyer,
+            debtor: info.target_address,
+            collateral_asset: bought.asset,
+            debt_asset: registered_token(db_ctx, info.target_address)?,
+            covered_debt: bought.baseAmount,
+            liquidated_collateral: bought.collateralAmount,
+            collateral_share_token: None,
+        })
+    }
+);
+
+/// the debt absorbed from an account is reported on its first collateral,
+/// accounts without collateral get a single row with a zero collateral
+fn absorbed(
+    info: &CallInfo,
+    absorber: Address,
+    base: Address,
+    collaterals: &[IComet::AbsorbCollateral],
+    debts: Vec<IComet::AbsorbDebt>,
+) -> Vec<NormalizedLiquidation<Protocol>> {
+    let mut liquidations = Vec::new();
+    for debt in debts {
+        let mut seized = collaterals
+            .iter()
+            .filter(|collateral| collateral.borrower == debt.borrower)
+            .map(|collateral| (collateral.asset, collateral.collateralAbsorbed))
+            .collect::<Vec<_>>();
+        if seized.is_empty() {
+            seized.push((Address::ZERO, U256::ZERO));
+        }
+
+        for (i, (asset, amount)) in seized.into_iter().enumerate() {
+            liquidations.push(NormalizedLiquidation {
+                protocol: Protocol::CompoundV3,
+                trace_index: info.trace_idx,
+                pool: info.target_address,
+                liquidator: absorber,
+                debtor: debt.borrower,
+                collateral_asset: asset,
+                debt_asset: base,
+                covered_debt: if i == 0 { debt.basePaidOut } else { U256::ZERO },
+                liquidated_collateral: amount,
+                collateral_share_token: None,
+            });
+        }
+    }
+
+    liquidations
+}
+
+/// a base supply that only repays debt mints no balance. one repaying the
+/// debt and supplying the rest is reported as a deposit of the whole amount
+fn comet_supply(
+    info: &CallInfo,
+    asset: Address,
+    supply: eyre::Result<IComet::Supply>,
+    collateral: eyre::Result<IComet::SupplyCollateral>,
+    transfers: Vec<IComet::Transfer>,
+) -> eyre::Result<Actions> {
+    if let Ok(collateral) = collateral {
+        return Ok(Actions::Deposit(comet_deposit(
+            info,
+            collateral.dst,
+            collateral.asset,
+            collateral.amount,
+        )));
+    }
+
+    let supply = supply?;
+    if transfers.is_empty() {
+        return Ok(Actions::Repay(NormalizedRepay {
+            protocol: Protocol::CompoundV3,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            from: info.from_address,
+            borrower: supply.dst,
+            asset,
+            amount: supply.amount,
+        }));
+    }
+
+    Ok(Actions::Deposit(comet_deposit(
+        info,
+        supply.dst,
+        asset,
+        supply.amount,
+    )))
+}
+
+/// a base withdrawal that only borrows burns no balance. one withdrawing the
+/// balance and borrowing the rest is reported as a withdrawal of the whole
+/// amount
+fn comet_withdraw(
+    info: &CallInfo,
+    asset: Address,
+    withdraw: eyre::Result<IComet::Withdraw>,
+    collateral: eyre::Result<IComet::WithdrawCollateral>,
+    transfers: Vec<IComet::Transfer>,
+) -> eyre::Result<Actions> {
+    if let Ok(collateral) = collateral {
+        return Ok(Actions::Withdraw(comet_withdrawal(
+            info,
+            collateral.src,
+            collateral.to,
+            collateral.asset,
+            collateral.amount,
+        )));
+    }
+
+    let withdraw = withdraw?;
+    if transfers.is_empty() {
+        return Ok(Actions::Borrow(NormalizedBorrow {
+            protocol: Protocol::CompoundV3,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            from: info.from_address,
+            borrower: withdraw.src,
+            recipient: withdraw.to,
+            asset,
+            amount: withdraw.amount,
+        }));
+    }
+
+    Ok(Actions::Withdraw(comet_withdrawal(
+        info,
+        withdraw.src,
+        withdraw.to,
+        asset,
+        withdraw.amount,
+    )))
+}
+
+fn comet_deposit(
+    info: &CallInfo,
+    recipient: Address,
+    asset: Address,
+    amount: U256,
+) -> NormalizedDeposit<Protocol> {
+    NormalizedDeposit {
+        protocol: Protocol::CompoundV3,
+        trace_index: info.trace_idx,
+        pool: info.target_address,
+        from: info.from_address,
+        recipient,
+        asset,
+        amount,
+        shares: None,
+    }
+}
+
+fn comet_withdrawal(
+    info: &CallInfo,
+    owner: Address,
+    recipient: Address,
+    asset: Address,
+    amount: U256,
+) -> NormalizedWithdraw<Protocol> {
+    NormalizedWithdraw {
+        protocol: Protocol::CompoundV3,
+        trace_index: info.trace_idx,
+        pool: info.target_address,
+        from: info.from_address,
+        owner,
+        recipient,
+        asset,
+        amount,
+        shares: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::address;
+
+    use super::*;
+    use crate::test_utils::call_info;
+
+    const COMET: Address = address!("c3d688b66703497daa19211eedff47f25384cdc3");
+    const USDC: Address = address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+    const WETH: Address = address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2");
+    const WBTC: Address = address!("2260fac5e5542a773aa44fbcfedf7c193bc2c599");
+    const USER: Address = address!("1000000000000000000000000000000000000001");
+    const ALICE: Address = address!("2000000000000000000000000000000000000002");
+    const BOB: Address = address!("3000000000000000000000000000000000000003");
+    const ABSORBER: Address = address!("4000000000000000000000000000000000000004");
+
+    fn debt(borrower: Address, paid_out: u64) -> IComet::AbsorbDebt {
+        IComet::AbsorbDebt {
+            absorber: ABSORBER,
+            borrower,
+            basePaidOut: U256::from(paid_out),
+            usdValue: U256::ZERO,
+        }
+    }
+
+    fn collateral(borrower: Address, asset: Address, amount: u64) -> IComet::AbsorbCollateral {
+        IComet::AbsorbCollateral {
+            absorber: ABSORBER,
+            borrower,
+            asset,
+            collateralAbsorbed: U256::from(amount),
+            usdValue: U256::ZERO,
+        }
+    }
+
+    fn supply() -> eyre::Result<IComet::Supply> {
+        Ok(IComet::Supply {
+            from: USER,
+            dst: ALICE,
+            amount: U256::from(100),
+        })
+    }
+
+    fn withdraw() -> eyre::Result<IComet::Withdraw> {
+        Ok(IComet::Withdraw {
+            src: ALICE,
+            to: USER,
+            amount: U256::from(100),
+        })
+    }
+
+    fn missing<T>() -> eyre::Result<T> {
+        Err(eyre::eyre!("missing"))
+    }
+
+    fn transfer() -> IComet::Transfer {
+        IComet::Transfer {
+            from: Address::ZERO,
+            to: ALICE,
+            amount: U256::from(40),
+        }
+    }
+
+    #[test]
+    fn groups_absorbed_collateral_by_account() {
+        let info = call_info(ABSORBER, COMET);
+        let liquidations = absorbed(
+            &info,
+            ABSORBER,
+            USDC,
+            &[
+                collateral(ALICE, WETH, 3),
+                collateral(BOB, WBTC, 5),
+                collateral(ALICE, WBTC, 7),
+            ],
+            vec![debt(ALICE, 100), debt(BOB, 200)],
+        );
+
+        let rows = liquidations
+            .iter()
+            .map(|liquidation| {
+                (
+                    liquidation.debtor,
+                    liquidation.collateral_asset,
+                    liquidation.liquidated_collateral,
+                    liquidation.covered_debt,
+                )
+            })
+            .collect::<Vec<_>>();
+        // the debt is only counted once, on the account's first collateral
+        assert_eq!(
+            rows,
+            vec![
+                (ALICE, WETH, U256::from(3), U256::from(100)),
+                (ALICE, WBTC, U256::from(7), U256::ZERO),
+                (BOB, WBTC, U256::from(5), U256::from(200)),
+            ]
+        );
+        assert!(
+            liquidations
+                .iter()
+                .all(|liquidation| liquidation.debt_asset == USDC)
+        );
+    }
+
+    #[test]
+    fn absorbs_accounts_without_collateral() {
+        let info = call_info(ABSORBER, COMET);
+        let liquidations = absorbed(&info, ABSORBER, USDC, &[], vec![debt(ALICE, 100)]);
+
+        assert_eq!(liquidations.len(), 1);
+        assert_eq!(liquidations[0].collateral_asset, Address::ZERO);
+        assert_eq!(liquidations[0].liquidated_collateral, U256::ZERO);
+        assert_eq!(liquidations[0].covered_debt, U256::from(100));
+    }
+
+    #[test]
+    fn supplies_without_a_balance_transfer_repay() {
+        let info = call_info(USER, COMET);
+
+        let Actions::Repay(repay) = comet_supply(&info, USDC, supply(), missing(), vec![]).unwrap()
+        else {
+            panic!("expected a repay");
+        };
+        assert_eq!((repay.borrower, repay.amount), (ALICE, U256::from(100)));
+
+        // a supply repaying part of the debt still deposits the whole amount
+        let Actions::Deposit(deposit) =
+            comet_supply(&info, USDC, supply(), missing(), vec![transfer()]).unwrap()
+        else {
+            panic!("expected a deposit");
+        };
+        assert_eq!(
+            (deposit.recipient, deposit.amount),
+            (ALICE, U256::from(100))
+        );
+
+        let collateral = IComet::SupplyCollateral {
+            from: USER,
+            dst: ALICE,
+            asset: WETH,
+            amount: U256::from(3),
+        };
+        let Actions::Deposit(deposit) =
+            comet_supply(&info, WETH, missing(), Ok(collateral), vec![]).unwrap()
+        else {
+            panic!("expected a deposit");
+        };
+        assert_eq!((deposit.asset, deposit.amount), (WETH, U256::from(3)));
+    }
+
+    #[test]
+    fn withdrawals_without_a_balance_transfer_borrow() {
+        let info = call_info(USER, COMET);
+
+        let Actions::Borrow(borrow) =
+            comet_withdraw(&info, USDC, withdraw(), missing(), vec![]).unwrap()
+        else {
+            panic!("expected a borrow");
+        };
+        assert_eq!(
+            (borrow.borrower, borrow.recipient, borrow.amount),
+            (ALICE, USER, U256::from(100))
+        );
+
+        let Actions::Withdraw(withdrawal) =
+            comet_withdraw(&info, USDC, withdraw(), missing(), vec![transfer()]).unwrap()
+        else {
+            panic!("expected a withdrawal");
+        };
+        assert_eq!(
+            (withdrawal.owner, withdrawal.recipient, withdrawal.amount),
+            (ALICE, USER, U256::from(100))
+        );
+
+        let collateral = IComet::WithdrawCollateral {
+            src: ALICE,
+            to: USER,
+            asset: WETH,
+            amount: U256::from(3),
+        };
+        let Actions::Withdraw(withdrawal) =
+            comet_withdraw(&info, WETH, missing(), Ok(collateral), vec![]).unwrap()
+        else {
+            panic!("expected a withdrawal");
+        };
+        assert_eq!((withdrawal.asset, withdrawal.amount), (WETH, U256::from(3)));
+    }
+}
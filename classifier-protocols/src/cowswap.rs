@@ -0,0 +1,182 @@
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::sol;
+use brontes_classifier::{action_impl, types::CallInfo};
+use brontes_classifier_types::{
+    NormalizedBatch, NormalizedInteraction, NormalizedSwap, NormalizedSwapWithFee,
+};
+
+use crate::{Actions, Protocol, ProtocolMetadata};
+
+sol! {
+    interface IGPv2Settlement {
+        struct TradeData {
+            uint256 sellTokenIndex;
+            uint256 buyTokenIndex;
+            address receiver;
+            uint256 sellAmount;
+            uint256 buyAmount;
+            uint32 validTo;
+            bytes32 appData;
+            uint256 feeAmount;
+            uint256 flags;
+            uint256 executedAmount;
+            bytes signature;
+        }
+
+        struct InteractionData {
+            address target;
+            uint256 value;
+            bytes callData;
+        }
+
+        event Trade(
+            address indexed owner,
+            address sellToken,
+            address buyToken,
+            uint256 sellAmount,
+            uint256 buyAmount,
+            uint256 feeAmount,
+            bytes orderUid
+        );
+        event Interaction(address indexed target, uint256 value, bytes4 selector);
+        event Settlement(address indexed solver);
+
+        function settle(
+            address[] tokens,
+            uint256[] clearingPrices,
+            TradeData[] trades,
+            InteractionData[][3] interactions
+        ) external;
+    }
+}
+
+// the settlement emits a trade per filled order, in the order of the call's
+// trades, and an interaction per call the solver had it make before, between
+// and after the trades. the events carry the executed amounts, the call only
+// adds the receiver of each order. fees are taken in the sell token and are
+// part of the trade's sell amount.
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CowSwap,
+    IGPv2Settlement::settleCall,
+    Batch,
+    [..(Trade | Interaction)*, ..Settlement],
+    call_data: true,
+    logs: true,
+    |info: CallInfo,
+    call_data: settleCall,
+    log_data: CowSwapSettleCallLogs,
+    _db_ctx: &DB| {
+        let trades = log_data.trade_field?;
+        if trades.len() != call_data.trades.len() {
+            return Err(eyre::eyre!(
+                "cow settlement with {} trades emitted {} trade events",
+                call_data.trades.len(),
+                trades.len()
+            ));
+        }
+
+        let user_swaps = trades
+            .into_iter()
+            .zip(&call_data.trades)
+            .map(|(trade, order)| traded(&info, trade, order.receiver))
+            .collect();
+        let solver_interactions = log_data
+            .interaction_field?
+            .into_iter()
+            .map(|interaction| NormalizedInteraction {
+                target: interaction.target,
+                value: interaction.value,
+                selector: interaction.selector,
+            })
+            .collect();
+
+        Ok(NormalizedBatch {
+            protocol: Protocol::CowSwap,
+            trace_index: info.trace_idx,
+            solver: log_data.settlement_field?.solver,
+            settlement_contract: info.target_address,
+            user_swaps,
+            solver_interactions,
+            child_trace_indices: info.child_trace_indices.clone(),
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+/// an order filled by the settlement, a zero receiver pays out to the owner
+fn traded(
+    info: &CallInfo,
+    trade: IGPv2Settlement::Trade,
+    receiver: Address,
+) -> NormalizedSwapWithFee<Protocol> {
+    NormalizedSwapWithFee {
+        swap: NormalizedSwap {
+            protocol: Protocol::CowSwap,
+            trace_index: info.trace_idx,
+            from: trade.owner,
+            recipient: if receiver == Address::ZERO {
+                trade.owner
+            } else {
+                receiver
+            },
+            pool: info.target_address,
+            pool_id: None,
+            token_in: trade.sellToken,
+            token_out: trade.buyToken,
+            amount_in: trade.sellAmount,
+            amount_out: trade.buyAmount,
+            msg_value: U256::ZERO,
+        },
+        fee_token: trade.sellToken,
+        fee_amount: trade.feeAmount,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{Bytes, address};
+
+    use super::*;
+    use crate::test_utils::call_info;
+
+    const SETTLEMENT: Address = address!("9008d19f58aabd9ed0d60971565aa8510560ab41");
+    const SOLVER: Address = address!("1000000000000000000000000000000000000001");
+    const OWNER: Address = address!("2000000000000000000000000000000000000002");
+    const RECEIVER: Address = address!("3000000000000000000000000000000000000003");
+    const WETH: Address = address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2");
+    const USDC: Address = address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+
+    fn trade() -> IGPv2Settlement::Trade {
+        IGPv2Settlement::Trade {
+            owner: OWNER,
+            sellToken: WETH,
+            buyToken: USDC,
+            sellAmount: U256::from(100),
+            buyAmount: U256::from(250),
+            feeAmount: U256::from(3),
+            orderUid: Bytes::new(),
+        }
+    }
+
+    #[test]
+    fn zero_receiver_pays_out_to_the_owner() {
+        let swap = traded(&call_info(SOLVER, SETTLEMENT), trade(), Address::ZERO);
+
+        assert_eq!(swap.swap.from, OWNER);
+        assert_eq!(swap.swap.recipient, OWNER);
+    }
+
+    #[test]
+    fn trade_maps_to_a_swap_with_a_sell_token_fee() {
+        let swap = traded(&call_info(SOLVER, SETTLEMENT), trade(), RECEIVER);
+
+        assert_eq!(swap.swap.recipient, RECEIVER);
+        assert_eq!(swap.swap.pool, SETTLEMENT);
+        assert_eq!((swap.swap.token_in, swap.swap.token_out), (WETH, USDC));
+        assert_eq!(swap.swap.amount_in, U256::from(100));
+        assert_eq!(swap.swap.amount_out, U256::from(250));
+        assert_eq!((swap.fee_token, swap.fee_amount), (WETH, U256::from(3)));
+    }
+}
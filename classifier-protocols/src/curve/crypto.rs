@@ -0,0 +1,872 @@
+use alloy_primitives::U256;
+use alloy_sol_types::sol;
+use brontes_classifier::{action_impl, types::CallInfo};
+
+use super::{curve_burn, curve_burn_one, curve_mint, curve_swap, eth_coins, pool_coins};
+use crate::{Actions, Protocol, ProtocolMetadata};
+
+sol! {
+    /// cryptoswap pools index their coins with `uint256`. overloads are
+    /// numbered in declaration order, `use_eth` pays and receives the wrapped
+    /// native coin as eth and the ng pools add a receiver.
+    interface ICurveCryptoSwap {
+        event TokenExchange(
+            address indexed buyer,
+            uint256 sold_id,
+            uint256 tokens_sold,
+            uint256 bought_id,
+            uint256 tokens_bought
+        );
+        event TokenExchange(
+            address indexed buyer,
+            uint256 sold_id,
+            uint256 tokens_sold,
+            uint256 bought_id,
+            uint256 tokens_bought,
+            uint256 fee,
+            uint256 packed_price_scale
+        );
+        event AddLiquidity(
+            address indexed provider,
+            uint256[2] token_amounts,
+            uint256 fee,
+            uint256 token_supply
+        );
+        event AddLiquidity(
+            address indexed provider,
+            uint256[3] token_amounts,
+            uint256 fee,
+            uint256 token_supply
+        );
+        event AddLiquidity(
+            address indexed provider,
+            address indexed receiver,
+            uint256[2] token_amounts,
+            uint256 fee,
+            uint256 token_supply,
+            uint256 packed_price_scale
+        );
+        event AddLiquidity(
+            address indexed provider,
+            uint256[3] token_amounts,
+            uint256 fee,
+            uint256 token_supply,
+            uint256 packed_price_scale
+        );
+        event RemoveLiquidity(
+            address indexed provider,
+            uint256[2] token_amounts,
+            uint256 token_supply
+        );
+        event RemoveLiquidity(
+            address indexed provider,
+            uint256[3] token_amounts,
+            uint256 token_supply
+        );
+        event RemoveLiquidityOne(
+            address indexed provider,
+            uint256 token_amount,
+            uint256 coin_index,
+            uint256 coin_amount
+        );
+        event RemoveLiquidityOne(
+            address indexed provider,
+            uint256 token_amount,
+            uint256 coin_index,
+            uint256 coin_amount,
+            uint256 approx_fee,
+            uint256 packed_price_scale
+        );
+
+        function exchange(uint256 i, uint256 j, uint256 dx, uint256 min_dy) external payable;
+        function exchange(uint256 i, uint256 j, uint256 dx, uint256 min_dy, bool use_eth)
+            external payable;
+        function exchange(
+            uint256 i,
+            uint256 j,
+            uint256 dx,
+            uint256 min_dy,
+            bool use_eth,
+            address receiver
+        ) external payable;
+        function exchange_underlying(uint256 i, uint256 j, uint256 dx, uint256 min_dy)
+            external payable;
+        function exchange_underlying(
+            uint256 i,
+            uint256 j,
+            uint256 dx,
+            uint256 min_dy,
+            address receiver
+        ) external payable;
+
+        function add_liquidity(uint256[2] amounts, uint256 min_mint_amount) external payable;
+        function add_liquidity(uint256[3] amounts, uint256 min_mint_amount) external payable;
+        function add_liquidity(uint256[2] amounts, uint256 min_mint_amount, bool use_eth)
+            external payable;
+        function add_liquidity(uint256[3] amounts, uint256 min_mint_amount, bool use_eth)
+            external payable;
+        function add_liquidity(uint256[2] amounts, uint256 min_mint_amount, address receiver)
+            external payable;
+        function add_liquidity(
+            uint256[3] amounts,
+            uint256 min_mint_amount,
+            bool use_eth,
+            address receiver
+        ) external payable;
+
+        function remove_liquidity(uint256 amount, uint256[2] min_amounts) external;
+        function remove_liquidity(uint256 amount, uint256[3] min_amounts) external;
+        function remove_liquidity(uint256 amount, uint256[2] min_amounts, bool use_eth)
+            external;
+        function remove_liquidity(uint256 amount, uint256[3] min_amounts, bool use_eth)
+            external;
+        function remove_liquidity(uint256 amount, uint256[2] min_amounts, address receiver)
+            external;
+        function remove_liquidity(
+            uint256 amount,
+            uint256[3] min_amounts,
+            bool use_eth,
+            address receiver
+        ) external;
+
+        function remove_liquidity_one_coin(uint256 token_amount, uint256 i, uint256 min_amount)
+            external;
+        function remove_liquidity_one_coin(
+            uint256 token_amount,
+            uint256 i,
+            uint256 min_amount,
+            bool use_eth
+        ) external;
+        function remove_liquidity_one_coin(
+            uint256 token_amount,
+            uint256 i,
+            uint256 min_amount,
+            bool use_eth,
+            address receiver
+        ) external;
+        function remove_liquidity_one_coin(
+            uint256 token_amount,
+            uint256 i,
+            uint256 min_amount,
+            address receiver
+        ) external;
+    }
+}
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveCrypto,
+    ICurveCryptoSwap::exchange_0Call,
+    Swap,
+    [..(TokenExchange_0 | TokenExchange_1)],
+    logs: true,
+    |info: CallInfo,
+    log_data: CurveCryptoExchange0CallLogs,
+    db_ctx: &DB| {
+        let (sold_id, tokens_sold, bought_id, tokens_bought) = exchanged(
+            log_data.token_exchange_0_field,
+            log_data.token_exchange_1_field,
+        )?;
+        let coins = eth_coins(
+            db_ctx,
+            pool_coins(db_ctx, info.protocol_address, false)?,
+            false,
+        );
+
+        curve_swap(
+            Protocol::CurveCrypto,
+            &info,
+            info.from_address,
+            &coins,
+            sold_id,
+            tokens_sold,
+            bought_id,
+            tokens_bought,
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveCrypto,
+    ICurveCryptoSwap::exchange_1Call,
+    Swap,
+    [..(TokenExchange_0 | TokenExchange_1)],
+    call_data: true,
+    logs: true,
+    |info: CallInfo,
+    call_data: exchange_1Call,
+    log_data: CurveCryptoExchange1CallLogs,
+    db_ctx: &DB| {
+        let (sold_id, tokens_sold, bought_id, tokens_bought) = exchanged(
+            log_data.token_exchange_0_field,
+            log_data.token_exchange_1_field,
+        )?;
+        let coins = eth_coins(
+            db_ctx,
+            pool_coins(db_ctx, info.protocol_address, false)?,
+            call_data.use_eth,
+        );
+
+        curve_swap(
+            Protocol::CurveCrypto,
+            &info,
+            info.from_address,
+            &coins,
+            sold_id,
+            tokens_sold,
+            bought_id,
+            tokens_bought,
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveCrypto,
+    ICurveCryptoSwap::exchange_2Call,
+    Swap,
+    [..(TokenExchange_0 | TokenExchange_1)],
+    call_data: true,
+    logs: true,
+    |info: CallInfo,
+    call_data: exchange_2Call,
+    log_data: CurveCryptoExchange2CallLogs,
+    db_ctx: &DB| {
+        let (sold_id, tokens_sold, bought_id, tokens_bought) = exchanged(
+            log_data.token_exchange_0_field,
+            log_data.token_exchange_1_field,
+        )?;
+        let coins = eth_coins(
+            db_ctx,
+            pool_coins(db_ctx, info.protocol_address, false)?,
+            call_data.use_eth,
+        );
+
+        curve_swap(
+            Protocol::CurveCrypto,
+            &info,
+            call_data.receiver,
+            &coins,
+            sold_id,
+            tokens_sold,
+            bought_id,
+            tokens_bought,
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveCrypto,
+    ICurveCryptoSwap::exchange_underlying_0Call,
+    Swap,
+    [..(TokenExchange_0 | TokenExchange_1)],
+    logs: true,
+    |info: CallInfo,
+    log_data: CurveCryptoExchangeUnderlying0CallLogs,
+    db_ctx: &DB| {
+        let (sold_id, tokens_sold, bought_id, tokens_bought) = exchanged(
+            log_data.token_exchange_0_field,
+            log_data.token_exchange_1_field,
+        )?;
+        let coins = eth_coins(
+            db_ctx,
+            pool_coins(db_ctx, info.protocol_address, false)?,
+            true,
+        );
+
+        curve_swap(
+            Protocol::CurveCrypto,
+            &info,
+            info.from_address,
+            &coins,
+            sold_id,
+            tokens_sold,
+            bought_id,
+            tokens_bought,
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveCrypto,
+    ICurveCryptoSwap::exchange_underlying_1Call,
+    Swap,
+    [..(TokenExchange_0 | TokenExchange_1)],
+    call_data: true,
+    logs: true,
+    |info: CallInfo,
+    call_data: exchange_underlying_1Call,
+    log_data: CurveCryptoExchangeUnderlying1CallLogs,
+    db_ctx: &DB| {
+        let (sold_id, tokens_sold, bought_id, tokens_bought) = exchanged(
+            log_data.token_exchange_0_field,
+            log_data.token_exchange_1_field,
+        )?;
+        let coins = eth_coins(
+            db_ctx,
+            pool_coins(db_ctx, info.protocol_address, false)?,
+            true,
+        );
+
+        curve_swap(
+            Protocol::CurveCrypto,
+            &info,
+            call_data.receiver,
+            &coins,
+            sold_id,
+            tokens_sold,
+            bought_id,
+            tokens_bought,
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveCrypto,
+    ICurveCryptoSwap::add_liquidity_0Call,
+    Mint,
+    [..(AddLiquidity_0 | AddLiquidity_2)],
+    logs: true,
+    |info: CallInfo,
+    log_data: CurveCryptoAddLiquidity0CallLogs,
+    db_ctx: &DB| {
+        let coins = eth_coins(
+            db_ctx,
+            pool_coins(db_ctx, info.protocol_address, false)?,
+            false,
+        );
+
+        // ng pools emit the extended event for the classic selectors as well
+        let amounts = log_data
+            .add_liquidity_0_field
+            .map(|added| added.token_amounts)
+            .or_else(|_| log_data.add_liquidity_2_field.map(|added| added.token_amounts))?;
+
+        curve_mint(
+            Protocol::CurveCrypto,
+            &info,
+            info.from_address,
+            coins,
+            amounts.to_vec(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveCrypto,
+    ICurveCryptoSwap::add_liquidity_1Call,
+    Mint,
+    [..(AddLiquidity_1 | AddLiquidity_3)],
+    logs: true,
+    |info: CallInfo,
+    log_data: CurveCryptoAddLiquidity1CallLogs,
+    db_ctx: &DB| {
+        let coins = eth_coins(
+            db_ctx,
+            pool_coins(db_ctx, info.protocol_address, false)?,
+            false,
+        );
+
+        let amounts = log_data
+            .add_liquidity_1_field
+            .map(|added| added.token_amounts)
+            .or_else(|_| log_data.add_liquidity_3_field.map(|added| added.token_amounts))?;
+
+        curve_mint(
+            Protocol::CurveCrypto,
+            &info,
+            info.from_address,
+            coins,
+            amounts.to_vec(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveCrypto,
+    ICurveCryptoSwap::add_liquidity_2Call,
+    Mint,
+    [..(AddLiquidity_0 | AddLiquidity_2)],
+    call_data: true,
+    logs: true,
+    |info: CallInfo,
+    call_data: add_liquidity_2Call,
+    log_data: CurveCryptoAddLiquidity2CallLogs,
+    db_ctx: &DB| {
+        let coins = eth_coins(
+            db_ctx,
+            pool_coins(db_ctx, info.protocol_address, false)?,
+            call_data.use_eth,
+        );
+
+        let amounts = log_data
+            .add_liquidity_0_field
+            .map(|added| added.token_amounts)
+            .or_else(|_| log_data.add_liquidity_2_field.map(|added| added.token_amounts))?;
+
+        curve_mint(
+            Protocol::CurveCrypto,
+            &info,
+            info.from_address,
+            coins,
+            amounts.to_vec(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveCrypto,
+    ICurveCryptoSwap::add_liquidity_3Call,
+    Mint,
+    [..(AddLiquidity_1 | AddLiquidity_3)],
+    call_data: true,
+    logs: true,
+    |info: CallInfo,
+    call_data: add_liquidity_3Call,
+    log_data: CurveCryptoAddLiquidity3CallLogs,
+    db_ctx: &DB| {
+        let coins = eth_coins(
+            db_ctx,
+            pool_coins(db_ctx, info.protocol_address, false)?,
+            call_data.use_eth,
+        );
+
+        let amounts = log_data
+            .add_liquidity_1_field
+            .map(|added| added.token_amounts)
+            .or_else(|_| log_data.add_liquidity_3_field.map(|added| added.token_amounts))?;
+
+        curve_mint(
+            Protocol::CurveCrypto,
+            &info,
+            info.from_address,
+            coins,
+            amounts.to_vec(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveCrypto,
+    ICurveCryptoSwap::add_liquidity_4Call,
+    Mint,
+    [..(AddLiquidity_0 | AddLiquidity_2)],
+    call_data: true,
+    logs: true,
+    |info: CallInfo,
+    call_data: add_liquidity_4Call,
+    log_data: CurveCryptoAddLiquidity4CallLogs,
+    db_ctx: &DB| {
+        let coins = eth_coins(
+            db_ctx,
+            pool_coins(db_ctx, info.protocol_address, false)?,
+            false,
+        );
+
+        let amounts = log_data
+            .add_liquidity_0_field
+            .map(|added| added.token_amounts)
+            .or_else(|_| log_data.add_liquidity_2_field.map(|added| added.token_amounts))?;
+
+        curve_mint(
+            Protocol::CurveCrypto,
+            &info,
+            call_data.receiver,
+            coins,
+            amounts.to_vec(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveCrypto,
+    ICurveCryptoSwap::add_liquidity_5Call,
+    Mint,
+    [..(AddLiquidity_1 | AddLiquidity_3)],
+    call_data: true,
+    logs: true,
+    |info: CallInfo,
+    call_data: add_liquidity_5Call,
+    log_data: CurveCryptoAddLiquidity5CallLogs,
+    db_ctx: &DB| {
+        let coins = eth_coins(
+            db_ctx,
+            pool_coins(db_ctx, info.protocol_address, false)?,
+            call_data.use_eth,
+        );
+
+        let amounts = log_data
+            .add_liquidity_1_field
+            .map(|added| added.token_amounts)
+            .or_else(|_| log_data.add_liquidity_3_field.map(|added| added.token_amounts))?;
+
+        curve_mint(
+            Protocol::CurveCrypto,
+            &info,
+            call_data.receiver,
+            coins,
+            amounts.to_vec(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveCrypto,
+    ICurveCryptoSwap::remove_liquidity_0Call,
+    Burn,
+    [..RemoveLiquidity_0],
+    logs: true,
+    |info: CallInfo,
+    log_data: CurveCryptoRemoveLiquidity0CallLogs,
+    db_ctx: &DB| {
+        let coins = eth_coins(
+            db_ctx,
+            pool_coins(db_ctx, info.protocol_address, false)?,
+            false,
+        );
+
+        curve_burn(
+            Protocol::CurveCrypto,
+            &info,
+            info.from_address,
+            coins,
+            log_data.remove_liquidity_0_field?.token_amounts.to_vec(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveCrypto,
+    ICurveCryptoSwap::remove_liquidity_1Call,
+    Burn,
+    [..RemoveLiquidity_1],
+    logs: true,
+    |info: CallInfo,
+    log_data: CurveCryptoRemoveLiquidity1CallLogs,
+    db_ctx: &DB| {
+        let coins = eth_coins(
+            db_ctx,
+            pool_coins(db_ctx, info.protocol_address, false)?,
+            false,
+        );
+
+        curve_burn(
+            Protocol::CurveCrypto,
+            &info,
+            info.from_address,
+            coins,
+            log_data.remove_liquidity_1_field?.token_amounts.to_vec(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveCrypto,
+    ICurveCryptoSwap::remove_liquidity_2Call,
+    Burn,
+    [..RemoveLiquidity_0],
+    call_data: true,
+    logs: true,
+    |info: CallInfo,
+    call_data: remove_liquidity_2Call,
+    log_data: CurveCryptoRemoveLiquidity2CallLogs,
+    db_ctx: &DB| {
+        let coins = eth_coins(
+            db_ctx,
+            pool_coins(db_ctx, info.protocol_address, false)?,
+            call_data.use_eth,
+        );
+
+        curve_burn(
+            Protocol::CurveCrypto,
+            &info,
+            info.from_address,
+            coins,
+            log_data.remove_liquidity_0_field?.token_amounts.to_vec(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveCrypto,
+    ICurveCryptoSwap::remove_liquidity_3Call,
+    Burn,
+    [..RemoveLiquidity_1],
+    call_data: true,
+    logs: true,
+    |info: CallInfo,
+    call_data: remove_liquidity_3Call,
+    log_data: CurveCryptoRemoveLiquidity3CallLogs,
+    db_ctx: &DB| {
+        let coins = eth_coins(
+            db_ctx,
+            pool_coins(db_ctx, info.protocol_address, false)?,
+            call_data.use_eth,
+        );
+
+        curve_burn(
+            Protocol::CurveCrypto,
+            &info,
+            info.from_address,
+            coins,
+            log_data.remove_liquidity_1_field?.token_amounts.to_vec(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveCrypto,
+    ICurveCryptoSwap::remove_liquidity_4Call,
+    Burn,
+    [..RemoveLiquidity_0],
+    call_data: true,
+    logs: true,
+    |info: CallInfo,
+    call_data: remove_liquidity_4Call,
+    log_data: CurveCryptoRemoveLiquidity4CallLogs,
+    db_ctx: &DB| {
+        let coins = eth_coins(
+            db_ctx,
+            pool_coins(db_ctx, info.protocol_address, false)?,
+            false,
+        );
+
+        curve_burn(
+            Protocol::CurveCrypto,
+            &info,
+            call_data.receiver,
+            coins,
+            log_data.remove_liquidity_0_field?.token_amounts.to_vec(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveCrypto,
+    ICurveCryptoSwap::remove_liquidity_5Call,
+    Burn,
+    [..RemoveLiquidity_1],
+    call_data: true,
+    logs: true,
+    |info: CallInfo,
+    call_data: remove_liquidity_5Call,
+    log_data: CurveCryptoRemoveLiquidity5CallLogs,
+    db_ctx: &DB| {
+        let coins = eth_coins(
+            db_ctx,
+            pool_coins(db_ctx, info.protocol_address, false)?,
+            call_data.use_eth,
+        );
+
+        curve_burn(
+            Protocol::CurveCrypto,
+            &info,
+            call_data.receiver,
+            coins,
+            log_data.remove_liquidity_1_field?.token_amounts.to_vec(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveCrypto,
+    ICurveCryptoSwap::remove_liquidity_one_coin_0Call,
+    Burn,
+    [..(RemoveLiquidityOne_0 | RemoveLiquidityOne_1)],
+    call_data: true,
+    logs: true,
+    |info: CallInfo,
+    call_data: remove_liquidity_one_coin_0Call,
+    log_data: CurveCryptoRemoveLiquidityOneCoin0CallLogs,
+    db_ctx: &DB| {
+        let amount = log_data
+            .remove_liquidity_one_0_field
+            .map(|removed| removed.coin_amount)
+            .or_else(|_| log_data.remove_liquidity_one_1_field.map(|removed| removed.coin_amount))?;
+        let coins = eth_coins(
+            db_ctx,
+            pool_coins(db_ctx, info.protocol_address, false)?,
+            false,
+        );
+
+        curve_burn_one(
+            Protocol::CurveCrypto,
+            &info,
+            info.from_address,
+            &coins,
+            call_data.i,
+            amount,
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveCrypto,
+    ICurveCryptoSwap::remove_liquidity_one_coin_1Call,
+    Burn,
+    [..(RemoveLiquidityOne_0 | RemoveLiquidityOne_1)],
+    call_data: true,
+    logs: true,
+    |info: CallInfo,
+    call_data: remove_liquidity_one_coin_1Call,
+    log_data: CurveCryptoRemoveLiquidityOneCoin1CallLogs,
+    db_ctx: &DB| {
+        let amount = log_data
+            .remove_liquidity_one_0_field
+            .map(|removed| removed.coin_amount)
+            .or_else(|_| log_data.remove_liquidity_one_1_field.map(|removed| removed.coin_amount))?;
+        let coins = eth_coins(
+            db_ctx,
+            pool_coins(db_ctx, info.protocol_address, false)?,
+            call_data.use_eth,
+        );
+
+        curve_burn_one(
+            Protocol::CurveCrypto,
+            &info,
+            info.from_address,
+            &coins,
+            call_data.i,
+            amount,
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveCrypto,
+    ICurveCryptoSwap::remove_liquidity_one_coin_2Call,
+    Burn,
+    [..(RemoveLiquidityOne_0 | RemoveLiquidityOne_1)],
+    call_data: true,
+    logs: true,
+    |info: CallInfo,
+    call_data: remove_liquidity_one_coin_2Call,
+    log_data: CurveCryptoRemoveLiquidityOneCoin2CallLogs,
+    db_ctx: &DB| {
+        let amount = log_data
+            .remove_liquidity_one_0_field
+            .map(|removed| removed.coin_amount)
+            .or_else(|_| log_data.remove_liquidity_one_1_field.map(|removed| removed.coin_amount))?;
+        let coins = eth_coins(
+            db_ctx,
+            pool_coins(db_ctx, info.protocol_address, false)?,
+            call_data.use_eth,
+        );
+
+        curve_burn_one(
+            Protocol::CurveCrypto,
+            &info,
+            call_data.receiver,
+            &coins,
+            call_data.i,
+            amount,
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveCrypto,
+    ICurveCryptoSwap::remove_liquidity_one_coin_3Call,
+    Burn,
+    [..(RemoveLiquidityOne_0 | RemoveLiquidityOne_1)],
+    call_data: true,
+    logs: true,
+    |info: CallInfo,
+    call_data: remove_liquidity_one_coin_3Call,
+    log_data: CurveCryptoRemoveLiquidityOneCoin3CallLogs,
+    db_ctx: &DB| {
+        let amount = log_data
+            .remove_liquidity_one_0_field
+            .map(|removed| removed.coin_amount)
+            .or_else(|_| log_data.remove_liquidity_one_1_field.map(|removed| removed.coin_amount))?;
+        let coins = eth_coins(
+            db_ctx,
+            pool_coins(db_ctx, info.protocol_address, false)?,
+            false,
+        );
+
+        curve_burn_one(
+            Protocol::CurveCrypto,
+            &info,
+            call_data.receiver,
+            &coins,
+            call_data.i,
+            amount,
+        )
+    }
+);
+
+/// the classic and the ng exchange event carry the same leading fields
+fn exchanged(
+    classic: eyre::Result<ICurveCryptoSwap::TokenExchange_0>,
+    ng: eyre::Result<ICurveCryptoSwap::TokenExchange_1>,
+) -> eyre::Result<(U256, U256, U256, U256)> {
+    classic
+        .map(|ex| (ex.sold_id, ex.tokens_sold, ex.bought_id, ex.tokens_bought))
+        .or_else(|_| ng.map(|ex| (ex.sold_id, ex.tokens_sold, ex.bought_id, ex.tokens_bought)))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::Address;
+
+    use super::*;
+
+    fn classic() -> ICurveCryptoSwap::TokenExchange_0 {
+        ICurveCryptoSwap::TokenExchange_0 {
+            buyer: Address::ZERO,
+            sold_id: U256::from(0),
+            tokens_sold: U256::from(10),
+            bought_id: U256::from(2),
+            tokens_bought: U256::from(3),
+        }
+    }
+
+    fn ng() -> ICurveCryptoSwap::TokenExchange_1 {
+        ICurveCryptoSwap::TokenExchange_1 {
+            buyer: Address::ZERO,
+            sold_id: U256::from(1),
+            tokens_sold: U256::from(20),
+            bought_id: U256::from(0),
+            tokens_bought: U256::from(5),
+            fee: U256::from(1),
+            packed_price_scale: U256::from(9),
+        }
+    }
+
+    #[test]
+    fn reads_either_exchange_event() {
+        let amounts = |a: u64, b: u64, c: u64, d: u64| {
+            (U256::from(a), U256::from(b), U256::from(c), U256::from(d))
+        };
+
+        assert_eq!(
+            exchanged(Ok(classic()), Err(eyre::eyre!("missing"))).unwrap(),
+            amounts(0, 10, 2, 3)
+        );
+        assert_eq!(
+            exchanged(Err(eyre::eyre!("missing")), Ok(ng())).unwrap(),
+            amounts(1, 20, 0, 5)
+        );
+        assert!(exchanged(Err(eyre::eyre!("missing")), Err(eyre::eyre!("missing"))).is_err());
+    }
+}
@@ -0,0 +1,292 @@
+mod crypto;
+mod stable;
+
+use alloy_primitives::{Address, U256};
+use brontes_classifier::{context::DataContext, types::CallInfo};
+use brontes_classifier_types::{NATIVE_TOKEN, NormalizedBurn, NormalizedMint, NormalizedSwap};
+pub use crypto::*;
+pub use stable::*;
+
+use crate::{Protocol, ProtocolMetadata};
+
+/// the coins of `pool` in index order. the registry's tokens must be listed in
+/// the pool's own coin order as they're read through `get_protocol_tokens`, the
+/// underlying coins used by `exchange_underlying` and the `use_underlying`
+/// calls of lending pools come from the pool metadata.
+fn pool_coins<DB: DataContext<Protocol, ProtocolMetadata>>(
+    db_ctx: &DB,
+    pool: Address,
+    underlying: bool,
+) -> eyre::Result<Vec<Address>> {
+    if !underlying {
+        return db_ctx.get_protocol_tokens(pool);
+    }
+
+    let coins = db_ctx.get_metadata(pool)?.underlying_tokens;
+    if coins.is_empty() {
+        return Err(eyre::eyre!(
+            "curve pool {pool:?} has no underlying tokens in its metadata"
+        ));
+    }
+
+    Ok(coins)
+}
+
+/// crypto pools called with `use_eth` take and pay out their wrapped native
+/// coin as eth
+fn eth_coins<DB: DataContext<Protocol, ProtocolMetadata>>(
+    db_ctx: &DB,
+    coins: Vec<Address>,
+    use_eth: bool,
+) -> Vec<Address> {
+    if !use_eth {
+        return coins;
+    }
+
+    coins
+        .into_iter()
+        .map(|coin| match db_ctx.get_protocol(coin) {
+            Ok(Protocol::WrappedNative) => NATIVE_TOKEN,
+            _ => coin,
+        })
+        .collect()
+}
+
+fn coin<I>(coins: &[Address], index: I) -> eyre::Result<Address>
+where
+    I: TryInto<usize> + Copy + std::fmt::Debug,
+{
+    index
+        .try_into()
+        .ok()
+        .and_then(|index| coins.get(index).copied())
+        .ok_or_else(|| {
+            eyre::eyre!(
+                "coin index {index:?} is out of range for {} coins",
+                coins.len()
+            )
+        })
+}
+
+#[allow(clippy::too_many_arguments)]
+fn curve_swap<I>(
+    protocol: Protocol,
+    info: &CallInfo,
+    recipient: Address,
+    coins: &[Address],
+    sold_id: I,
+    tokens_sold: U256,
+    bought_id: I,
+    tokens_bought: U256,
+) -> eyre::Result<NormalizedSwap<Protocol>>
+where
+    I: TryInto<usize> + Copy + std::fmt::Debug,
+{
+    Ok(NormalizedSwap {
+        protocol,
+        trace_index: info.trace_idx,
+        from: info.from_address,
+        recipient,
+        pool: info.target_address,
+        pool_id: None,
+        token_in: coin(coins, sold_id)?,
+        token_out: coin(coins, bought_id)?,
+        amount_in: tokens_sold,
+        amount_out: tokens_bought,
+        msg_value: info.msg_value,
+    })
+}
+
+fn curve_mint(
+    protocol: Protocol,
+    info: &CallInfo,
+    recipient: Address,
+    coins: Vec<Address>,
+    amounts: Vec<U256>,
+) -> eyre::Result<NormalizedMint<Protocol>> {
+    check_amounts(&coins, &amounts)?;
+
+    Ok(NormalizedMint {
+        protocol,
+        trace_index: info.trace_idx,
+        from: info.from_address,
+        recipient,
+        pool: info.target_address,
+        pool_id: None,
+        tokens: coins,
+        amounts,
+        position: None,
+    })
+}
+
+fn curve_burn(
+    protocol: Protocol,
+    info: &CallInfo,
+    recipient: Address,
+    coins: Vec<Address>,
+    amounts: Vec<U256>,
+) -> eyre::Result<NormalizedBurn<Protocol>> {
+    check_amounts(&coins, &amounts)?;
+
+    Ok(NormalizedBurn {
+        protocol,
+        trace_index: info.trace_idx,
+        from: info.from_address,
+        recipient,
+        pool: info.target_address,
+        pool_id: None,
+        tokens: coins,
+        amounts,
+        position: None,
+    })
+}
+
+/// a burn paying out a single coin, from `remove_liquidity_one_coin`
+fn curve_burn_one<I>(
+    protocol: Protocol,
+    info: &CallInfo,
+    recipient: Address,
+    coins: &[Address],
+    index: I,
+    amount: U256,
+) -> eyre::Result<NormalizedBurn<Protocol>>
+where
+    I: TryInto<usize> + Copy + std::fmt::Debug,
+{
+    curve_burn(
+        protocol,
+        info,
+        recipient,
+        vec![coin(coins, index)?],
+        vec![amount],
+    )
+}
+
+fn check_amounts(coins: &[Address], amounts: &[U256]) -> eyre::Result<()> {
+    if coins.len() != amounts.len() {
+        return Err(eyre::eyre!(
+            "got {} amounts for a curve pool of {} coins",
+            amounts.len(),
+            coins.len()
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::address;
+
+    use super::*;
+    use crate::test_utils::{TestRegistry, call_info};
+
+    const POOL: Address = address!("d51a44d3fae010294c616388b506acda1bfaae46");
+    const USDT: Address = address!("dac17f958d2ee523a2206206994597c13d831ec7");
+    const WBTC: Address = address!("2260fac5e5542a773aa44fbcfedf7c193bc2c599");
+    const WETH: Address = address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2");
+    const DAI: Address = address!("6b175474e89094c44da98b954eedeac495271d0f");
+    const USDC: Address = address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+    const USER: Address = address!("1000000000000000000000000000000000000001");
+
+    /// tricrypto with its coins in pool order, which isn't the sorted order
+    fn registry() -> TestRegistry {
+        TestRegistry::default()
+            .with_metadata(
+                POOL,
+                Protocol::CurveCrypto,
+                &[USDT, WBTC, WETH],
+                ProtocolMetadata {
+                    underlying_tokens: vec![DAI, USDC, USDT],
+                    ..Default::default()
+                },
+            )
+            .with(WETH, Protocol::WrappedNative, &[])
+    }
+
+    #[test]
+    fn resolves_coins_in_pool_order() {
+        let db = registry();
+
+        assert_eq!(
+            pool_coins(&db, POOL, false).unwrap(),
+            vec![USDT, WBTC, WETH]
+        );
+        assert_eq!(pool_coins(&db, POOL, true).unwrap(), vec![DAI, USDC, USDT]);
+    }
+
+    #[test]
+    fn requires_underlying_coins_in_the_metadata() {
+        let db = TestRegistry::default().with(POOL, Protocol::CurveStable, &[DAI, USDC]);
+
+        assert!(pool_coins(&db, POOL, true).is_err());
+    }
+
+    #[test]
+    fn pays_the_wrapped_native_coin_as_eth() {
+        let db = registry();
+        let coins = pool_coins(&db, POOL, false).unwrap();
+
+        assert_eq!(eth_coins(&db, coins.clone(), false), coins);
+        assert_eq!(eth_coins(&db, coins, true), vec![USDT, WBTC, NATIVE_TOKEN]);
+    }
+
+    #[test]
+    fn indexes_coins_by_int128_and_uint256() {
+        let coins = [USDT, WBTC, WETH];
+
+        // stableswap pools index with int128, cryptoswap pools with uint256
+        assert_eq!(coin(&coins, 1i128).unwrap(), WBTC);
+        assert_eq!(coin(&coins, U256::from(2)).unwrap(), WETH);
+
+        assert!(coin(&coins, -1i128).is_err());
+        assert!(coin(&coins, 3i128).is_err());
+        assert!(coin(&coins, U256::from(3)).is_err());
+        assert!(coin(&coins, U256::MAX).is_err());
+    }
+
+    #[test]
+    fn burns_a_single_coin() {
+        let db = registry();
+        let info = call_info(USER, POOL);
+        let coins = eth_coins(&db, pool_coins(&db, POOL, false).unwrap(), true);
+
+        let burn = curve_burn_one(
+            Protocol::CurveCrypto,
+            &info,
+            USER,
+            &coins,
+            U256::from(2),
+            U256::from(7),
+        )
+        .unwrap();
+        assert_eq!(burn.tokens, vec![NATIVE_TOKEN]);
+        assert_eq!(burn.amounts, vec![U256::from(7)]);
+        assert_eq!(burn.recipient, USER);
+        assert_eq!(burn.pool, POOL);
+
+        let coins = pool_coins(&db, POOL, true).unwrap();
+        let burn = curve_burn_one(
+            Protocol::CurveStable,
+            &info,
+            USER,
+            &coins,
+            1i128,
+            U256::from(7),
+        )
+        .unwrap();
+        assert_eq!(burn.tokens, vec![USDC]);
+
+        assert!(
+            curve_burn_one(
+                Protocol::CurveStable,
+                &info,
+                USER,
+                &coins,
+                -1i128,
+                U256::from(7)
+            )
+            .is_err()
+        );
+    }
+}
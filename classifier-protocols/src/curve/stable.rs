@@ -0,0 +1,773 @@
+use alloy_sol_types::sol;
+use brontes_classifier::{action_impl, types::CallInfo};
+
+use super::{curve_burn, curve_burn_one, curve_mint, curve_swap, pool_coins};
+use crate::{Actions, Protocol, ProtocolMetadata};
+
+sol! {
+    /// stableswap pools (plain, lending and meta pools) index their coins with
+    /// `int128`. overloads are numbered in declaration order, the ng variants
+    /// take dynamic arrays and an optional receiver.
+    interface ICurveStableSwap {
+        event TokenExchange(
+            address indexed buyer,
+            int128 sold_id,
+            uint256 tokens_sold,
+            int128 bought_id,
+            uint256 tokens_bought
+        );
+        event TokenExchangeUnderlying(
+            address indexed buyer,
+            int128 sold_id,
+            uint256 tokens_sold,
+            int128 bought_id,
+            uint256 tokens_bought
+        );
+        event AddLiquidity(
+            address indexed provider,
+            uint256[2] token_amounts,
+            uint256[2] fees,
+            uint256 invariant,
+            uint256 token_supply
+        );
+        event AddLiquidity(
+            address indexed provider,
+            uint256[3] token_amounts,
+            uint256[3] fees,
+            uint256 invariant,
+            uint256 token_supply
+        );
+        event AddLiquidity(
+            address indexed provider,
+            uint256[4] token_amounts,
+            uint256[4] fees,
+            uint256 invariant,
+            uint256 token_supply
+        );
+        event AddLiquidity(
+            address indexed provider,
+            uint256[] token_amounts,
+            uint256[] fees,
+            uint256 invariant,
+            uint256 token_supply
+        );
+        event RemoveLiquidity(
+            address indexed provider,
+            uint256[2] token_amounts,
+            uint256[2] fees,
+            uint256 token_supply
+        );
+        event RemoveLiquidity(
+            address indexed provider,
+            uint256[3] token_amounts,
+            uint256[3] fees,
+            uint256 token_supply
+        );
+        event RemoveLiquidity(
+            address indexed provider,
+            uint256[4] token_amounts,
+            uint256[4] fees,
+            uint256 token_supply
+        );
+        event RemoveLiquidity(
+            address indexed provider,
+            uint256[] token_amounts,
+            uint256[] fees,
+            uint256 token_supply
+        );
+        event RemoveLiquidityOne(
+            address indexed provider,
+            uint256 token_amount,
+            uint256 coin_amount
+        );
+        event RemoveLiquidityOne(
+            address indexed provider,
+            int128 token_id,
+            uint256 token_amount,
+            uint256 coin_amount,
+            uint256 token_supply
+        );
+
+        function exchange(int128 i, int128 j, uint256 dx, uint256 min_dy) external payable;
+        function exchange(int128 i, int128 j, uint256 dx, uint256 min_dy, address receiver)
+            external payable;
+        function exchange_underlying(int128 i, int128 j, uint256 dx, uint256 min_dy) external;
+        function exchange_underlying(
+            int128 i,
+            int128 j,
+            uint256 dx,
+            uint256 min_dy,
+            address receiver
+        ) external;
+
+        function add_liquidity(uint256[2] amounts, uint256 min_mint_amount) external payable;
+        function add_liquidity(uint256[3] amounts, uint256 min_mint_amount) external;
+        function add_liquidity(uint256[4] amounts, uint256 min_mint_amount) external;
+        function add_liquidity(uint256[2] amounts, uint256 min_mint_amount, bool use_underlying)
+            external;
+        function add_liquidity(uint256[3] amounts, uint256 min_mint_amount, bool use_underlying)
+            external;
+        function add_liquidity(uint256[] amounts, uint256 min_mint_amount) external payable;
+        function add_liquidity(uint256[] amounts, uint256 min_mint_amount, address receiver)
+            external payable;
+
+        function remove_liquidity(uint256 amount, uint256[2] min_amounts) external;
+        function remove_liquidity(uint256 amount, uint256[3] min_amounts) external;
+        function remove_liquidity(uint256 amount, uint256[4] min_amounts) external;
+        function remove_liquidity(uint256 amount, uint256[2] min_amounts, bool use_underlying)
+            external;
+        function remove_liquidity(uint256 amount, uint256[3] min_amounts, bool use_underlying)
+            external;
+        function remove_liquidity(uint256 amount, uint256[] min_amounts) external;
+        function remove_liquidity(uint256 amount, uint256[] min_amounts, address receiver)
+            external;
+
+        function remove_liquidity_one_coin(uint256 token_amount, int128 i, uint256 min_amount)
+            external;
+        function remove_liquidity_one_coin(
+            uint256 token_amount,
+            int128 i,
+            uint256 min_amount,
+            bool use_underlying
+        ) external;
+        function remove_liquidity_one_coin(
+            uint256 token_amount,
+            int128 i,
+            uint256 min_amount,
+            address receiver
+        ) external;
+
+        function remove_liquidity_imbalance(uint256[2] amounts, uint256 max_burn_amount)
+            external;
+        function remove_liquidity_imbalance(uint256[3] amounts, uint256 max_burn_amount)
+            external;
+        function remove_liquidity_imbalance(uint256[4] amounts, uint256 max_burn_amount)
+            external;
+        function remove_liquidity_imbalance(uint256[] amounts, uint256 max_burn_amount)
+            external;
+        function remove_liquidity_imbalance(
+            uint256[] amounts,
+            uint256 max_burn_amount,
+            address receiver
+        ) external;
+    }
+}
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveStable,
+    ICurveStableSwap::exchange_0Call,
+    Swap,
+    [..TokenExchange],
+    logs: true,
+    |info: CallInfo,
+    log_data: CurveStableExchange0CallLogs,
+    db_ctx: &DB| {
+        let exchange = log_data.token_exchange_field?;
+        let coins = pool_coins(db_ctx, info.protocol_address, false)?;
+
+        curve_swap(
+            Protocol::CurveStable,
+            &info,
+            info.from_address,
+            &coins,
+            exchange.sold_id,
+            exchange.tokens_sold,
+            exchange.bought_id,
+            exchange.tokens_bought,
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveStable,
+    ICurveStableSwap::exchange_1Call,
+    Swap,
+    [..TokenExchange],
+    call_data: true,
+    logs: true,
+    |info: CallInfo,
+    call_data: exchange_1Call,
+    log_data: CurveStableExchange1CallLogs,
+    db_ctx: &DB| {
+        let exchange = log_data.token_exchange_field?;
+        let coins = pool_coins(db_ctx, info.protocol_address, false)?;
+
+        curve_swap(
+            Protocol::CurveStable,
+            &info,
+            call_data.receiver,
+            &coins,
+            exchange.sold_id,
+            exchange.tokens_sold,
+            exchange.bought_id,
+            exchange.tokens_bought,
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveStable,
+    ICurveStableSwap::exchange_underlying_0Call,
+    Swap,
+    [..TokenExchangeUnderlying],
+    logs: true,
+    |info: CallInfo,
+    log_data: CurveStableExchangeUnderlying0CallLogs,
+    db_ctx: &DB| {
+        let exchange = log_data.token_exchange_underlying_field?;
+        let coins = pool_coins(db_ctx, info.protocol_address, true)?;
+
+        curve_swap(
+            Protocol::CurveStable,
+            &info,
+            info.from_address,
+            &coins,
+            exchange.sold_id,
+            exchange.tokens_sold,
+            exchange.bought_id,
+            exchange.tokens_bought,
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveStable,
+    ICurveStableSwap::exchange_underlying_1Call,
+    Swap,
+    [..TokenExchangeUnderlying],
+    call_data: true,
+    logs: true,
+    |info: CallInfo,
+    call_data: exchange_underlying_1Call,
+    log_data: CurveStableExchangeUnderlying1CallLogs,
+    db_ctx: &DB| {
+        let exchange = log_data.token_exchange_underlying_field?;
+        let coins = pool_coins(db_ctx, info.protocol_address, true)?;
+
+        curve_swap(
+            Protocol::CurveStable,
+            &info,
+            call_data.receiver,
+            &coins,
+            exchange.sold_id,
+            exchange.tokens_sold,
+            exchange.bought_id,
+            exchange.tokens_bought,
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveStable,
+    ICurveStableSwap::add_liquidity_0Call,
+    Mint,
+    [..AddLiquidity_0],
+    logs: true,
+    |info: CallInfo,
+    log_data: CurveStableAddLiquidity0CallLogs,
+    db_ctx: &DB| {
+        let coins = pool_coins(db_ctx, info.protocol_address, false)?;
+
+        curve_mint(
+            Protocol::CurveStable,
+            &info,
+            info.from_address,
+            coins,
+            log_data.add_liquidity_0_field?.token_amounts.to_vec(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveStable,
+    ICurveStableSwap::add_liquidity_1Call,
+    Mint,
+    [..AddLiquidity_1],
+    logs: true,
+    |info: CallInfo,
+    log_data: CurveStableAddLiquidity1CallLogs,
+    db_ctx: &DB| {
+        let coins = pool_coins(db_ctx, info.protocol_address, false)?;
+
+        curve_mint(
+            Protocol::CurveStable,
+            &info,
+            info.from_address,
+            coins,
+            log_data.add_liquidity_1_field?.token_amounts.to_vec(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveStable,
+    ICurveStableSwap::add_liquidity_2Call,
+    Mint,
+    [..AddLiquidity_2],
+    logs: true,
+    |info: CallInfo,
+    log_data: CurveStableAddLiquidity2CallLogs,
+    db_ctx: &DB| {
+        let coins = pool_coins(db_ctx, info.protocol_address, false)?;
+
+        curve_mint(
+            Protocol::CurveStable,
+            &info,
+            info.from_address,
+            coins,
+            log_data.add_liquidity_2_field?.token_amounts.to_vec(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveStable,
+    ICurveStableSwap::add_liquidity_3Call,
+    Mint,
+    [..AddLiquidity_0],
+    call_data: true,
+    logs: true,
+    |info: CallInfo,
+    call_data: add_liquidity_3Call,
+    log_data: CurveStableAddLiquidity3CallLogs,
+    db_ctx: &DB| {
+        let coins = pool_coins(db_ctx, info.protocol_address, call_data.use_underlying)?;
+
+        curve_mint(
+            Protocol::CurveStable,
+            &info,
+            info.from_address,
+            coins,
+            log_data.add_liquidity_0_field?.token_amounts.to_vec(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveStable,
+    ICurveStableSwap::add_liquidity_4Call,
+    Mint,
+    [..AddLiquidity_1],
+    call_data: true,
+    logs: true,
+    |info: CallInfo,
+    call_data: add_liquidity_4Call,
+    log_data: CurveStableAddLiquidity4CallLogs,
+    db_ctx: &DB| {
+        let coins = pool_coins(db_ctx, info.protocol_address, call_data.use_underlying)?;
+
+        curve_mint(
+            Protocol::CurveStable,
+            &info,
+            info.from_address,
+            coins,
+            log_data.add_liquidity_1_field?.token_amounts.to_vec(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveStable,
+    ICurveStableSwap::add_liquidity_5Call,
+    Mint,
+    [..AddLiquidity_3],
+    logs: true,
+    |info: CallInfo,
+    log_data: CurveStableAddLiquidity5CallLogs,
+    db_ctx: &DB| {
+        let coins = pool_coins(db_ctx, info.protocol_address, false)?;
+
+        curve_mint(
+            Protocol::CurveStable,
+            &info,
+            info.from_address,
+            coins,
+            log_data.add_liquidity_3_field?.token_amounts.to_vec(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveStable,
+    ICurveStableSwap::add_liquidity_6Call,
+    Mint,
+    [..AddLiquidity_3],
+    call_data: true,
+    logs: true,
+    |info: CallInfo,
+    call_data: add_liquidity_6Call,
+    log_data: CurveStableAddLiquidity6CallLogs,
+    db_ctx: &DB| {
+        let coins = pool_coins(db_ctx, info.protocol_address, false)?;
+
+        curve_mint(
+            Protocol::CurveStable,
+            &info,
+            call_data.receiver,
+            coins,
+            log_data.add_liquidity_3_field?.token_amounts.to_vec(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveStable,
+    ICurveStableSwap::remove_liquidity_0Call,
+    Burn,
+    [..RemoveLiquidity_0],
+    logs: true,
+    |info: CallInfo,
+    log_data: CurveStableRemoveLiquidity0CallLogs,
+    db_ctx: &DB| {
+        let coins = pool_coins(db_ctx, info.protocol_address, false)?;
+
+        curve_burn(
+            Protocol::CurveStable,
+            &info,
+            info.from_address,
+            coins,
+            log_data.remove_liquidity_0_field?.token_amounts.to_vec(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveStable,
+    ICurveStableSwap::remove_liquidity_1Call,
+    Burn,
+    [..RemoveLiquidity_1],
+    logs: true,
+    |info: CallInfo,
+    log_data: CurveStableRemoveLiquidity1CallLogs,
+    db_ctx: &DB| {
+        let coins = pool_coins(db_ctx, info.protocol_address, false)?;
+
+        curve_burn(
+            Protocol::CurveStable,
+            &info,
+            info.from_address,
+            coins,
+            log_data.remove_liquidity_1_field?.token_amounts.to_vec(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveStable,
+    ICurveStableSwap::remove_liquidity_2Call,
+    Burn,
+    [..RemoveLiquidity_2],
+    logs: true,
+    |info: CallInfo,
+    log_data: CurveStableRemoveLiquidity2CallLogs,
+    db_ctx: &DB| {
+        let coins = pool_coins(db_ctx, info.protocol_address, false)?;
+
+        curve_burn(
+            Protocol::CurveStable,
+            &info,
+            info.from_address,
+            coins,
+            log_data.remove_liquidity_2_field?.token_amounts.to_vec(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveStable,
+    ICurveStableSwap::remove_liquidity_3Call,
+    Burn,
+    [..RemoveLiquidity_0],
+    call_data: true,
+    logs: true,
+    |info: CallInfo,
+    call_data: remove_liquidity_3Call,
+    log_data: CurveStableRemoveLiquidity3CallLogs,
+    db_ctx: &DB| {
+        let coins = pool_coins(db_ctx, info.protocol_address, call_data.use_underlying)?;
+
+        curve_burn(
+            Protocol::CurveStable,
+            &info,
+            info.from_address,
+            coins,
+            log_data.remove_liquidity_0_field?.token_amounts.to_vec(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveStable,
+    ICurveStableSwap::remove_liquidity_4Call,
+    Burn,
+    [..RemoveLiquidity_1],
+    call_data: true,
+    logs: true,
+    |info: CallInfo,
+    call_data: remove_liquidity_4Call,
+    log_data: CurveStableRemoveLiquidity4CallLogs,
+    db_ctx: &DB| {
+        let coins = pool_coins(db_ctx, info.protocol_address, call_data.use_underlying)?;
+
+        curve_burn(
+            Protocol::CurveStable,
+            &info,
+            info.from_address,
+            coins,
+            log_data.remove_liquidity_1_field?.token_amounts.to_vec(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveStable,
+    ICurveStableSwap::remove_liquidity_5Call,
+    Burn,
+    [..RemoveLiquidity_3],
+    logs: true,
+    |info: CallInfo,
+    log_data: CurveStableRemoveLiquidity5CallLogs,
+    db_ctx: &DB| {
+        let coins = pool_coins(db_ctx, info.protocol_address, false)?;
+
+        curve_burn(
+            Protocol::CurveStable,
+            &info,
+            info.from_address,
+            coins,
+            log_data.remove_liquidity_3_field?.token_amounts.to_vec(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveStable,
+    ICurveStableSwap::remove_liquidity_6Call,
+    Burn,
+    [..RemoveLiquidity_3],
+    call_data: true,
+    logs: true,
+    |info: CallInfo,
+    call_data: remove_liquidity_6Call,
+    log_data: CurveStableRemoveLiquidity6CallLogs,
+    db_ctx: &DB| {
+        let coins = pool_coins(db_ctx, info.protocol_address, false)?;
+
+        curve_burn(
+            Protocol::CurveStable,
+            &info,
+            call_data.receiver,
+            coins,
+            log_data.remove_liquidity_3_field?.token_amounts.to_vec(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveStable,
+    ICurveStableSwap::remove_liquidity_one_coin_0Call,
+    Burn,
+    [..(RemoveLiquidityOne_0 | RemoveLiquidityOne_1)],
+    call_data: true,
+    logs: true,
+    |info: CallInfo,
+    call_data: remove_liquidity_one_coin_0Call,
+    log_data: CurveStableRemoveLiquidityOneCoin0CallLogs,
+    db_ctx: &DB| {
+        let coins = pool_coins(db_ctx, info.protocol_address, false)?;
+
+        // ng pools emit the extended event for the classic selector as well
+        let amount = log_data
+            .remove_liquidity_one_0_field
+            .map(|removed| removed.coin_amount)
+            .or_else(|_| log_data.remove_liquidity_one_1_field.map(|removed| removed.coin_amount))?;
+
+        curve_burn_one(
+            Protocol::CurveStable,
+            &info,
+            info.from_address,
+            &coins,
+            call_data.i,
+            amount,
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveStable,
+    ICurveStableSwap::remove_liquidity_one_coin_1Call,
+    Burn,
+    [..RemoveLiquidityOne_0],
+    call_data: true,
+    logs: true,
+    |info: CallInfo,
+    call_data: remove_liquidity_one_coin_1Call,
+    log_data: CurveStableRemoveLiquidityOneCoin1CallLogs,
+    db_ctx: &DB| {
+        let coins = pool_coins(db_ctx, info.protocol_address, call_data.use_underlying)?;
+
+        curve_burn_one(
+            Protocol::CurveStable,
+            &info,
+            info.from_address,
+            &coins,
+            call_data.i,
+            log_data.remove_liquidity_one_0_field?.coin_amount,
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveStable,
+    ICurveStableSwap::remove_liquidity_one_coin_2Call,
+    Burn,
+    [..RemoveLiquidityOne_1],
+    call_data: true,
+    logs: true,
+    |info: CallInfo,
+    call_data: remove_liquidity_one_coin_2Call,
+    log_data: CurveStableRemoveLiquidityOneCoin2CallLogs,
+    db_ctx: &DB| {
+        let coins = pool_coins(db_ctx, info.protocol_address, false)?;
+
+        curve_burn_one(
+            Protocol::CurveStable,
+            &info,
+            call_data.receiver,
+            &coins,
+            call_data.i,
+            log_data.remove_liquidity_one_1_field?.coin_amount,
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveStable,
+    ICurveStableSwap::remove_liquidity_imbalance_0Call,
+    Burn,
+    [],
+    call_data: true,
+    |info: CallInfo,
+    call_data: remove_liquidity_imbalance_0Call,
+    db_ctx: &DB| {
+        let coins = pool_coins(db_ctx, info.protocol_address, false)?;
+
+        curve_burn(
+            Protocol::CurveStable,
+            &info,
+            info.from_address,
+            coins,
+            call_data.amounts.to_vec(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveStable,
+    ICurveStableSwap::remove_liquidity_imbalance_1Call,
+    Burn,
+    [],
+    call_data: true,
+    |info: CallInfo,
+    call_data: remove_liquidity_imbalance_1Call,
+    db_ctx: &DB| {
+        let coins = pool_coins(db_ctx, info.protocol_address, false)?;
+
+        curve_burn(
+            Protocol::CurveStable,
+            &info,
+            info.from_address,
+            coins,
+            call_data.amounts.to_vec(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveStable,
+    ICurveStableSwap::remove_liquidity_imbalance_2Call,
+    Burn,
+    [],
+    call_data: true,
+    |info: CallInfo,
+    call_data: remove_liquidity_imbalance_2Call,
+    db_ctx: &DB| {
+        let coins = pool_coins(db_ctx, info.protocol_address, false)?;
+
+        curve_burn(
+            Protocol::CurveStable,
+            &info,
+            info.from_address,
+            coins,
+            call_data.amounts.to_vec(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveStable,
+    ICurveStableSwap::remove_liquidity_imbalance_3Call,
+    Burn,
+    [],
+    call_data: true,
+    |info: CallInfo,
+    call_data: remove_liquidity_imbalance_3Call,
+    db_ctx: &DB| {
+        let coins = pool_coins(db_ctx, info.protocol_address, false)?;
+
+        curve_burn(
+            Protocol::CurveStable,
+            &info,
+            info.from_address,
+            coins,
+            call_data.amounts.to_vec(),
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::CurveStable,
+    ICurveStableSwap::remove_liquidity_imbalance_4Call,
+    Burn,
+    [],
+    call_data: true,
+    |info: CallInfo,
+    call_data: remove_liquidity_imbalance_4Call,
+    db_ctx: &DB| {
+        let coins = pool_coins(db_ctx, info.protocol_address, false)?;
+
+        curve_burn(
+            Protocol::CurveStable,
+            &info,
+            call_data.receiver,
+            coins,
+            call_data.amounts.to_vec(),
+        )
+    }
+);
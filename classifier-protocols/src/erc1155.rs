@@ -0,0 +1,126 @@
+use alloy_primitives::U256;
+use alloy_sol_types::{SolEvent, sol};
+use brontes_classifier::{action_impl, types::CallInfo};
+use brontes_classifier_types::NormalizedNftTransfer;
+use brontes_tracer::types::TxTrace;
+
+use crate::{Actions, IERC721, Protocol, ProtocolMetadata};
+
+sol! {
+    interface IERC1155 {
+        event TransferSingle(
+            address indexed operator,
+            address indexed from,
+            address indexed to,
+            uint256 id,
+            uint256 value
+        );
+        event TransferBatch(
+            address indexed operator,
+            address indexed from,
+            address indexed to,
+            uint256[] ids,
+            uint256[] values
+        );
+
+        function safeTransferFrom(
+            address from,
+            address to,
+            uint256 id,
+            uint256 amount,
+            bytes data
+        ) external;
+        function safeBatchTransferFrom(
+            address from,
+            address to,
+            uint256[] ids,
+            uint256[] amounts,
+            bytes data
+        ) external;
+    }
+}
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::Erc1155,
+    IERC1155::safeTransferFromCall,
+    NftTransfer,
+    [..TransferSingle],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: Erc1155SafeTransferFromCallLogs, _db_ctx: &DB| {
+        let transfer = log_data.transfer_single_field?;
+        Ok(NormalizedNftTransfer {
+            trace_index: info.trace_idx,
+            collection: info.target_address,
+            from: transfer.from,
+            to: transfer.to,
+            token_ids: vec![transfer.id],
+            amounts: vec![transfer.value],
+        })
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::Erc1155,
+    IERC1155::safeBatchTransferFromCall,
+    NftTransfer,
+    [..TransferBatch],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: Erc1155SafeBatchTransferFromCallLogs, _db_ctx: &DB| {
+        let transfer = log_data.transfer_batch_field?;
+        Ok(NormalizedNftTransfer {
+            trace_index: info.trace_idx,
+            collection: info.target_address,
+            from: transfer.from,
+            to: transfer.to,
+            token_ids: transfer.ids,
+            amounts: transfer.values,
+        })
+    }
+);
+
+/// every erc721 `Transfer` and erc1155 `TransferSingle` / `TransferBatch`
+/// event emitted in the transaction, including mints, burns and the transfers
+/// marketplaces make while filling orders
+pub fn nft_transfer_events(tx: &TxTrace) -> Vec<NormalizedNftTransfer> {
+    tx.trace
+        .iter()
+        .flat_map(|trace| {
+            trace.logs.iter().filter_map(|log| {
+                // erc20 transfers share the erc721 signature but leave the
+                // amount unindexed
+                let erc721 = (log.topics().len() == 4)
+                    .then(|| IERC721::Transfer::decode_log_data(&log.data, false).ok())
+                    .flatten();
+
+                let (from, to, token_ids, amounts) = if let Some(event) = erc721 {
+                    (
+                        event.from,
+                        event.to,
+                        vec![event.tokenId],
+                        vec![U256::from(1)],
+                    )
+                } else if let Ok(event) =
+                    IERC1155::TransferSingle::decode_log_data(&log.data, false)
+                {
+                    (event.from, event.to, vec![event.id], vec![event.value])
+                } else {
+                    let event = IERC1155::TransferBatch::decode_log_data(&log.data, false).ok()?;
+                    (event.from, event.to, event.ids, event.values)
+                };
+
+                Some(NormalizedNftTransfer {
+                    trace_index: trace.trace_idx,
+                    collection: log.address,
+                    from,
+                    to,
+                    token_ids,
+                    amounts,
+                })
+            })
+        })
+        .collect()
+}
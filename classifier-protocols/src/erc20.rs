@@ -0,0 +1,188 @@
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::{SolEvent, sol};
+use brontes_classifier::{action_impl, types::CallInfo};
+use brontes_classifier_types::{NormalizedApproval, NormalizedTransfer};
+use brontes_tracer::types::TxTrace;
+
+use crate::{Actions, Protocol, ProtocolMetadata};
+
+sol! {
+    // declared without return values so tokens that don't return a bool
+    // (usdt, bnb, ..) decode the same as compliant ones
+    interface IERC20 {
+        event Transfer(address indexed from, address indexed to, uint256 value);
+        event Approval(address indexed owner, address indexed spender, uint256 value);
+
+        function transfer(address to, uint256 amount) external;
+        function transferFrom(address from, address to, uint256 amount) external;
+        function approve(address spender, uint256 amount) external;
+        // eip-2612, the deadline only bounds the signature, the allowance
+        // doesn't lapse
+        function permit(
+            address owner,
+            address spender,
+            uint256 value,
+            uint256 deadline,
+            uint8 v,
+            bytes32 r,
+            bytes32 s
+        ) external;
+    }
+}
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::Erc20,
+    IERC20::transferCall,
+    Transfer,
+    [..Transfer*],
+    call_data: true,
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo,
+    call_data: transferCall,
+    log_data: Erc20TransferCallLogs,
+    _db_ctx: &DB| {
+        normalize_transfer(
+            &info,
+            info.from_address,
+            call_data.to,
+            call_data.amount,
+            &log_data.transfer_field?,
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::Erc20,
+    IERC20::transferFromCall,
+    Transfer,
+    [..Transfer*],
+    call_data: true,
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo,
+    call_data: transferFromCall,
+    log_data: Erc20TransferFromCallLogs,
+    _db_ctx: &DB| {
+        normalize_transfer(
+            &info,
+            call_data.from,
+            call_data.to,
+            call_data.amount,
+            &log_data.transfer_field?,
+        )
+    }
+);
+
+// `approve` shares its selector with erc721's, which indexes the token id and
+// leaves the event data empty. the allowance is taken from the `Approval`
+// event so erc721 approvals fail to decode here.
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::Erc20,
+    IERC20::approveCall,
+    Approval,
+    [..Approval],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: Erc20ApproveCallLogs, _db_ctx: &DB| {
+        let approval = log_data.approval_field?;
+
+        Ok(NormalizedApproval {
+            trace_index: info.trace_idx,
+            owner: approval.owner,
+            spender: approval.spender,
+            token: info.target_address,
+            amount: approval.value,
+            expiration: None,
+            via: None,
+        })
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::Erc20,
+    IERC20::permitCall,
+    Approval,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: permitCall, _db_ctx: &DB| {
+        Ok(NormalizedApproval {
+            trace_index: info.trace_idx,
+            owner: call_data.owner,
+            spender: call_data.spender,
+            token: info.target_address,
+            amount: call_data.value,
+            expiration: None,
+            via: None,
+        })
+    }
+);
+
+/// the amount received is taken from the token's `Transfer` events, anything
+/// the calldata amount exceeds it by was kept by a fee on transfer token.
+fn normalize_transfer(
+    info: &CallInfo,
+    from: Address,
+    to: Address,
+    amount: U256,
+    events: &[IERC20::Transfer],
+) -> eyre::Result<NormalizedTransfer> {
+    let received = events
+        .iter()
+        .filter(|event| event.from == from && event.to == to)
+        .map(|event| event.value)
+        .reduce(|a, b| a.saturating_add(b));
+
+    // some tokens skip the event for zero value transfers
+    let received = match received {
+        Some(received) => received,
+        None if amount.is_zero() => U256::ZERO,
+        None => {
+            return Err(eyre::eyre!(
+                "token {:?} emitted no Transfer event from {from:?} to {to:?}",
+                info.target_address
+            ));
+        }
+    };
+
+    Ok(NormalizedTransfer {
+        trace_index: info.trace_idx,
+        from,
+        to,
+        token: info.target_address,
+        amount: received,
+        fee: amount.saturating_sub(received),
+    })
+}
+
+/// every erc20 `Transfer` event emitted in the transaction, including the ones
+/// from mints, burns and transfers done outside of `transfer` /
+/// `transferFrom`. erc721 transfers share the signature but index the token id
+/// and are skipped.
+pub fn erc20_transfer_events(tx: &TxTrace) -> Vec<NormalizedTransfer> {
+    tx.trace
+        .iter()
+        .flat_map(|trace| {
+            trace.logs.iter().filter_map(|log| {
+                if log.topics().len() != 3 {
+                    return None;
+                }
+                let event = IERC20::Transfer::decode_log_data(&log.data, false).ok()?;
+
+                Some(NormalizedTransfer {
+                    trace_index: trace.trace_idx,
+                    from: event.from,
+                    to: event.to,
+                    token: log.address,
+                    amount: event.value,
+                    fee: U256::ZERO,
+                })
+            })
+        })
+        .collect()
+}
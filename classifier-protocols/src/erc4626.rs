@@ -0,0 +1,193 @@
+use alloy_sol_types::sol;
+use brontes_classifier::{action_impl, context::DataContext, types::CallInfo};
+use brontes_classifier_types::{NormalizedDeposit, NormalizedWithdraw};
+
+use crate::{Actions, Protocol, ProtocolMetadata, registered_token};
+
+sol! {
+    interface IERC4626 {
+        event Deposit(
+            address indexed sender,
+            address indexed owner,
+            uint256 assets,
+            uint256 shares
+        );
+        event Withdraw(
+            address indexed sender,
+            address indexed receiver,
+            address indexed owner,
+            uint256 assets,
+            uint256 shares
+        );
+
+        function deposit(uint256 assets, address receiver) external returns (uint256 shares);
+        function mint(uint256 shares, address receiver) external returns (uint256 assets);
+        function withdraw(
+            uint256 assets,
+            address receiver,
+            address owner
+        ) external returns (uint256 shares);
+        function redeem(
+            uint256 shares,
+            address receiver,
+            address owner
+        ) external returns (uint256 assets);
+    }
+}
+
+// every tokenized vault shares the same abi, so any vault registered as
+// `Erc4626` with its underlying asset as its only token is classified here.
+// the amounts come from the events, `mint` and `withdraw` only name one side
+// of the exchange in their calldata.
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::Erc4626,
+    IERC4626::depositCall,
+    Deposit,
+    [..Deposit],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: Erc4626DepositCallLogs, db_ctx: &DB| {
+        deposited(&info, db_ctx, log_data.deposit_field?)
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::Erc4626,
+    IERC4626::mintCall,
+    Deposit,
+    [..Deposit],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: Erc4626MintCallLogs, db_ctx: &DB| {
+        deposited(&info, db_ctx, log_data.deposit_field?)
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::Erc4626,
+    IERC4626::withdrawCall,
+    Withdraw,
+    [..Withdraw],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: Erc4626WithdrawCallLogs, db_ctx: &DB| {
+        withdrawn(&info, db_ctx, log_data.withdraw_field?)
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::Erc4626,
+    IERC4626::redeemCall,
+    Withdraw,
+    [..Withdraw],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: Erc4626RedeemCallLogs, db_ctx: &DB| {
+        withdrawn(&info, db_ctx, log_data.withdraw_field?)
+    }
+);
+
+fn deposited<DB: DataContext<Protocol, ProtocolMetadata>>(
+    info: &CallInfo,
+    db_ctx: &DB,
+    deposit: IERC4626::Deposit,
+) -> eyre::Result<NormalizedDeposit<Protocol>> {
+    Ok(NormalizedDeposit {
+        protocol: Protocol::Erc4626,
+        trace_index: info.trace_idx,
+        pool: info.target_address,
+        from: deposit.sender,
+        recipient: deposit.owner,
+        asset: registered_token(db_ctx, info.target_address)?,
+        amount: deposit.assets,
+        shares: Some(deposit.shares),
+    })
+}
+
+fn withdrawn<DB: DataContext<Protocol, ProtocolMetadata>>(
+    info: &CallInfo,
+    db_ctx: &DB,
+    withdraw: IERC4626::Withdraw,
+) -> eyre::Result<NormalizedWithdraw<Protocol>> {
+    Ok(NormalizedWithdraw {
+        protocol: Protocol::Erc4626,
+        trace_index: info.trace_idx,
+        pool: info.target_address,
+        from: withdraw.sender,
+        owner: withdraw.owner,
+        recipient: withdraw.receiver,
+        asset: registered_token(db_ctx, info.target_address)?,
+        amount: withdraw.assets,
+        shares: Some(withdraw.shares),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{Address, U256, address};
+
+    use super::*;
+    use crate::test_utils::{TestRegistry, call_info};
+
+    const VAULT: Address = address!("83f20f44975d03b1b09e64809b757c47f942beea");
+    const DAI: Address = address!("6b175474e89094c44da98b954eedeac495271d0f");
+    const SENDER: Address = address!("1000000000000000000000000000000000000001");
+    const OWNER: Address = address!("2000000000000000000000000000000000000002");
+    const RECEIVER: Address = address!("3000000000000000000000000000000000000003");
+
+    fn registry() -> TestRegistry {
+        TestRegistry::default().with(VAULT, Protocol::Erc4626, &[DAI])
+    }
+
+    #[test]
+    fn deposit_credits_the_shares_owner() {
+        let deposit = IERC4626::Deposit {
+            sender: SENDER,
+            owner: OWNER,
+            assets: U256::from(100),
+            shares: U256::from(95),
+        };
+
+        let deposit = deposited(&call_info(SENDER, VAULT), &registry(), deposit).unwrap();
+        assert_eq!((deposit.from, deposit.recipient), (SENDER, OWNER));
+        assert_eq!((deposit.pool, deposit.asset), (VAULT, DAI));
+        assert_eq!(deposit.amount, U256::from(100));
+        assert_eq!(deposit.shares, Some(U256::from(95)));
+    }
+
+    #[test]
+    fn withdraw_keeps_the_owner_and_receiver_apart() {
+        let withdraw = IERC4626::Withdraw {
+            sender: SENDER,
+            receiver: RECEIVER,
+            owner: OWNER,
+            assets: U256::from(100),
+            shares: U256::from(95),
+        };
+
+        let withdraw = withdrawn(&call_info(SENDER, VAULT), &registry(), withdraw).unwrap();
+        assert_eq!(withdraw.from, SENDER);
+        assert_eq!(withdraw.owner, OWNER);
+        assert_eq!(withdraw.recipient, RECEIVER);
+        assert_eq!(withdraw.asset, DAI);
+        assert_eq!(withdraw.shares, Some(U256::from(95)));
+    }
+
+    #[test]
+    fn vault_needs_a_single_underlying() {
+        let db = TestRegistry::default().with(VAULT, Protocol::Erc4626, &[DAI, RECEIVER]);
+        let deposit = IERC4626::Deposit {
+            sender: SENDER,
+            owner: OWNER,
+            assets: U256::from(100),
+            shares: U256::from(95),
+        };
+
+        assert!(deposited(&call_info(SENDER, VAULT), &db, deposit).is_err());
+    }
+}
@@ -0,0 +1,127 @@
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::sol;
+use brontes_classifier::{action_impl, types::CallInfo};
+use brontes_classifier_types::{NormalizedNftApproval, NormalizedNftTransfer};
+
+use crate::{Actions, Protocol, ProtocolMetadata};
+
+sol! {
+    interface IERC721 {
+        event Transfer(address indexed from, address indexed to, uint256 indexed tokenId);
+        event Approval(address indexed owner, address indexed approved, uint256 indexed tokenId);
+        event ApprovalForAll(address indexed owner, address indexed operator, bool approved);
+
+        function transferFrom(address from, address to, uint256 tokenId) external;
+        function safeTransferFrom(address from, address to, uint256 tokenId) external;
+        function safeTransferFrom(
+            address from,
+            address to,
+            uint256 tokenId,
+            bytes data
+        ) external;
+        function approve(address to, uint256 tokenId) external;
+        function setApprovalForAll(address operator, bool approved) external;
+    }
+}
+
+// `transferFrom` and `approve` share their selectors with erc20's, the token id
+// being indexed is what tells the events apart. these classifiers are tried
+// first and fail on erc20 events, leaving the call to the erc20 ones.
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::Erc721,
+    IERC721::transferFromCall,
+    NftTransfer,
+    [..Transfer],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: Erc721TransferFromCallLogs, _db_ctx: &DB| {
+        Ok(transferred(&info, log_data.transfer_field?))
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::Erc721,
+    IERC721::safeTransferFrom_0Call,
+    NftTransfer,
+    [..Transfer],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: Erc721SafeTransferFrom0CallLogs, _db_ctx: &DB| {
+        Ok(transferred(&info, log_data.transfer_field?))
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::Erc721,
+    IERC721::safeTransferFrom_1Call,
+    NftTransfer,
+    [..Transfer],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: Erc721SafeTransferFrom1CallLogs, _db_ctx: &DB| {
+        Ok(transferred(&info, log_data.transfer_field?))
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::Erc721,
+    IERC721::approveCall,
+    NftApproval,
+    [..Approval],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: Erc721ApproveCallLogs, _db_ctx: &DB| {
+        let approval = log_data.approval_field?;
+
+        // approving the zero address clears the token's approval
+        Ok(NormalizedNftApproval {
+            trace_index: info.trace_idx,
+            collection: info.target_address,
+            owner: approval.owner,
+            operator: approval.approved,
+            token_id: Some(approval.tokenId),
+            approved: approval.approved != Address::ZERO,
+        })
+    }
+);
+
+// erc1155 shares `setApprovalForAll` and its event, so this covers the
+// approvals of both.
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::Erc721,
+    IERC721::setApprovalForAllCall,
+    NftApproval,
+    [..ApprovalForAll],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: Erc721SetApprovalForAllCallLogs, _db_ctx: &DB| {
+        let approval = log_data.approval_for_all_field?;
+
+        Ok(NormalizedNftApproval {
+            trace_index: info.trace_idx,
+            collection: info.target_address,
+            owner: approval.owner,
+            operator: approval.operator,
+            token_id: None,
+            approved: approval.approved,
+        })
+    }
+);
+
+fn transferred(info: &CallInfo, transfer: IERC721::Transfer) -> NormalizedNftTransfer {
+    NormalizedNftTransfer {
+        trace_index: info.trace_idx,
+        collection: info.target_address,
+        from: transfer.from,
+        to: transfer.to,
+        token_ids: vec![transfer.tokenId],
+        amounts: vec![U256::from(1)],
+    }
+}
@@ -0,0 +1,303 @@
+mod aave;
+mod aggregator;
+mod balancer_v2;
+mod bridge;
+mod compound;
+mod cowswap;
+mod curve;
+mod erc1155;
+mod erc20;
+mod erc4626;
+mod erc721;
+mod metadata;
+mod nft;
+mod permit2;
+mod protocol;
+mod staking;
+#[cfg(test)]
+mod test_utils;
+mod uniswap_v2;
+mod uniswap_v3;
+mod uniswap_v4;
+mod wrapped_native;
+
+pub use aave::*;
+pub use aggregator::*;
+use alloy_primitives::Address;
+pub use balancer_v2::*;
+pub use bridge::*;
+use brontes_classifier::{action_dispatch, context::DataContext};
+use brontes_classifier_types::Action;
+pub use compound::*;
+pub use cowswap::*;
+pub use curve::*;
+pub use erc20::*;
+pub use erc721::*;
+pub use erc1155::*;
+pub use erc4626::*;
+pub use metadata::ProtocolMetadata;
+pub use nft::*;
+pub use permit2::*;
+pub use protocol::Protocol;
+pub use staking::*;
+pub use uniswap_v2::*;
+pub use uniswap_v3::*;
+pub use uniswap_v4::*;
+pub use wrapped_native::*;
+
+pub type Actions = Action<Protocol>;
+
+/// the only token `contract` is registered with, e.g. a lending market's
+/// underlying, a vault's asset or the token a staking wrapper wraps
+fn registered_token<DB: DataContext<Protocol, ProtocolMetadata>>(
+    db_ctx: &DB,
+    contract: Address,
+) -> eyre::Result<Address> {
+    match db_ctx.get_protocol_tokens_sorted(contract)?.as_slice() {
+        [token] => Ok(*token),
+        tokens => Err(eyre::eyre!(
+            "{contract:?} must be registered with a single token, got {tokens:?}"
+        )),
+    }
+}
+
+action_dispatch!(
+    (ProtocolClassifier, Protocol, ProtocolMetadata) => Actions |
+        WrappedNativeDepositCall,
+        WrappedNativeWithdrawCall,
+        UniswapV2SwapCall,
+        UniswapV2MintCall,
+        UniswapV2BurnCall,
+        UniswapV2SkimCall,
+        UniswapV2SyncCall,
+        UniswapV2SwapExactTokensForTokensCall,
+        UniswapV2SwapTokensForExactTokensCall,
+        UniswapV2SwapExactETHForTokensCall,
+        UniswapV2SwapTokensForExactETHCall,
+        UniswapV2SwapExactTokensForETHCall,
+        UniswapV2SwapETHForExactTokensCall,
+        UniswapV2SwapExactTokensForTokensSupportingFeeOnTransferTokensCall,
+        UniswapV2SwapExactETHForTokensSupportingFeeOnTransferTokensCall,
+        UniswapV2SwapExactTokensForETHSupportingFeeOnTransferTokensCall,
+        SushiSwapV2SwapCall,
+        SushiSwapV2MintCall,
+        SushiSwapV2BurnCall,
+        SushiSwapV2SkimCall,
+        SushiSwapV2SyncCall,
+        SushiSwapV2SwapExactTokensForTokensCall,
+        SushiSwapV2SwapTokensForExactTokensCall,
+        SushiSwapV2SwapExactETHForTokensCall,
+        SushiSwapV2SwapTokensForExactETHCall,
+        SushiSwapV2SwapExactTokensForETHCall,
+        SushiSwapV2SwapETHForExactTokensCall,
+        SushiSwapV2SwapExactTokensForTokensSupportingFeeOnTransferTokensCall,
+        SushiSwapV2SwapExactETHForTokensSupportingFeeOnTransferTokensCall,
+        SushiSwapV2SwapExactTokensForETHSupportingFeeOnTransferTokensCall,
+        PancakeSwapV2SwapCall,
+        PancakeSwapV2MintCall,
+        PancakeSwapV2BurnCall,
+        PancakeSwapV2SkimCall,
+        PancakeSwapV2SyncCall,
+        PancakeSwapV2SwapExactTokensForTokensCall,
+        PancakeSwapV2SwapTokensForExactTokensCall,
+        PancakeSwapV2SwapExactETHForTokensCall,
+        PancakeSwapV2SwapTokensForExactETHCall,
+        PancakeSwapV2SwapExactTokensForETHCall,
+        PancakeSwapV2SwapETHForExactTokensCall,
+        PancakeSwapV2SwapExactTokensForTokensSupportingFeeOnTransferTokensCall,
+        PancakeSwapV2SwapExactETHForTokensSupportingFeeOnTransferTokensCall,
+        PancakeSwapV2SwapExactTokensForETHSupportingFeeOnTransferTokensCall,
+        UniswapV3SwapCall,
+        UniswapV3MintCall,
+        UniswapV3BurnCall,
+        UniswapV3CollectCall,
+        UniswapV3FlashCall,
+        UniswapV3PositionManagerMintCall,
+        UniswapV3PositionManagerIncreaseLiquidityCall,
+        UniswapV3PositionManagerDecreaseLiquidityCall,
+        UniswapV3PositionManagerCollectCall,
+        UniswapV4SwapCall,
+        UniswapV4ModifyLiquidityCall,
+        UniswapV4SyncCall,
+        UniswapV4SettleCall,
+        UniswapV4SettleForCall,
+        UniswapV4TakeCall,
+        BalancerV2SwapCall,
+        BalancerV2BatchSwapCall,
+        BalancerV2JoinPoolCall,
+        BalancerV2ExitPoolCall,
+        BalancerV2FlashLoanCall,
+        CurveStableExchange0Call,
+        CurveStableExchange1Call,
+        CurveStableExchangeUnderlying0Call,
+        CurveStableExchangeUnderlying1Call,
+        CurveStableAddLiquidity0Call,
+        CurveStableAddLiquidity1Call,
+        CurveStableAddLiquidity2Call,
+        CurveStableAddLiquidity3Call,
+        CurveStableAddLiquidity4Call,
+        CurveStableAddLiquidity5Call,
+        CurveStableAddLiquidity6Call,
+        CurveStableRemoveLiquidity0Call,
+        CurveStableRemoveLiquidity1Call,
+        CurveStableRemoveLiquidity2Call,
+        CurveStableRemoveLiquidity3Call,
+        CurveStableRemoveLiquidity4Call,
+        CurveStableRemoveLiquidity5Call,
+        CurveStableRemoveLiquidity6Call,
+        CurveStableRemoveLiquidityOneCoin0Call,
+        CurveStableRemoveLiquidityOneCoin1Call,
+        CurveStableRemoveLiquidityOneCoin2Call,
+        CurveStableRemoveLiquidityImbalance0Call,
+        CurveStableRemoveLiquidityImbalance1Call,
+        CurveStableRemoveLiquidityImbalance2Call,
+        CurveStableRemoveLiquidityImbalance3Call,
+        CurveStableRemoveLiquidityImbalance4Call,
+        CurveCryptoExchange0Call,
+        CurveCryptoExchange1Call,
+        CurveCryptoExchange2Call,
+        CurveCryptoExchangeUnderlying0Call,
+        CurveCryptoExchangeUnderlying1Call,
+        CurveCryptoAddLiquidity0Call,
+        CurveCryptoAddLiquidity1Call,
+        CurveCryptoAddLiquidity2Call,
+        CurveCryptoAddLiquidity3Call,
+        CurveCryptoAddLiquidity4Call,
+        CurveCryptoAddLiquidity5Call,
+        CurveCryptoRemoveLiquidity0Call,
+        CurveCryptoRemoveLiquidity1Call,
+        CurveCryptoRemoveLiquidity2Call,
+        CurveCryptoRemoveLiquidity3Call,
+        CurveCryptoRemoveLiquidity4Call,
+        CurveCryptoRemoveLiquidity5Call,
+        CurveCryptoRemoveLiquidityOneCoin0Call,
+        CurveCryptoRemoveLiquidityOneCoin1Call,
+        CurveCryptoRemoveLiquidityOneCoin2Call,
+        CurveCryptoRemoveLiquidityOneCoin3Call,
+        AaveV2DepositCall,
+        AaveV2WithdrawCall,
+        AaveV2BorrowCall,
+        AaveV2RepayCall,
+        AaveV2LiquidationCallCall,
+        AaveV2FlashLoanCall,
+        AaveV3SupplyCall,
+        AaveV3DepositCall,
+        AaveV3WithdrawCall,
+        AaveV3BorrowCall,
+        AaveV3RepayCall,
+        AaveV3LiquidationCallCall,
+        AaveV3FlashLoanCall,
+        AaveV3FlashLoanSimpleCall,
+        CompoundV2Mint0Call,
+        CompoundV2Mint1Call,
+        CompoundV2RedeemCall,
+        CompoundV2RedeemUnderlyingCall,
+        CompoundV2BorrowCall,
+        CompoundV2RepayBorrow0Call,
+        CompoundV2RepayBorrow1Call,
+        CompoundV2RepayBorrowBehalf0Call,
+        CompoundV2RepayBorrowBehalf1Call,
+        CompoundV2LiquidateBorrow0Call,
+        CompoundV2LiquidateBorrow1Call,
+        CompoundV3SupplyCall,
+        CompoundV3SupplyToCall,
+        CompoundV3SupplyFromCall,
+        CompoundV3WithdrawCall,
+        CompoundV3WithdrawToCall,
+        CompoundV3WithdrawFromCall,
+        CompoundV3AbsorbCall,
+        CompoundV3BuyCollateralCall,
+        OneInchV5SwapCall,
+        OneInchV5UnoswapCall,
+        OneInchV5UnoswapToCall,
+        OneInchV5UniswapV3SwapCall,
+        OneInchV5UniswapV3SwapToCall,
+        OneInchV6SwapCall,
+        OneInchV6UnoswapCall,
+        OneInchV6UnoswapToCall,
+        OneInchV6EthUnoswapCall,
+        OneInchV6EthUnoswapToCall,
+        OneInchV6Unoswap2Call,
+        OneInchV6UnoswapTo2Call,
+        OneInchV6EthUnoswap2Call,
+        OneInchV6EthUnoswapTo2Call,
+        OneInchV6Unoswap3Call,
+        OneInchV6UnoswapTo3Call,
+        OneInchV6EthUnoswap3Call,
+        OneInchV6EthUnoswapTo3Call,
+        ZeroExTransformERC20Call,
+        ZeroExSellToUniswapCall,
+        ZeroExSellEthForTokenToUniswapV3Call,
+        ZeroExSellTokenForEthToUniswapV3Call,
+        ZeroExSellTokenForTokenToUniswapV3Call,
+        ParaswapV5SimpleSwapCall,
+        ParaswapV5SimpleBuyCall,
+        ParaswapV5MultiSwapCall,
+        ParaswapV5MegaSwapCall,
+        UniversalRouterExecute0Call,
+        UniversalRouterExecute1Call,
+        CowSwapSettleCall,
+        Erc4626DepositCall,
+        Erc4626MintCall,
+        Erc4626WithdrawCall,
+        Erc4626RedeemCall,
+        LidoSubmitCall,
+        LidoWstEthWrapCall,
+        LidoWstEthUnwrapCall,
+        LidoWithdrawalQueueRequestWithdrawalsCall,
+        LidoWithdrawalQueueRequestWithdrawalsWstETHCall,
+        LidoWithdrawalQueueRequestWithdrawalsWithPermitCall,
+        LidoWithdrawalQueueRequestWithdrawalsWstETHWithPermitCall,
+        LidoWithdrawalQueueClaimWithdrawalCall,
+        LidoWithdrawalQueueClaimWithdrawalsCall,
+        LidoWithdrawalQueueClaimWithdrawalsToCall,
+        RocketPoolDepositCall,
+        RocketPoolRethBurnCall,
+        SeaportFulfillBasicOrderCall,
+        SeaportFulfillBasicOrderEfficient6GL6ycCall,
+        SeaportFulfillOrderCall,
+        SeaportFulfillAdvancedOrderCall,
+        SeaportFulfillAvailableOrdersCall,
+        SeaportFulfillAvailableAdvancedOrdersCall,
+        BlurExecuteCall,
+        BlurBulkExecuteCall,
+        OpStandardBridgeDepositETHCall,
+        OpStandardBridgeDepositETHToCall,
+        OpStandardBridgeBridgeETHCall,
+        OpStandardBridgeBridgeETHToCall,
+        OpStandardBridgeDepositERC20Call,
+        OpStandardBridgeDepositERC20ToCall,
+        OpStandardBridgeBridgeERC20Call,
+        OpStandardBridgeBridgeERC20ToCall,
+        OpStandardBridgeFinalizeBridgeETHCall,
+        OpStandardBridgeFinalizeBridgeERC20Call,
+        ArbitrumInboxDepositEthCall,
+        ArbitrumGatewayRouterOutboundTransferCall,
+        ArbitrumGatewayRouterOutboundTransferCustomRefundCall,
+        ArbitrumGatewayFinalizeInboundTransferCall,
+        ZkSyncMailboxRequestL2TransactionCall,
+        ZkSyncMailboxFinalizeEthWithdrawalCall,
+        ZkSyncErc20BridgeDeposit0Call,
+        ZkSyncErc20BridgeDeposit1Call,
+        ZkSyncErc20BridgeFinalizeWithdrawalCall,
+        Permit2Permit0Call,
+        Permit2Permit1Call,
+        Permit2PermitTransferFrom0Call,
+        Permit2PermitTransferFrom1Call,
+        Permit2PermitWitnessTransferFrom0Call,
+        Permit2PermitWitnessTransferFrom1Call;
+    fallback:
+        Erc20TransferCall,
+        Erc721TransferFromCall,
+        Erc20TransferFromCall,
+        Erc721ApproveCall,
+        Erc20ApproveCall,
+        Erc721SetApprovalForAllCall,
+        Erc20PermitCall,
+        Erc721SafeTransferFrom0Call,
+        Erc721SafeTransferFrom1Call,
+        Erc1155SafeTransferFromCall,
+        Erc1155SafeBatchTransferFromCall;
+    receive:
+        WrappedNativeDepositCall
+);
@@ -0,0 +1,18 @@
+use alloy_primitives::Address;
+use serde::{Deserialize, Serialize};
+
+/// Pool information the classifiers of this crate need beyond the registry's
+/// token list, read from the `metadata` field of a registry entry. every field
+/// is optional so entries of protocols that don't need it can leave it out.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProtocolMetadata {
+    /// the coins `exchange_underlying` indexes into for curve lending and meta
+    /// pools, e.g. `[dai, usdc, usdt]` for the compound pool or `[coin0,
+    /// base coins..]` for a meta pool
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub underlying_tokens: Vec<Address>,
+    /// the l2 a canonical bridge contract connects to, e.g. `10` for
+    /// optimism's l1 standard bridge and `8453` for base's
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub l2_chain_id: Option<u64>,
+}
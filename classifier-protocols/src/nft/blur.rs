@@ -0,0 +1,197 @@
+use alloy_primitives::Address;
+use alloy_sol_types::sol;
+use brontes_classifier::{action_impl, types::CallInfo};
+use brontes_classifier_types::{NATIVE_TOKEN, NormalizedNftTrade};
+
+use super::nft_trades;
+use crate::{Actions, Protocol, ProtocolMetadata};
+
+sol! {
+    interface IBlurExchange {
+        struct Fee {
+            uint16 rate;
+            address recipient;
+        }
+
+        struct Order {
+            address trader;
+            uint8 side;
+            address matchingPolicy;
+            address collection;
+            uint256 tokenId;
+            uint256 amount;
+            address paymentToken;
+            uint256 price;
+            uint256 listingTime;
+            uint256 expirationTime;
+            Fee[] fees;
+            uint256 salt;
+            bytes extraParams;
+        }
+
+        struct Input {
+            Order order;
+            uint8 v;
+            bytes32 r;
+            bytes32 s;
+            bytes extraSignature;
+            uint8 signatureVersion;
+            uint256 blockNumber;
+        }
+
+        struct Execution {
+            Input sell;
+            Input buy;
+        }
+
+        event OrdersMatched(
+            address indexed maker,
+            address indexed taker,
+            Order sell,
+            bytes32 sellHash,
+            Order buy,
+            bytes32 buyHash
+        );
+
+        function execute(Input sell, Input buy) external payable;
+        function bulkExecute(Execution[] executions) external payable;
+    }
+}
+
+// the exchange sits behind a proxy and runs each match of a bulk execution
+// through a delegate call to itself. a match settles at the maker's price, the
+// sell side names the token even when it fills a collection bid. eth payments
+// use the zero address.
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::Blur,
+    IBlurExchange::executeCall,
+    Actions,
+    [..OrdersMatched*],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: BlurExecuteCallLogs, _db_ctx: &DB| {
+        matched(&info, log_data.orders_matched_field?)
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::Blur,
+    IBlurExchange::bulkExecuteCall,
+    Actions,
+    [..OrdersMatched*],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: BlurBulkExecuteCallLogs, _db_ctx: &DB| {
+        matched(&info, log_data.orders_matched_field?)
+    }
+);
+
+fn matched(info: &CallInfo, matches: Vec<IBlurExchange::OrdersMatched>) -> eyre::Result<Actions> {
+    let trades = matches
+        .into_iter()
+        .map(|matched| {
+            let (sell, buy) = (matched.sell, matched.buy);
+            let price = if matched.maker == sell.trader {
+                sell.price
+            } else {
+                buy.price
+            };
+
+            NormalizedNftTrade {
+                protocol: Protocol::Blur,
+                trace_index: info.trace_idx,
+                marketplace: info.target_address,
+                seller: sell.trader,
+                buyer: buy.trader,
+                collection: sell.collection,
+                token_id: sell.tokenId,
+                amount: sell.amount,
+                payment_token: if sell.paymentToken == Address::ZERO {
+                    NATIVE_TOKEN
+                } else {
+                    sell.paymentToken
+                },
+                price,
+            }
+        })
+        .collect();
+
+    nft_trades(Protocol::Blur, info.trace_idx, info.target_address, trades)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{B256, Bytes, U256, address};
+
+    use super::*;
+    use crate::test_utils::call_info;
+
+    const BLUR: Address = address!("000000000000ad05ccc4f10045630fb830b95127");
+    const COLLECTION: Address = address!("bc4ca0eda7647a8ab7c2061c2e118a18a936f13d");
+    const SELLER: Address = address!("1000000000000000000000000000000000000001");
+    const BUYER: Address = address!("2000000000000000000000000000000000000002");
+
+    fn order(trader: Address, side: u8, price: u64) -> IBlurExchange::Order {
+        IBlurExchange::Order {
+            trader,
+            side,
+            matchingPolicy: Address::ZERO,
+            collection: COLLECTION,
+            tokenId: U256::from(7),
+            amount: U256::from(1),
+            paymentToken: Address::ZERO,
+            price: U256::from(price),
+            listingTime: U256::ZERO,
+            expirationTime: U256::ZERO,
+            fees: Vec::new(),
+            salt: U256::ZERO,
+            extraParams: Bytes::new(),
+        }
+    }
+
+    /// a sell at 10 matched with a buy at 12
+    fn orders_matched(maker: Address, taker: Address) -> IBlurExchange::OrdersMatched {
+        IBlurExchange::OrdersMatched {
+            maker,
+            taker,
+            sell: order(SELLER, 1, 10),
+            sellHash: B256::ZERO,
+            buy: order(BUYER, 0, 12),
+            buyHash: B256::ZERO,
+        }
+    }
+
+    fn trade(matches: Vec<IBlurExchange::OrdersMatched>) -> NormalizedNftTrade<Protocol> {
+        match matched(&call_info(BUYER, BLUR), matches).unwrap() {
+            Actions::NftTrade(trade) => trade,
+            action => panic!("expected a single trade, got {action:?}"),
+        }
+    }
+
+    #[test]
+    fn settles_at_the_makers_price() {
+        let listing = trade(vec![orders_matched(SELLER, BUYER)]);
+        assert_eq!(listing.price, U256::from(10));
+        assert_eq!((listing.seller, listing.buyer), (SELLER, BUYER));
+        assert_eq!(listing.payment_token, NATIVE_TOKEN);
+
+        let bid = trade(vec![orders_matched(BUYER, SELLER)]);
+        assert_eq!(bid.price, U256::from(12));
+        assert_eq!((bid.seller, bid.buyer), (SELLER, BUYER));
+    }
+
+    #[test]
+    fn reports_bulk_executions_as_a_multi_trade() {
+        let matches = vec![orders_matched(SELLER, BUYER), orders_matched(BUYER, SELLER)];
+        let Actions::MultiNftTrade(trades) = matched(&call_info(BUYER, BLUR), matches).unwrap()
+        else {
+            panic!("expected a multi trade");
+        };
+
+        assert_eq!(trades.trades.len(), 2);
+        assert!(matched(&call_info(BUYER, BLUR), Vec::new()).is_err());
+    }
+}
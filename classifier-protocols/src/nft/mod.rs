@@ -0,0 +1,30 @@
+mod blur;
+mod seaport;
+
+use alloy_primitives::Address;
+pub use blur::*;
+use brontes_classifier_types::{Action, NormalizedMultiNftTrade, NormalizedNftTrade};
+pub use seaport::*;
+
+use crate::{Actions, Protocol};
+
+// marketplaces fill one or more orders per call, a single fill is reported as
+// a trade and sweeps as a multi trade holding one trade per nft.
+
+fn nft_trades(
+    protocol: Protocol,
+    trace_index: u64,
+    marketplace: Address,
+    mut trades: Vec<NormalizedNftTrade<Protocol>>,
+) -> eyre::Result<Actions> {
+    match trades.len() {
+        0 => Err(eyre::eyre!("{protocol} call filled no nft orders")),
+        1 => Ok(Action::NftTrade(trades.remove(0))),
+        _ => Ok(Action::MultiNftTrade(NormalizedMultiNftTrade {
+            protocol,
+            trace_index,
+            marketplace,
+            trades,
+        })),
+    }
+}
@@ -0,0 +1,451 @@
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::sol;
+use brontes_classifier::{action_impl, types::CallInfo};
+use brontes_classifier_types::{NATIVE_TOKEN, NormalizedNftTrade};
+
+use super::nft_trades;
+use crate::{Actions, Protocol, ProtocolMetadata};
+
+sol! {
+    interface ISeaport {
+        struct AdditionalRecipient {
+            uint256 amount;
+            address recipient;
+        }
+
+        struct BasicOrderParameters {
+            address considerationToken;
+            uint256 considerationIdentifier;
+            uint256 considerationAmount;
+            address offerer;
+            address zone;
+            address offerToken;
+            uint256 offerIdentifier;
+            uint256 offerAmount;
+            uint8 basicOrderType;
+            uint256 startTime;
+            uint256 endTime;
+            bytes32 zoneHash;
+            uint256 salt;
+            bytes32 offererConduitKey;
+            bytes32 fulfillerConduitKey;
+            uint256 totalOriginalAdditionalRecipients;
+            AdditionalRecipient[] additionalRecipients;
+            bytes signature;
+        }
+
+        struct OfferItem {
+            uint8 itemType;
+            address token;
+            uint256 identifierOrCriteria;
+            uint256 startAmount;
+            uint256 endAmount;
+        }
+
+        struct ConsiderationItem {
+            uint8 itemType;
+            address token;
+            uint256 identifierOrCriteria;
+            uint256 startAmount;
+            uint256 endAmount;
+            address recipient;
+        }
+
+        struct OrderParameters {
+            address offerer;
+            address zone;
+            OfferItem[] offer;
+            ConsiderationItem[] consideration;
+            uint8 orderType;
+            uint256 startTime;
+            uint256 endTime;
+            bytes32 zoneHash;
+            uint256 salt;
+            bytes32 conduitKey;
+            uint256 totalOriginalConsiderationItems;
+        }
+
+        struct Order {
+            OrderParameters parameters;
+            bytes signature;
+        }
+
+        struct AdvancedOrder {
+            OrderParameters parameters;
+            uint120 numerator;
+            uint120 denominator;
+            bytes signature;
+            bytes extraData;
+        }
+
+        struct CriteriaResolver {
+            uint256 orderIndex;
+            uint8 side;
+            uint256 index;
+            uint256 identifier;
+            bytes32[] criteriaProof;
+        }
+
+        struct FulfillmentComponent {
+            uint256 orderIndex;
+            uint256 itemIndex;
+        }
+
+        struct SpentItem {
+            uint8 itemType;
+            address token;
+            uint256 identifier;
+            uint256 amount;
+        }
+
+        struct ReceivedItem {
+            uint8 itemType;
+            address token;
+            uint256 identifier;
+            uint256 amount;
+            address recipient;
+        }
+
+        event OrderFulfilled(
+            bytes32 orderHash,
+            address indexed offerer,
+            address indexed zone,
+            address recipient,
+            SpentItem[] offer,
+            ReceivedItem[] consideration
+        );
+
+        function fulfillBasicOrder(BasicOrderParameters parameters) external payable;
+        function fulfillBasicOrder_efficient_6GL6yc(
+            BasicOrderParameters parameters
+        ) external payable;
+        function fulfillOrder(Order order, bytes32 fulfillerConduitKey) external payable;
+        function fulfillAdvancedOrder(
+            AdvancedOrder advancedOrder,
+            CriteriaResolver[] criteriaResolvers,
+            bytes32 fulfillerConduitKey,
+            address recipient
+        ) external payable;
+        function fulfillAvailableOrders(
+            Order[] orders,
+            FulfillmentComponent[][] offerFulfillments,
+            FulfillmentComponent[][] considerationFulfillments,
+            bytes32 fulfillerConduitKey,
+            uint256 maximumFulfilled
+        ) external payable;
+        function fulfillAvailableAdvancedOrders(
+            AdvancedOrder[] advancedOrders,
+            CriteriaResolver[] criteriaResolvers,
+            FulfillmentComponent[][] offerFulfillments,
+            FulfillmentComponent[][] considerationFulfillments,
+            bytes32 fulfillerConduitKey,
+            address recipient,
+            uint256 maximumFulfilled
+        ) external payable;
+    }
+}
+
+// an order fulfilled event lists the items the offerer gave up and the ones
+// it asked for, with criteria already resolved to token ids. a listing offers
+// nfts for a consideration paid by the fulfiller, a bid offers the payment for
+// nfts sent to the offerer. the consideration includes the marketplace fees and
+// royalties, the bid's offer pays for them. `matchOrders` fills both sides of
+// a trade as separate orders and isn't classified.
+
+const NATIVE: u8 = 0;
+const ERC20: u8 = 1;
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::Seaport,
+    ISeaport::fulfillBasicOrderCall,
+    Actions,
+    [..OrderFulfilled*],
+    logs: true,
+    |info: CallInfo, log_data: SeaportFulfillBasicOrderCallLogs, _db_ctx: &DB| {
+        fulfilled(&info, log_data.order_fulfilled_field?)
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::Seaport,
+    ISeaport::fulfillBasicOrder_efficient_6GL6ycCall,
+    Actions,
+    [..OrderFulfilled*],
+    logs: true,
+    |info: CallInfo,
+    log_data: SeaportFulfillBasicOrderEfficient6GL6ycCallLogs,
+    _db_ctx: &DB| {
+        fulfilled(&info, log_data.order_fulfilled_field?)
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::Seaport,
+    ISeaport::fulfillOrderCall,
+    Actions,
+    [..OrderFulfilled*],
+    logs: true,
+    |info: CallInfo, log_data: SeaportFulfillOrderCallLogs, _db_ctx: &DB| {
+        fulfilled(&info, log_data.order_fulfilled_field?)
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::Seaport,
+    ISeaport::fulfillAdvancedOrderCall,
+    Actions,
+    [..OrderFulfilled*],
+    logs: true,
+    |info: CallInfo, log_data: SeaportFulfillAdvancedOrderCallLogs, _db_ctx: &DB| {
+        fulfilled(&info, log_data.order_fulfilled_field?)
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::Seaport,
+    ISeaport::fulfillAvailableOrdersCall,
+    Actions,
+    [..OrderFulfilled*],
+    logs: true,
+    |info: CallInfo, log_data: SeaportFulfillAvailableOrdersCallLogs, _db_ctx: &DB| {
+        fulfilled(&info, log_data.order_fulfilled_field?)
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::Seaport,
+    ISeaport::fulfillAvailableAdvancedOrdersCall,
+    Actions,
+    [..OrderFulfilled*],
+    logs: true,
+    |info: CallInfo,
+    log_data: SeaportFulfillAvailableAdvancedOrdersCallLogs,
+    _db_ctx: &DB| {
+        fulfilled(&info, log_data.order_fulfilled_field?)
+    }
+);
+
+fn fulfilled(info: &CallInfo, orders: Vec<ISeaport::OrderFulfilled>) -> eyre::Result<Actions> {
+    let trades = orders
+        .iter()
+        .flat_map(|order| order_trades(info, order))
+        .collect();
+    nft_trades(
+        Protocol::Seaport,
+        info.trace_idx,
+        info.target_address,
+        trades,
+    )
+}
+
+/// one trade per nft of the order, a bundle's price is split evenly between
+/// its nfts with the remainder on the first. orders without nfts on either
+/// side are skipped
+fn order_trades(
+    info: &CallInfo,
+    order: &ISeaport::OrderFulfilled,
+) -> Vec<NormalizedNftTrade<Protocol>> {
+    let is_nft = |item_type: u8| item_type > ERC20;
+
+    let offered_nfts = order
+        .offer
+        .iter()
+        .filter(|item| is_nft(item.itemType))
+        .map(|item| (item.token, item.identifier, item.amount))
+        .collect::<Vec<_>>();
+    let (seller, buyer, nfts, payments) = if !offered_nfts.is_empty() {
+        let payments = order
+            .consideration
+            .iter()
+            .map(|item| (item.itemType, item.token, item.amount))
+            .collect::<Vec<_>>();
+        (order.offerer, order.recipient, offered_nfts, payments)
+    } else {
+        let nfts = order
+            .consideration
+            .iter()
+            .filter(|item| is_nft(item.itemType))
+            .map(|item| (item.token, item.identifier, item.amount))
+            .collect::<Vec<_>>();
+        let payments = order
+            .offer
+            .iter()
+            .map(|item| (item.itemType, item.token, item.amount))
+            .collect::<Vec<_>>();
+        (order.recipient, order.offerer, nfts, payments)
+    };
+    if nfts.is_empty() {
+        return Vec::new();
+    }
+
+    // the order is priced in the first currency it pays in
+    let payments = payments
+        .into_iter()
+        .filter(|(item_type, ..)| *item_type == NATIVE || *item_type == ERC20)
+        .map(|(item_type, token, amount)| {
+            (
+                if item_type == NATIVE {
+                    NATIVE_TOKEN
+                } else {
+                    token
+                },
+                amount,
+            )
+        })
+        .collect::<Vec<_>>();
+    let payment_token = payments
+        .first()
+        .map(|(token, _)| *token)
+        .unwrap_or(Address::ZERO);
+    let price = payments
+        .iter()
+        .filter(|(token, _)| *token == payment_token)
+        .fold(U256::ZERO, |acc, (_, amount)| acc.saturating_add(*amount));
+
+    let count = U256::from(nfts.len());
+    let (share, remainder) = (price / count, price % count);
+    nfts.into_iter()
+        .enumerate()
+        .map(|(i, (collection, token_id, amount))| NormalizedNftTrade {
+            protocol: Protocol::Seaport,
+            trace_index: info.trace_idx,
+            marketplace: info.target_address,
+            seller,
+            buyer,
+            collection,
+            token_id,
+            amount,
+            payment_token,
+            price: if i == 0 { share + remainder } else { share },
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{B256, address};
+
+    use super::*;
+    use crate::test_utils::call_info;
+
+    const SEAPORT: Address = address!("0000000000000068f116a894984e2db1123eb395");
+    const COLLECTION: Address = address!("bc4ca0eda7647a8ab7c2061c2e118a18a936f13d");
+    const WETH: Address = address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2");
+    const OFFERER: Address = address!("1000000000000000000000000000000000000001");
+    const FULFILLER: Address = address!("2000000000000000000000000000000000000002");
+    const FEE_RECIPIENT: Address = address!("3000000000000000000000000000000000000003");
+    const ERC721: u8 = 2;
+
+    fn spent(item_type: u8, token: Address, identifier: u64, amount: u64) -> ISeaport::SpentItem {
+        ISeaport::SpentItem {
+            itemType: item_type,
+            token,
+            identifier: U256::from(identifier),
+            amount: U256::from(amount),
+        }
+    }
+
+    fn received(
+        item_type: u8,
+        token: Address,
+        identifier: u64,
+        amount: u64,
+        recipient: Address,
+    ) -> ISeaport::ReceivedItem {
+        ISeaport::ReceivedItem {
+            itemType: item_type,
+            token,
+            identifier: U256::from(identifier),
+            amount: U256::from(amount),
+            recipient,
+        }
+    }
+
+    fn order(
+        offer: Vec<ISeaport::SpentItem>,
+        consideration: Vec<ISeaport::ReceivedItem>,
+    ) -> ISeaport::OrderFulfilled {
+        ISeaport::OrderFulfilled {
+            orderHash: B256::ZERO,
+            offerer: OFFERER,
+            zone: Address::ZERO,
+            recipient: FULFILLER,
+            offer,
+            consideration,
+        }
+    }
+
+    #[test]
+    fn splits_a_bundle_listing_with_the_remainder_on_the_first_nft() {
+        let info = call_info(FULFILLER, SEAPORT);
+        let listing = order(
+            vec![
+                spent(ERC721, COLLECTION, 1, 1),
+                spent(ERC721, COLLECTION, 2, 1),
+            ],
+            vec![
+                received(NATIVE, Address::ZERO, 0, 10, OFFERER),
+                received(NATIVE, Address::ZERO, 0, 1, FEE_RECIPIENT),
+            ],
+        );
+
+        let trades = order_trades(&info, &listing);
+        let rows = trades
+            .iter()
+            .map(|trade| (trade.seller, trade.buyer, trade.token_id, trade.price))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            vec![
+                (OFFERER, FULFILLER, U256::from(1), U256::from(6)),
+                (OFFERER, FULFILLER, U256::from(2), U256::from(5)),
+            ]
+        );
+        assert!(
+            trades
+                .iter()
+                .all(|trade| trade.payment_token == NATIVE_TOKEN)
+        );
+    }
+
+    #[test]
+    fn prices_an_accepted_bid_by_the_offered_payment() {
+        let info = call_info(FULFILLER, SEAPORT);
+        // the fee is taken out of the offered weth, the bidder pays 100
+        let bid = order(
+            vec![spent(ERC20, WETH, 0, 100)],
+            vec![
+                received(ERC721, COLLECTION, 7, 1, OFFERER),
+                received(ERC20, WETH, 0, 3, FEE_RECIPIENT),
+            ],
+        );
+
+        let trades = order_trades(&info, &bid);
+        assert_eq!(trades.len(), 1);
+        assert_eq!(
+            (trades[0].seller, trades[0].buyer, trades[0].token_id),
+            (FULFILLER, OFFERER, U256::from(7))
+        );
+        assert_eq!(
+            (trades[0].payment_token, trades[0].price),
+            (WETH, U256::from(100))
+        );
+    }
+
+    #[test]
+    fn skips_orders_without_nfts() {
+        let info = call_info(FULFILLER, SEAPORT);
+        let swap = order(
+            vec![spent(ERC20, WETH, 0, 100)],
+            vec![received(NATIVE, Address::ZERO, 0, 100, OFFERER)],
+        );
+
+        assert!(order_trades(&info, &swap).is_empty());
+    }
+}
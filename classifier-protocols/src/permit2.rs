@@ -0,0 +1,249 @@
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::sol;
+use brontes_classifier::{action_impl, types::CallInfo};
+use brontes_classifier_types::{
+    NormalizedApproval, NormalizedMultiApproval, NormalizedMultiTransfer, NormalizedTransfer,
+};
+
+use crate::{Actions, Protocol, ProtocolMetadata};
+
+sol! {
+    interface IPermit2 {
+        struct PermitDetails {
+            address token;
+            uint160 amount;
+            uint48 expiration;
+            uint48 nonce;
+        }
+
+        struct PermitSingle {
+            PermitDetails details;
+            address spender;
+            uint256 sigDeadline;
+        }
+
+        struct PermitBatch {
+            PermitDetails[] details;
+            address spender;
+            uint256 sigDeadline;
+        }
+
+        struct TokenPermissions {
+            address token;
+            uint256 amount;
+        }
+
+        struct PermitTransferFrom {
+            TokenPermissions permitted;
+            uint256 nonce;
+            uint256 deadline;
+        }
+
+        struct PermitBatchTransferFrom {
+            TokenPermissions[] permitted;
+            uint256 nonce;
+            uint256 deadline;
+        }
+
+        struct SignatureTransferDetails {
+            address to;
+            uint256 requestedAmount;
+        }
+
+        function permit(address owner, PermitSingle permitSingle, bytes signature) external;
+        function permit(address owner, PermitBatch permitBatch, bytes signature) external;
+        function permitTransferFrom(
+            PermitTransferFrom permit,
+            SignatureTransferDetails transferDetails,
+            address owner,
+            bytes signature
+        ) external;
+        function permitTransferFrom(
+            PermitBatchTransferFrom permit,
+            SignatureTransferDetails[] transferDetails,
+            address owner,
+            bytes signature
+        ) external;
+        function permitWitnessTransferFrom(
+            PermitTransferFrom permit,
+            SignatureTransferDetails transferDetails,
+            address owner,
+            bytes32 witness,
+            string witnessTypeString,
+            bytes signature
+        ) external;
+        function permitWitnessTransferFrom(
+            PermitBatchTransferFrom permit,
+            SignatureTransferDetails[] transferDetails,
+            address owner,
+            bytes32 witness,
+            string witnessTypeString,
+            bytes signature
+        ) external;
+    }
+}
+
+// permit2 keeps its own allowances on top of the token approvals made to it,
+// they're reported with permit2 as the contract holding them. an expiration of
+// zero lapses at the end of the block the permit is used in. signature
+// transfers, with or without a witness, move the tokens without leaving an
+// allowance behind, the token's own `transferFrom` is still classified on the
+// child trace.
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::Permit2,
+    IPermit2::permit_0Call,
+    Approval,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: permit_0Call, _db_ctx: &DB| {
+        let permit = call_data.permitSingle;
+        Ok(permitted(&info, call_data.owner, permit.spender, permit.details))
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::Permit2,
+    IPermit2::permit_1Call,
+    MultiApproval,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: permit_1Call, _db_ctx: &DB| {
+        let permit = call_data.permitBatch;
+        Ok(NormalizedMultiApproval {
+            trace_index: info.trace_idx,
+            approvals: permit
+                .details
+                .into_iter()
+                .map(|details| permitted(&info, call_data.owner, permit.spender, details))
+                .collect(),
+        })
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::Permit2,
+    IPermit2::permitTransferFrom_0Call,
+    Transfer,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: permitTransferFrom_0Call, _db_ctx: &DB| {
+        Ok(signature_transfer(
+            &info,
+            call_data.owner,
+            call_data.permit.permitted.token,
+            call_data.transferDetails,
+        ))
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::Permit2,
+    IPermit2::permitTransferFrom_1Call,
+    MultiTransfer,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: permitTransferFrom_1Call, _db_ctx: &DB| {
+        batch_signature_transfer(
+            &info,
+            call_data.owner,
+            call_data.permit.permitted,
+            call_data.transferDetails,
+        )
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::Permit2,
+    IPermit2::permitWitnessTransferFrom_0Call,
+    Transfer,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: permitWitnessTransferFrom_0Call, _db_ctx: &DB| {
+        Ok(signature_transfer(
+            &info,
+            call_data.owner,
+            call_data.permit.permitted.token,
+            call_data.transferDetails,
+        ))
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::Permit2,
+    IPermit2::permitWitnessTransferFrom_1Call,
+    MultiTransfer,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: permitWitnessTransferFrom_1Call, _db_ctx: &DB| {
+        batch_signature_transfer(
+            &info,
+            call_data.owner,
+            call_data.permit.permitted,
+            call_data.transferDetails,
+        )
+    }
+);
+
+fn permitted(
+    info: &CallInfo,
+    owner: Address,
+    spender: Address,
+    details: IPermit2::PermitDetails,
+) -> NormalizedApproval {
+    NormalizedApproval {
+        trace_index: info.trace_idx,
+        owner,
+        spender,
+        token: details.token,
+        amount: U256::from(details.amount),
+        expiration: Some(details.expiration.to()),
+        via: Some(info.target_address),
+    }
+}
+
+fn signature_transfer(
+    info: &CallInfo,
+    owner: Address,
+    token: Address,
+    details: IPermit2::SignatureTransferDetails,
+) -> NormalizedTransfer {
+    NormalizedTransfer {
+        trace_index: info.trace_idx,
+        from: owner,
+        to: details.to,
+        token,
+        amount: details.requestedAmount,
+        fee: U256::ZERO,
+    }
+}
+
+fn batch_signature_transfer(
+    info: &CallInfo,
+    owner: Address,
+    permitted: Vec<IPermit2::TokenPermissions>,
+    details: Vec<IPermit2::SignatureTransferDetails>,
+) -> eyre::Result<NormalizedMultiTransfer> {
+    if permitted.len() != details.len() {
+        return Err(eyre::eyre!(
+            "permit2 batch transfer of {} tokens with {} transfer details",
+            permitted.len(),
+            details.len()
+        ));
+    }
+
+    Ok(NormalizedMultiTransfer {
+        trace_index: info.trace_idx,
+        transfers: permitted
+            .into_iter()
+            .zip(details)
+            .map(|(permission, details)| signature_transfer(info, owner, permission.token, details))
+            .collect(),
+    })
+}
@@ -0,0 +1,82 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+macro_rules! protocols {
+    ($($variant:ident),* $(,)?) => {
+        /// Every protocol with a classifier in this crate. registry entries name
+        /// the protocol by its variant, e.g. `"UniswapV2"`.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+        pub enum Protocol {
+            $($variant),*
+        }
+
+        impl Protocol {
+            pub const ALL: &[Protocol] = &[$(Protocol::$variant),*];
+
+            pub const fn name(&self) -> &'static str {
+                match self {
+                    $(Protocol::$variant => stringify!($variant)),*
+                }
+            }
+        }
+
+        impl FromStr for Protocol {
+            type Err = eyre::Report;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $(stringify!($variant) => Ok(Protocol::$variant),)*
+                    _ => Err(eyre::eyre!("unknown protocol {s}")),
+                }
+            }
+        }
+    };
+}
+
+protocols!(
+    Erc20,
+    Erc721,
+    Erc1155,
+    WrappedNative,
+    UniswapV2,
+    SushiSwapV2,
+    PancakeSwapV2,
+    UniswapV3,
+    UniswapV3PositionManager,
+    UniswapV4,
+    CurveStable,
+    CurveCrypto,
+    BalancerV2,
+    AaveV2,
+    AaveV3,
+    CompoundV2,
+    CompoundV3,
+    OneInchV5,
+    OneInchV6,
+    ZeroEx,
+    ParaswapV5,
+    UniversalRouter,
+    CowSwap,
+    Erc4626,
+    Lido,
+    LidoWstEth,
+    LidoWithdrawalQueue,
+    RocketPool,
+    RocketPoolReth,
+    Seaport,
+    Blur,
+    OpStandardBridge,
+    ArbitrumInbox,
+    ArbitrumGatewayRouter,
+    ArbitrumGateway,
+    ZkSyncMailbox,
+    ZkSyncErc20Bridge,
+    Permit2
+);
+
+impl fmt::Display for Protocol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
@@ -0,0 +1,412 @@
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::sol;
+use brontes_classifier::{action_impl, context::DataContext, types::CallInfo};
+use brontes_classifier_types::{NATIVE_TOKEN, NormalizedDeposit, NormalizedWithdraw};
+
+use crate::{Actions, Protocol, ProtocolMetadata, registered_token};
+
+sol! {
+    interface IStETH {
+        event Submitted(address indexed sender, uint256 amount, address referral);
+        event TransferShares(address indexed from, address indexed to, uint256 sharesValue);
+
+        function submit(address _referral) external payable returns (uint256);
+    }
+
+    interface IWstETH {
+        // emitted by both wsteth and steth, the steth side carries the pooled
+        // amount and the wsteth side the shares it stands for
+        event Transfer(address indexed from, address indexed to, uint256 value);
+
+        function wrap(uint256 _stETHAmount) external returns (uint256);
+        function unwrap(uint256 _wstETHAmount) external returns (uint256);
+    }
+
+    interface IWithdrawalQueue {
+        struct PermitInput {
+            uint256 value;
+            uint256 deadline;
+            uint8 v;
+            bytes32 r;
+            bytes32 s;
+        }
+
+        event WithdrawalRequested(
+            uint256 indexed requestId,
+            address indexed requestor,
+            address indexed owner,
+            uint256 amountOfStETH,
+            uint256 amountOfShares
+        );
+        event WithdrawalClaimed(
+            uint256 indexed requestId,
+            address indexed owner,
+            address indexed receiver,
+            uint256 amountOfETH
+        );
+
+        function requestWithdrawals(
+            uint256[] _amounts,
+            address _owner
+        ) external returns (uint256[] requestIds);
+        function requestWithdrawalsWstETH(
+            uint256[] _amounts,
+            address _owner
+        ) external returns (uint256[] requestIds);
+        function requestWithdrawalsWithPermit(
+            uint256[] _amounts,
+            address _owner,
+            PermitInput _permit
+        ) external returns (uint256[] requestIds);
+        function requestWithdrawalsWstETHWithPermit(
+            uint256[] _amounts,
+            address _owner,
+            PermitInput _permit
+        ) external returns (uint256[] requestIds);
+        function claimWithdrawal(uint256 _requestId) external;
+        function claimWithdrawals(uint256[] _requestIds, uint256[] _hints) external;
+        function claimWithdrawalsTo(
+            uint256[] _requestIds,
+            uint256[] _hints,
+            address _recipient
+        ) external;
+    }
+}
+
+// steth and the withdrawal queue sit behind proxies, wsteth doesn't. the
+// queue takes steth for each request, unwrapping wsteth requests first, and
+// pays out eth once a request is finalized and claimed. a request is a
+// deposit of steth into the queue and a claim the withdrawal of its eth.
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::Lido,
+    IStETH::submitCall,
+    Deposit,
+    [..Submitted, ..TransferShares],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: LidoSubmitCallLogs, _db_ctx: &DB| {
+        let submitted = log_data.submitted_field?;
+        Ok(NormalizedDeposit {
+            protocol: Protocol::Lido,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            from: info.from_address,
+            recipient: submitted.sender,
+            asset: NATIVE_TOKEN,
+            amount: submitted.amount,
+            shares: Some(log_data.transfer_shares_field?.sharesValue),
+        })
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::LidoWstEth,
+    IWstETH::wrapCall,
+    Deposit,
+    [..Transfer*],
+    logs: true,
+    include_child_logs: true,
+    |info: CallInfo, log_data: LidoWstEthWrapCallLogs, db_ctx: &DB| {
+        let steth = registered_token(db_ctx, info.target_address)?;
+        let (pooled, shares) = converted(
+            &info,
+            steth,
+            log_data.transfer_field?,
+            log_data.transfer_emitter?,
+        );
+
+        Ok(NormalizedDeposit {
+            protocol: Protocol::LidoWstEth,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            from: info.from_address,
+            recipient: info.from_address,
+            asset: steth,
+            amount: pooled,
+            shares: Some(shares),
+        })
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::LidoWstEth,
+    IWstETH::unwrapCall,
+    Withdraw,
+    [..Transfer*],
+    logs: true,
+    include_child_logs: true,
+    |info: CallInfo, log_data: LidoWstEthUnwrapCallLogs, db_ctx: &DB| {
+        let steth = registered_token(db_ctx, info.target_address)?;
+        let (pooled, shares) = converted(
+            &info,
+            steth,
+            log_data.transfer_field?,
+            log_data.transfer_emitter?,
+        );
+
+        Ok(NormalizedWithdraw {
+            protocol: Protocol::LidoWstEth,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            from: info.from_address,
+            owner: info.from_address,
+            recipient: info.from_address,
+            asset: steth,
+            amount: pooled,
+            shares: Some(shares),
+        })
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::LidoWithdrawalQueue,
+    IWithdrawalQueue::requestWithdrawalsCall,
+    Deposit,
+    [..WithdrawalRequested*],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: LidoWithdrawalQueueRequestWithdrawalsCallLogs, db_ctx: &DB| {
+        requested(&info, db_ctx, log_data.withdrawal_requested_field?)
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::LidoWithdrawalQueue,
+    IWithdrawalQueue::requestWithdrawalsWstETHCall,
+    Deposit,
+    [..WithdrawalRequested*],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo,
+    log_data: LidoWithdrawalQueueRequestWithdrawalsWstETHCallLogs,
+    db_ctx: &DB| {
+        requested(&info, db_ctx, log_data.withdrawal_requested_field?)
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::LidoWithdrawalQueue,
+    IWithdrawalQueue::requestWithdrawalsWithPermitCall,
+    Deposit,
+    [..WithdrawalRequested*],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo,
+    log_data: LidoWithdrawalQueueRequestWithdrawalsWithPermitCallLogs,
+    db_ctx: &DB| {
+        requested(&info, db_ctx, log_data.withdrawal_requested_field?)
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::LidoWithdrawalQueue,
+    IWithdrawalQueue::requestWithdrawalsWstETHWithPermitCall,
+    Deposit,
+    [..WithdrawalRequested*],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo,
+    log_data: LidoWithdrawalQueueRequestWithdrawalsWstETHWithPermitCallLogs,
+    db_ctx: &DB| {
+        requested(&info, db_ctx, log_data.withdrawal_requested_field?)
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::LidoWithdrawalQueue,
+    IWithdrawalQueue::claimWithdrawalCall,
+    Withdraw,
+    [..WithdrawalClaimed*],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: LidoWithdrawalQueueClaimWithdrawalCallLogs, _db_ctx: &DB| {
+        claimed(&info, log_data.withdrawal_claimed_field?)
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::LidoWithdrawalQueue,
+    IWithdrawalQueue::claimWithdrawalsCall,
+    Withdraw,
+    [..WithdrawalClaimed*],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: LidoWithdrawalQueueClaimWithdrawalsCallLogs, _db_ctx: &DB| {
+        claimed(&info, log_data.withdrawal_claimed_field?)
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::LidoWithdrawalQueue,
+    IWithdrawalQueue::claimWithdrawalsToCall,
+    Withdraw,
+    [..WithdrawalClaimed*],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: LidoWithdrawalQueueClaimWithdrawalsToCallLogs, _db_ctx: &DB| {
+        claimed(&info, log_data.withdrawal_claimed_field?)
+    }
+);
+
+/// the steth and wsteth amounts a wrap or unwrap exchanged, the wsteth amount
+/// being the steth shares it stands for
+fn converted(
+    info: &CallInfo,
+    steth: Address,
+    transfers: Vec<IWstETH::Transfer>,
+    emitters: Vec<Address>,
+) -> (U256, U256) {
+    transfers.iter().zip(&emitters).fold(
+        (U256::ZERO, U256::ZERO),
+        |(pooled, shares), (transfer, emitter)| {
+            if *emitter == steth {
+                (pooled.saturating_add(transfer.value), shares)
+            } else if *emitter == info.target_address {
+                (pooled, shares.saturating_add(transfer.value))
+            } else {
+                (pooled, shares)
+            }
+        },
+    )
+}
+
+/// every request of a call locks its steth for the same owner
+fn requested<DB: DataContext<Protocol, ProtocolMetadata>>(
+    info: &CallInfo,
+    db_ctx: &DB,
+    requests: Vec<IWithdrawalQueue::WithdrawalRequested>,
+) -> eyre::Result<NormalizedDeposit<Protocol>> {
+    let Some(first) = requests.first() else {
+        return Err(eyre::eyre!("lido withdrawal request without a request"));
+    };
+
+    Ok(NormalizedDeposit {
+        protocol: Protocol::LidoWithdrawalQueue,
+        trace_index: info.trace_idx,
+        pool: info.target_address,
+        from: info.from_address,
+        recipient: first.owner,
+        asset: registered_token(db_ctx, info.target_address)?,
+        amount: requests.iter().fold(U256::ZERO, |acc, request| {
+            acc.saturating_add(request.amountOfStETH)
+        }),
+        shares: Some(requests.iter().fold(U256::ZERO, |acc, request| {
+            acc.saturating_add(request.amountOfShares)
+        })),
+    })
+}
+
+/// every request of a claim is owned by the caller and paid to one receiver
+fn claimed(
+    info: &CallInfo,
+    claims: Vec<IWithdrawalQueue::WithdrawalClaimed>,
+) -> eyre::Result<NormalizedWithdraw<Protocol>> {
+    let Some(first) = claims.first() else {
+        return Err(eyre::eyre!("lido withdrawal claim without a request"));
+    };
+
+    Ok(NormalizedWithdraw {
+        protocol: Protocol::LidoWithdrawalQueue,
+        trace_index: info.trace_idx,
+        pool: info.target_address,
+        from: info.from_address,
+        owner: first.owner,
+        recipient: first.receiver,
+        asset: NATIVE_TOKEN,
+        amount: claims.iter().fold(U256::ZERO, |acc, claim| {
+            acc.saturating_add(claim.amountOfETH)
+        }),
+        shares: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::address;
+
+    use super::*;
+    use crate::test_utils::{TestRegistry, call_info};
+
+    const STETH: Address = address!("ae7ab96520de3a18e5e111b5eaab095312d7fe84");
+    const WSTETH: Address = address!("7f39c581f595b53c5cb19bd0b3f8da6c935e2ca0");
+    const QUEUE: Address = address!("889edc2edab5f40e902b864ad4d7ade8e412f9b1");
+    const USER: Address = address!("1000000000000000000000000000000000000001");
+    const RECEIVER: Address = address!("2000000000000000000000000000000000000002");
+
+    fn transfer(value: u64) -> IWstETH::Transfer {
+        IWstETH::Transfer {
+            from: USER,
+            to: WSTETH,
+            value: U256::from(value),
+        }
+    }
+
+    fn request(steth: u64, shares: u64) -> IWithdrawalQueue::WithdrawalRequested {
+        IWithdrawalQueue::WithdrawalRequested {
+            requestId: U256::from(steth),
+            requestor: USER,
+            owner: RECEIVER,
+            amountOfStETH: U256::from(steth),
+            amountOfShares: U256::from(shares),
+        }
+    }
+
+    fn claim(eth: u64) -> IWithdrawalQueue::WithdrawalClaimed {
+        IWithdrawalQueue::WithdrawalClaimed {
+            requestId: U256::from(eth),
+            owner: USER,
+            receiver: RECEIVER,
+            amountOfETH: U256::from(eth),
+        }
+    }
+
+    #[test]
+    fn conversion_splits_transfers_by_emitter() {
+        let info = call_info(USER, WSTETH);
+        // the steth transfer into wsteth, the wsteth mint and an unrelated token
+        let transfers = vec![transfer(100), transfer(87), transfer(5)];
+        let emitters = vec![STETH, WSTETH, RECEIVER];
+
+        let (pooled, shares) = converted(&info, STETH, transfers, emitters);
+        assert_eq!(pooled, U256::from(100));
+        assert_eq!(shares, U256::from(87));
+    }
+
+    #[test]
+    fn requests_of_a_call_are_summed() {
+        let db = TestRegistry::default().with(QUEUE, Protocol::LidoWithdrawalQueue, &[STETH]);
+        let requests = vec![request(100, 87), request(50, 43)];
+
+        let deposit = requested(&call_info(USER, QUEUE), &db, requests).unwrap();
+        assert_eq!(deposit.from, USER);
+        assert_eq!(deposit.recipient, RECEIVER);
+        assert_eq!(deposit.asset, STETH);
+        assert_eq!(deposit.amount, U256::from(150));
+        assert_eq!(deposit.shares, Some(U256::from(130)));
+
+        assert!(requested(&call_info(USER, QUEUE), &db, Vec::new()).is_err());
+    }
+
+    #[test]
+    fn claims_of_a_call_are_summed() {
+        let withdraw = claimed(&call_info(USER, QUEUE), vec![claim(100), claim(51)]).unwrap();
+        assert_eq!(withdraw.owner, USER);
+        assert_eq!(withdraw.recipient, RECEIVER);
+        assert_eq!(withdraw.asset, NATIVE_TOKEN);
+        assert_eq!(withdraw.amount, U256::from(151));
+        assert_eq!(withdraw.shares, None);
+
+        assert!(claimed(&call_info(USER, QUEUE), Vec::new()).is_err());
+    }
+}
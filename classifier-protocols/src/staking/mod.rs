@@ -0,0 +1,12 @@
+mod lido;
+mod rocket_pool;
+
+pub use lido::*;
+pub use rocket_pool::*;
+
+// staking deposits take the native token and mint a liquid receipt token, the
+// receipt token amount is reported as the deposit's shares. wrapping a receipt
+// token into a non rebasing one is a deposit of the receipt token into the
+// wrapper, the same way wrapped native deposits are. contracts wrapping or
+// redeeming a liquid staking token are registered with it as their only token,
+// e.g. steth for wsteth and the lido withdrawal queue.
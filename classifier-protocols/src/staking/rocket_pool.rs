@@ -0,0 +1,69 @@
+use alloy_sol_types::sol;
+use brontes_classifier::{action_impl, types::CallInfo};
+use brontes_classifier_types::{NATIVE_TOKEN, NormalizedDeposit, NormalizedWithdraw};
+
+use crate::{Actions, Protocol, ProtocolMetadata};
+
+sol! {
+    interface IRocketDepositPool {
+        // emitted by reth when the deposit pool mints it
+        event TokensMinted(address indexed to, uint256 amount, uint256 ethAmount, uint256 time);
+
+        function deposit() external payable;
+    }
+
+    interface IRocketTokenRETH {
+        event TokensBurned(address indexed from, uint256 amount, uint256 ethAmount, uint256 time);
+
+        function burn(uint256 _rethAmount) external;
+    }
+}
+
+// the deposit pool keeps a deposit fee out of the eth it mints reth for, the
+// deposit's amount is the eth paid in and its shares the reth minted.
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::RocketPool,
+    IRocketDepositPool::depositCall,
+    Deposit,
+    [..TokensMinted],
+    logs: true,
+    include_child_logs: true,
+    |info: CallInfo, log_data: RocketPoolDepositCallLogs, _db_ctx: &DB| {
+        let minted = log_data.tokens_minted_field?;
+        Ok(NormalizedDeposit {
+            protocol: Protocol::RocketPool,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            from: info.from_address,
+            recipient: minted.to,
+            asset: NATIVE_TOKEN,
+            amount: info.msg_value,
+            shares: Some(minted.amount),
+        })
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::RocketPoolReth,
+    IRocketTokenRETH::burnCall,
+    Withdraw,
+    [..TokensBurned],
+    logs: true,
+    |info: CallInfo, log_data: RocketPoolRethBurnCallLogs, _db_ctx: &DB| {
+        let burned = log_data.tokens_burned_field?;
+        Ok(NormalizedWithdraw {
+            protocol: Protocol::RocketPoolReth,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            from: info.from_address,
+            owner: burned.from,
+            recipient: burned.from,
+            asset: NATIVE_TOKEN,
+            amount: burned.ethAmount,
+            shares: Some(burned.amount),
+        })
+    }
+);
@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+
+use alloy_primitives::{Address, U256};
+use brontes_classifier::{
+    context::{DataContext, NotRegistered},
+    types::CallInfo,
+};
+
+use crate::{Protocol, ProtocolMetadata};
+
+/// an in memory registry, tokens are handed out in the order they were
+/// registered in by `get_protocol_tokens`
+#[derive(Debug, Default)]
+pub struct TestRegistry {
+    entries: HashMap<Address, (Protocol, Vec<Address>, ProtocolMetadata)>,
+}
+
+impl TestRegistry {
+    pub fn with(self, address: Address, protocol: Protocol, tokens: &[Address]) -> Self {
+        self.with_metadata(address, protocol, tokens, ProtocolMetadata::default())
+    }
+
+    pub fn with_metadata(
+        mut self,
+        address: Address,
+        protocol: Protocol,
+        tokens: &[Address],
+        metadata: ProtocolMetadata,
+    ) -> Self {
+        self.entries
+            .insert(address, (protocol, tokens.to_vec(), metadata));
+        self
+    }
+
+    fn entry(&self, address: Address) -> eyre::Result<&(Protocol, Vec<Address>, ProtocolMetadata)> {
+        self.entries
+            .get(&address)
+            .ok_or_else(|| NotRegistered::Address(address).into())
+    }
+}
+
+impl DataContext<Protocol, ProtocolMetadata> for TestRegistry {
+    fn get_protocol(&self, target_address: Address) -> eyre::Result<Protocol> {
+        self.entry(target_address).map(|(protocol, ..)| *protocol)
+    }
+
+    fn get_protocol_tokens_sorted(&self, target_address: Address) -> eyre::Result<Vec<Address>> {
+        let mut tokens = self.get_protocol_tokens(target_address)?;
+        tokens.sort();
+        Ok(tokens)
+    }
+
+    fn get_protocol_tokens(&self, target_address: Address) -> eyre::Result<Vec<Address>> {
+        self.entry(target_address)
+            .map(|(_, tokens, _)| tokens.clone())
+    }
+
+    fn get_metadata(&self, target_address: Address) -> eyre::Result<ProtocolMetadata> {
+        self.entry(target_address)
+            .map(|(.., metadata)| metadata.clone())
+    }
+}
+
+/// a call from `from` to `target` without value at trace index 1
+pub fn call_info(from: Address, target: Address) -> CallInfo {
+    CallInfo {
+        call: brontes_tracer::types::CallInfo {
+            trace_idx: 1,
+            target_address: target,
+            from_address: from,
+            msg_sender: from,
+            msg_value: U256::ZERO,
+        },
+        implementation_address: None,
+        protocol_address: target,
+        child_trace_indices: Vec::new(),
+        static_call_targets: Vec::new(),
+    }
+}
@@ -0,0 +1,663 @@
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::sol;
+use brontes_classifier::types::CallInfo;
+use brontes_classifier_types::{
+    NATIVE_TOKEN, NormalizedAggregatorSwap, NormalizedBurn, NormalizedCollect, NormalizedMint,
+    NormalizedSwap, NormalizedSync,
+};
+
+use crate::Protocol;
+
+sol! {
+    interface IUniswapV2Pair {
+        event Transfer(address indexed from, address indexed to, uint256 value);
+        event Mint(address indexed sender, uint256 amount0, uint256 amount1);
+        event Burn(address indexed sender, uint256 amount0, uint256 amount1, address indexed to);
+        event Swap(
+            address indexed sender,
+            uint256 amount0In,
+            uint256 amount1In,
+            uint256 amount0Out,
+            uint256 amount1Out,
+            address indexed to
+        );
+        event Sync(uint112 reserve0, uint112 reserve1);
+
+        function mint(address to) external returns (uint256 liquidity);
+        function burn(address to) external returns (uint256 amount0, uint256 amount1);
+        function swap(uint256 amount0Out, uint256 amount1Out, address to, bytes data) external;
+        function skim(address to) external;
+        function sync() external;
+    }
+
+    interface IUniswapV2Router02 {
+        // emitted by the tokens and the wrapped native token the router calls
+        // into, used to recover the output of the fee on transfer variants
+        event Transfer(address indexed from, address indexed to, uint256 value);
+        event Withdrawal(address indexed src, uint256 wad);
+
+        function swapExactTokensForTokens(
+            uint256 amountIn,
+            uint256 amountOutMin,
+            address[] path,
+            address to,
+            uint256 deadline
+        ) external returns (uint256[] amounts);
+        function swapTokensForExactTokens(
+            uint256 amountOut,
+            uint256 amountInMax,
+            address[] path,
+            address to,
+            uint256 deadline
+        ) external returns (uint256[] amounts);
+        function swapExactETHForTokens(
+            uint256 amountOutMin,
+            address[] path,
+            address to,
+            uint256 deadline
+        ) external payable returns (uint256[] amounts);
+        function swapTokensForExactETH(
+            uint256 amountOut,
+            uint256 amountInMax,
+            address[] path,
+            address to,
+            uint256 deadline
+        ) external returns (uint256[] amounts);
+        function swapExactTokensForETH(
+            uint256 amountIn,
+            uint256 amountOutMin,
+            address[] path,
+            address to,
+            uint256 deadline
+        ) external returns (uint256[] amounts);
+        function swapETHForExactTokens(
+            uint256 amountOut,
+            address[] path,
+            address to,
+            uint256 deadline
+        ) external payable returns (uint256[] amounts);
+
+        function swapExactTokensForTokensSupportingFeeOnTransferTokens(
+            uint256 amountIn,
+            uint256 amountOutMin,
+            address[] path,
+            address to,
+            uint256 deadline
+        ) external;
+        function swapExactETHForTokensSupportingFeeOnTransferTokens(
+            uint256 amountOutMin,
+            address[] path,
+            address to,
+            uint256 deadline
+        ) external payable;
+        function swapExactTokensForETHSupportingFeeOnTransferTokens(
+            uint256 amountIn,
+            uint256 amountOutMin,
+            address[] path,
+            address to,
+            uint256 deadline
+        ) external;
+    }
+}
+
+/// Generates the pair and router classifiers for a uniswap v2 fork. each fork
+/// gets its own module so the generated imports don't collide, pools and
+/// routers are picked up through the registry under the fork's protocol name.
+macro_rules! uniswap_v2_pack {
+    ($module:ident, $protocol:ident) => {
+        pub mod $module {
+            use brontes_classifier::{action_impl, types::CallInfo};
+
+            use super::{
+                IUniswapV2Pair, IUniswapV2Router02, pair_burn, pair_mint, pair_skim, pair_swap,
+                pair_sync, router_swap, sum_received, sum_unwrapped,
+            };
+            use crate::{Actions, Protocol, ProtocolMetadata};
+
+            ::paste::paste! {
+                action_impl!(
+                    (Protocol, Actions, ProtocolMetadata),
+                    Protocol::$protocol,
+                    IUniswapV2Pair::swapCall,
+                    Swap,
+                    [..Swap],
+                    call_data: true,
+                    logs: true,
+                    |info: CallInfo,
+                    call_data: swapCall,
+                    log_data: [<$protocol SwapCallLogs>],
+                    db_ctx: &DB| {
+                        let tokens = db_ctx.get_protocol_tokens_sorted(info.protocol_address)?;
+                        pair_swap(Protocol::$protocol, &info, call_data.to, log_data.swap_field?, &tokens)
+                    }
+                );
+
+                action_impl!(
+                    (Protocol, Actions, ProtocolMetadata),
+                    Protocol::$protocol,
+                    IUniswapV2Pair::mintCall,
+                    Mint,
+                    [..Mint],
+                    call_data: true,
+                    logs: true,
+                    |info: CallInfo,
+                    call_data: mintCall,
+                    log_data: [<$protocol MintCallLogs>],
+                    db_ctx: &DB| {
+                        let tokens = db_ctx.get_protocol_tokens_sorted(info.protocol_address)?;
+                        pair_mint(Protocol::$protocol, &info, call_data.to, log_data.mint_field?, tokens)
+                    }
+                );
+
+                action_impl!(
+                    (Protocol, Actions, ProtocolMetadata),
+                    Protocol::$protocol,
+                    IUniswapV2Pair::burnCall,
+                    Burn,
+                    [..Burn],
+                    logs: true,
+                    |info: CallInfo,
+                    log_data: [<$protocol BurnCallLogs>],
+                    db_ctx: &DB| {
+                        let tokens = db_ctx.get_protocol_tokens_sorted(info.protocol_address)?;
+                        pair_burn(Protocol::$protocol, &info, log_data.burn_field?, tokens)
+                    }
+                );
+
+                action_impl!(
+                    (Protocol, Actions, ProtocolMetadata),
+                    Protocol::$protocol,
+                    IUniswapV2Pair::skimCall,
+                    Collect,
+                    [..Transfer*],
+                    call_data: true,
+                    logs: true,
+                    include_child_logs: true,
+                    |info: CallInfo,
+                    call_data: skimCall,
+                    log_data: [<$protocol SkimCallLogs>],
+                    db_ctx: &DB| {
+                        let tokens = db_ctx.get_protocol_tokens_sorted(info.protocol_address)?;
+                        pair_skim(
+                            Protocol::$protocol,
+                            &info,
+                            call_data.to,
+                            &log_data.transfer_field?,
+                            &log_data.transfer_emitter?,
+                            tokens,
+                        )
+                    }
+                );
+
+                action_impl!(
+                    (Protocol, Actions, ProtocolMetadata),
+                    Protocol::$protocol,
+                    IUniswapV2Pair::syncCall,
+                    Sync,
+                    [..Sync],
+                    logs: true,
+                    |info: CallInfo,
+                    log_data: [<$protocol SyncCallLogs>],
+                    db_ctx: &DB| {
+                        let tokens = db_ctx.get_protocol_tokens_sorted(info.protocol_address)?;
+                        pair_sync(Protocol::$protocol, &info, log_data.sync_field?, tokens)
+                    }
+                );
+
+                action_impl!(
+                    (Protocol, Actions, ProtocolMetadata),
+                    Protocol::$protocol,
+                    IUniswapV2Router02::swapExactTokensForTokensCall,
+                    AggregatorSwap,
+                    [],
+                    call_data: true,
+                    return_data: true,
+                    |info: CallInfo,
+                    call_data: swapExactTokensForTokensCall,
+                    return_data: swapExactTokensForTokensReturn,
+                    _db_ctx: &DB| {
+                        router_swap(
+                            Protocol::$protocol,
+                            &info,
+                            &call_data.path,
+                            (false, false),
+                            call_data.to,
+                            &return_data.amounts,
+                        )
+                    }
+                );
+
+                action_impl!(
+                    (Protocol, Actions, ProtocolMetadata),
+                    Protocol::$protocol,
+                    IUniswapV2Router02::swapTokensForExactTokensCall,
+                    AggregatorSwap,
+                    [],
+                    call_data: true,
+                    return_data: true,
+                    |info: CallInfo,
+                    call_data: swapTokensForExactTokensCall,
+                    return_data: swapTokensForExactTokensReturn,
+                    _db_ctx: &DB| {
+                        router_swap(
+                            Protocol::$protocol,
+                            &info,
+                            &call_data.path,
+                            (false, false),
+                            call_data.to,
+                            &return_data.amounts,
+                        )
+                    }
+                );
+
+                action_impl!(
+                    (Protocol, Actions, ProtocolMetadata),
+                    Protocol::$protocol,
+                    IUniswapV2Router02::swapExactETHForTokensCall,
+                    AggregatorSwap,
+                    [],
+                    call_data: true,
+                    return_data: true,
+                    |info: CallInfo,
+                    call_data: swapExactETHForTokensCall,
+                    return_data: swapExactETHForTokensReturn,
+                    _db_ctx: &DB| {
+                        router_swap(
+                            Protocol::$protocol,
+                            &info,
+                            &call_data.path,
+                            (true, false),
+                            call_data.to,
+                            &return_data.amounts,
+                        )
+                    }
+                );
+
+                action_impl!(
+                    (Protocol, Actions, ProtocolMetadata),
+                    Protocol::$protocol,
+                    IUniswapV2Router02::swapTokensForExactETHCall,
+                    AggregatorSwap,
+                    [],
+                    call_data: true,
+                    return_data: true,
+                    |info: CallInfo,
+                    call_data: swapTokensForExactETHCall,
+                    return_data: swapTokensForExactETHReturn,
+                    _db_ctx: &DB| {
+                        router_swap(
+                            Protocol::$protocol,
+                            &info,
+                            &call_data.path,
+                            (false, true),
+                            call_data.to,
+                            &return_data.amounts,
+                        )
+                    }
+                );
+
+                action_impl!(
+                    (Protocol, Actions, ProtocolMetadata),
+                    Protocol::$protocol,
+                    IUniswapV2Router02::swapExactTokensForETHCall,
+                    AggregatorSwap,
+                    [],
+                    call_data: true,
+                    return_data: true,
+                    |info: CallInfo,
+                    call_data: swapExactTokensForETHCall,
+                    return_data: swapExactTokensForETHReturn,
+                    _db_ctx: &DB| {
+                        router_swap(
+                            Protocol::$protocol,
+                            &info,
+                            &call_data.path,
+                            (false, true),
+                            call_data.to,
+                            &return_data.amounts,
+                        )
+                    }
+                );
+
+                action_impl!(
+                    (Protocol, Actions, ProtocolMetadata),
+                    Protocol::$protocol,
+                    IUniswapV2Router02::swapETHForExactTokensCall,
+                    AggregatorSwap,
+                    [],
+                    call_data: true,
+                    return_data: true,
+                    |info: CallInfo,
+                    call_data: swapETHForExactTokensCall,
+                    return_data: swapETHForExactTokensReturn,
+                    _db_ctx: &DB| {
+                        router_swap(
+                            Protocol::$protocol,
+                            &info,
+                            &call_data.path,
+                            (true, false),
+                            call_data.to,
+                            &return_data.amounts,
+                        )
+                    }
+                );
+
+                action_impl!(
+                    (Protocol, Actions, ProtocolMetadata),
+                    Protocol::$protocol,
+                    IUniswapV2Router02::swapExactTokensForTokensSupportingFeeOnTransferTokensCall,
+                    AggregatorSwap,
+                    [..Transfer*],
+                    call_data: true,
+                    logs: true,
+                    include_child_logs: true,
+                    |info: CallInfo,
+                    call_data: swapExactTokensForTokensSupportingFeeOnTransferTokensCall,
+                    log_data: [<$protocol SwapExactTokensForTokensSupportingFeeOnTransferTokensCallLogs>],
+                    _db_ctx: &DB| {
+                        let amount_out = sum_received(
+                            &log_data.transfer_field?,
+                            &log_data.transfer_emitter?,
+                            &call_data.path,
+                            call_data.to,
+                        )?;
+                        router_swap(
+                            Protocol::$protocol,
+                            &info,
+                            &call_data.path,
+                            (false, false),
+                            call_data.to,
+                            &[call_data.amountIn, amount_out],
+                        )
+                    }
+                );
+
+                action_impl!(
+                    (Protocol, Actions, ProtocolMetadata),
+                    Protocol::$protocol,
+                    IUniswapV2Router02::swapExactETHForTokensSupportingFeeOnTransferTokensCall,
+                    AggregatorSwap,
+                    [..Transfer*],
+                    call_data: true,
+                    logs: true,
+                    include_child_logs: true,
+                    |info: CallInfo,
+                    call_data: swapExactETHForTokensSupportingFeeOnTransferTokensCall,
+                    log_data: [<$protocol SwapExactETHForTokensSupportingFeeOnTransferTokensCallLogs>],
+                    _db_ctx: &DB| {
+                        let amount_out = sum_received(
+                            &log_data.transfer_field?,
+                            &log_data.transfer_emitter?,
+                            &call_data.path,
+                            call_data.to,
+                        )?;
+                        router_swap(
+                            Protocol::$protocol,
+                            &info,
+                            &call_data.path,
+                            (true, false),
+                            call_data.to,
+                            &[info.msg_value, amount_out],
+                        )
+                    }
+                );
+
+                action_impl!(
+                    (Protocol, Actions, ProtocolMetadata),
+                    Protocol::$protocol,
+                    IUniswapV2Router02::swapExactTokensForETHSupportingFeeOnTransferTokensCall,
+                    AggregatorSwap,
+                    [..Withdrawal*],
+                    call_data: true,
+                    logs: true,
+                    include_child_logs: true,
+                    |info: CallInfo,
+                    call_data: swapExactTokensForETHSupportingFeeOnTransferTokensCall,
+                    log_data: [<$protocol SwapExactTokensForETHSupportingFeeOnTransferTokensCallLogs>],
+                    _db_ctx: &DB| {
+                        let amount_out = sum_unwrapped(
+                            &log_data.withdrawal_field?,
+                            &log_data.withdrawal_emitter?,
+                            &call_data.path,
+                        )?;
+                        router_swap(
+                            Protocol::$protocol,
+                            &info,
+                            &call_data.path,
+                            (false, true),
+                            call_data.to,
+                            &[call_data.amountIn, amount_out],
+                        )
+                    }
+                );
+            }
+        }
+
+        pub use $module::*;
+    };
+}
+
+uniswap_v2_pack!(uniswap, UniswapV2);
+uniswap_v2_pack!(sushiswap, SushiSwapV2);
+uniswap_v2_pack!(pancakeswap, PancakeSwapV2);
+
+fn pair_tokens(info: &CallInfo, tokens: &[Address]) -> eyre::Result<(Address, Address)> {
+    match tokens {
+        [token0, token1] => Ok((*token0, *token1)),
+        _ => Err(eyre::eyre!(
+            "pair {:?} needs exactly 2 registered tokens, found {}",
+            info.protocol_address,
+            tokens.len()
+        )),
+    }
+}
+
+fn pair_swap(
+    protocol: Protocol,
+    info: &CallInfo,
+    recipient: Address,
+    swap: IUniswapV2Pair::Swap,
+    tokens: &[Address],
+) -> eyre::Result<NormalizedSwap<Protocol>> {
+    let (token0, token1) = pair_tokens(info, tokens)?;
+
+    let (token_in, amount_in, token_out, amount_out) = if swap.amount0In.is_zero() {
+        (token1, swap.amount1In, token0, swap.amount0Out)
+    } else {
+        (token0, swap.amount0In, token1, swap.amount1Out)
+    };
+
+    Ok(NormalizedSwap {
+        protocol,
+        trace_index: info.trace_idx,
+        from: info.from_address,
+        recipient,
+        pool: info.target_address,
+        pool_id: None,
+        token_in,
+        token_out,
+        amount_in,
+        amount_out,
+        msg_value: info.msg_value,
+    })
+}
+
+/// the deposited amounts come from the `Mint` event, which the pair derives
+/// from the transfers into it that preceded the call.
+fn pair_mint(
+    protocol: Protocol,
+    info: &CallInfo,
+    recipient: Address,
+    mint: IUniswapV2Pair::Mint,
+    tokens: Vec<Address>,
+) -> eyre::Result<NormalizedMint<Protocol>> {
+    pair_tokens(info, &tokens)?;
+
+    Ok(NormalizedMint {
+        protocol,
+        trace_index: info.trace_idx,
+        from: info.from_address,
+        recipient,
+        pool: info.target_address,
+        pool_id: None,
+        tokens,
+        amounts: vec![mint.amount0, mint.amount1],
+        position: None,
+    })
+}
+
+fn pair_burn(
+    protocol: Protocol,
+    info: &CallInfo,
+    burn: IUniswapV2Pair::Burn,
+    tokens: Vec<Address>,
+) -> eyre::Result<NormalizedBurn<Protocol>> {
+    pair_tokens(info, &tokens)?;
+
+    Ok(NormalizedBurn {
+        protocol,
+        trace_index: info.trace_idx,
+        from: info.from_address,
+        recipient: burn.to,
+        pool: info.target_address,
+        pool_id: None,
+        tokens,
+        amounts: vec![burn.amount0, burn.amount1],
+        position: None,
+    })
+}
+
+/// the excess is attributed to each token by the emitter of the transfers out
+/// of the pair, tokens without a transfer skimmed nothing.
+fn pair_skim(
+    protocol: Protocol,
+    info: &CallInfo,
+    recipient: Address,
+    transfers: &[IUniswapV2Pair::Transfer],
+    emitters: &[Address],
+    tokens: Vec<Address>,
+) -> eyre::Result<NormalizedCollect<Protocol>> {
+    pair_tokens(info, &tokens)?;
+
+    let amounts = tokens
+        .iter()
+        .map(|token| {
+            transfers
+                .iter()
+                .zip(emitters)
+                .filter(|(transfer, emitter)| {
+                    *emitter == token
+                        && transfer.from == info.target_address
+                        && transfer.to == recipient
+                })
+                .fold(U256::ZERO, |acc, (transfer, _)| {
+                    acc.saturating_add(transfer.value)
+                })
+        })
+        .collect();
+
+    Ok(NormalizedCollect {
+        protocol,
+        trace_index: info.trace_idx,
+        from: info.from_address,
+        recipient,
+        pool: info.target_address,
+        pool_id: None,
+        tokens,
+        amounts,
+        position: None,
+    })
+}
+
+fn pair_sync(
+    protocol: Protocol,
+    info: &CallInfo,
+    sync: IUniswapV2Pair::Sync,
+    tokens: Vec<Address>,
+) -> eyre::Result<NormalizedSync<Protocol>> {
+    pair_tokens(info, &tokens)?;
+
+    Ok(NormalizedSync {
+        protocol,
+        trace_index: info.trace_idx,
+        pool: info.target_address,
+        tokens,
+        reserves: vec![U256::from(sync.reserve0), U256::from(sync.reserve1)],
+    })
+}
+
+/// the whole route as a single swap from the first to the last token of the
+/// path, the hops themselves are classified on the pairs the router called.
+/// `native` marks the (input, output) sides paid or received in the native
+/// currency.
+fn router_swap(
+    protocol: Protocol,
+    info: &CallInfo,
+    path: &[Address],
+    native: (bool, bool),
+    recipient: Address,
+    amounts: &[U256],
+) -> eyre::Result<NormalizedAggregatorSwap<Protocol>> {
+    let (Some(first), Some(last)) = (path.first(), path.last()) else {
+        return Err(eyre::eyre!("empty swap path"));
+    };
+    let (Some(amount_in), Some(amount_out)) = (amounts.first(), amounts.last()) else {
+        return Err(eyre::eyre!("router returned no amounts"));
+    };
+
+    Ok(NormalizedAggregatorSwap {
+        swap: NormalizedSwap {
+            protocol,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            recipient,
+            pool: info.target_address,
+            pool_id: None,
+            token_in: if native.0 { NATIVE_TOKEN } else { *first },
+            token_out: if native.1 { NATIVE_TOKEN } else { *last },
+            amount_in: *amount_in,
+            amount_out: *amount_out,
+            msg_value: info.msg_value,
+        },
+        child_trace_indices: info.child_trace_indices.clone(),
+    })
+}
+
+/// what `recipient` received of the path's output token over all the transfers
+/// made below the router, for fee on transfer output tokens this is the amount
+/// after the fee. transfers of any other token, e.g. the hops into a recipient
+/// that is itself a pair, aren't counted.
+fn sum_received(
+    transfers: &[IUniswapV2Router02::Transfer],
+    emitters: &[Address],
+    path: &[Address],
+    recipient: Address,
+) -> eyre::Result<U256> {
+    let token_out = path.last().ok_or(eyre::eyre!("empty swap path"))?;
+
+    Ok(transfers
+        .iter()
+        .zip(emitters)
+        .filter(|(transfer, emitter)| *emitter == token_out && transfer.to == recipient)
+        .fold(U256::ZERO, |acc, (transfer, _)| {
+            acc.saturating_add(transfer.value)
+        }))
+}
+
+/// what the router unwrapped of the wrapped native token the path ends in, the
+/// eth it pays out
+fn sum_unwrapped(
+    withdrawals: &[IUniswapV2Router02::Withdrawal],
+    emitters: &[Address],
+    path: &[Address],
+) -> eyre::Result<U256> {
+    let wrapped_native = path.last().ok_or(eyre::eyre!("empty swap path"))?;
+
+    Ok(withdrawals
+        .iter()
+        .zip(emitters)
+        .filter(|(_, emitter)| *emitter == wrapped_native)
+        .fold(U256::ZERO, |acc, (withdrawal, _)| {
+            acc.saturating_add(withdrawal.wad)
+        }))
+}
@@ -0,0 +1,498 @@
+use alloy_primitives::{Address, I256, U256};
+use alloy_sol_types::sol;
+use brontes_classifier::{action_impl, types::CallInfo};
+use brontes_classifier_types::{
+    NormalizedBurn, NormalizedCollect, NormalizedFlashLoan, NormalizedMint, NormalizedPosition,
+    NormalizedSwap,
+};
+
+use crate::{Actions, Protocol, ProtocolMetadata};
+
+sol! {
+    interface IUniswapV3Pool {
+        event Flash(
+            address indexed sender,
+            address indexed recipient,
+            uint256 amount0,
+            uint256 amount1,
+            uint256 paid0,
+            uint256 paid1
+        );
+
+        function swap(
+            address recipient,
+            bool zeroForOne,
+            int256 amountSpecified,
+            uint160 sqrtPriceLimitX96,
+            bytes data
+        ) external returns (int256 amount0, int256 amount1);
+        function mint(
+            address recipient,
+            int24 tickLower,
+            int24 tickUpper,
+            uint128 amount,
+            bytes data
+        ) external returns (uint256 amount0, uint256 amount1);
+        function burn(
+            int24 tickLower,
+            int24 tickUpper,
+            uint128 amount
+        ) external returns (uint256 amount0, uint256 amount1);
+        function collect(
+            address recipient,
+            int24 tickLower,
+            int24 tickUpper,
+            uint128 amount0Requested,
+            uint128 amount1Requested
+        ) external returns (uint128 amount0, uint128 amount1);
+        function flash(address recipient, uint256 amount0, uint256 amount1, bytes data) external;
+    }
+
+    interface INonfungiblePositionManager {
+        // emitted by the pool the manager calls into, the emitter identifies
+        // the pool of the position
+        event Mint(
+            address sender,
+            address indexed owner,
+            int24 indexed tickLower,
+            int24 indexed tickUpper,
+            uint128 amount,
+            uint256 amount0,
+            uint256 amount1
+        );
+        event Burn(
+            address indexed owner,
+            int24 indexed tickLower,
+            int24 indexed tickUpper,
+            uint128 amount,
+            uint256 amount0,
+            uint256 amount1
+        );
+        event Collect(
+            address indexed owner,
+            address recipient,
+            int24 indexed tickLower,
+            int24 indexed tickUpper,
+            uint128 amount0,
+            uint128 amount1
+        );
+
+        struct MintParams {
+            address token0;
+            address token1;
+            uint24 fee;
+            int24 tickLower;
+            int24 tickUpper;
+            uint256 amount0Desired;
+            uint256 amount1Desired;
+            uint256 amount0Min;
+            uint256 amount1Min;
+            address recipient;
+            uint256 deadline;
+        }
+
+        struct IncreaseLiquidityParams {
+            uint256 tokenId;
+            uint256 amount0Desired;
+            uint256 amount1Desired;
+            uint256 amount0Min;
+            uint256 amount1Min;
+            uint256 deadline;
+        }
+
+        struct DecreaseLiquidityParams {
+            uint256 tokenId;
+            uint128 liquidity;
+            uint256 amount0Min;
+            uint256 amount1Min;
+            uint256 deadline;
+        }
+
+        struct CollectParams {
+            uint256 tokenId;
+            address recipient;
+            uint128 amount0Max;
+            uint128 amount1Max;
+        }
+
+        function mint(MintParams params) external payable returns (
+            uint256 tokenId,
+            uint128 liquidity,
+            uint256 amount0,
+            uint256 amount1
+        );
+        function increaseLiquidity(IncreaseLiquidityParams params) external payable returns (
+            uint128 liquidity,
+            uint256 amount0,
+            uint256 amount1
+        );
+        function decreaseLiquidity(DecreaseLiquidityParams params) external payable returns (
+            uint256 amount0,
+            uint256 amount1
+        );
+        function collect(CollectParams params) external payable returns (
+            uint256 amount0,
+            uint256 amount1
+        );
+    }
+}
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::UniswapV3,
+    IUniswapV3Pool::swapCall,
+    Swap,
+    [],
+    call_data: true,
+    return_data: true,
+    |info: CallInfo,
+    call_data: swapCall,
+    return_data: swapReturn,
+    db_ctx: &DB| {
+        let (token0, token1) = pool_tokens(
+            info.protocol_address,
+            &db_ctx.get_protocol_tokens_sorted(info.protocol_address)?,
+        )?;
+
+        // positive deltas are paid into the pool
+        let (token_in, amount_in, token_out, amount_out) = if return_data.amount0.is_positive() {
+            (token0, return_data.amount0, token1, return_data.amount1)
+        } else {
+            (token1, return_data.amount1, token0, return_data.amount0)
+        };
+
+        Ok(NormalizedSwap {
+            protocol: Protocol::UniswapV3,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            recipient: call_data.recipient,
+            pool: info.target_address,
+            pool_id: None,
+            token_in,
+            token_out,
+            amount_in: abs(amount_in),
+            amount_out: abs(amount_out),
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::UniswapV3,
+    IUniswapV3Pool::mintCall,
+    Mint,
+    [],
+    call_data: true,
+    return_data: true,
+    |info: CallInfo,
+    call_data: mintCall,
+    return_data: mintReturn,
+    db_ctx: &DB| {
+        Ok(NormalizedMint {
+            protocol: Protocol::UniswapV3,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            recipient: call_data.recipient,
+            pool: info.target_address,
+            pool_id: None,
+            tokens: db_ctx.get_protocol_tokens_sorted(info.protocol_address)?,
+            amounts: vec![return_data.amount0, return_data.amount1],
+            position: Some(NormalizedPosition {
+                token_id: None,
+                tick_lower: call_data.tickLower.as_i32(),
+                tick_upper: call_data.tickUpper.as_i32(),
+                liquidity: call_data.amount,
+            }),
+        })
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::UniswapV3,
+    IUniswapV3Pool::burnCall,
+    Burn,
+    [],
+    call_data: true,
+    return_data: true,
+    |info: CallInfo,
+    call_data: burnCall,
+    return_data: burnReturn,
+    db_ctx: &DB| {
+        // burned amounts are owed to the position until collected
+        Ok(NormalizedBurn {
+            protocol: Protocol::UniswapV3,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            recipient: info.from_address,
+            pool: info.target_address,
+            pool_id: None,
+            tokens: db_ctx.get_protocol_tokens_sorted(info.protocol_address)?,
+            amounts: vec![return_data.amount0, return_data.amount1],
+            position: Some(NormalizedPosition {
+                token_id: None,
+                tick_lower: call_data.tickLower.as_i32(),
+                tick_upper: call_data.tickUpper.as_i32(),
+                liquidity: call_data.amount,
+            }),
+        })
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::UniswapV3,
+    IUniswapV3Pool::collectCall,
+    Collect,
+    [],
+    call_data: true,
+    return_data: true,
+    |info: CallInfo,
+    call_data: collectCall,
+    return_data: collectReturn,
+    db_ctx: &DB| {
+        Ok(NormalizedCollect {
+            protocol: Protocol::UniswapV3,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            recipient: call_data.recipient,
+            pool: info.target_address,
+            pool_id: None,
+            tokens: db_ctx.get_protocol_tokens_sorted(info.protocol_address)?,
+            amounts: vec![
+                U256::from(return_data.amount0),
+                U256::from(return_data.amount1),
+            ],
+            position: Some(NormalizedPosition {
+                token_id: None,
+                tick_lower: call_data.tickLower.as_i32(),
+                tick_upper: call_data.tickUpper.as_i32(),
+                liquidity: 0,
+            }),
+        })
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::UniswapV3,
+    IUniswapV3Pool::flashCall,
+    FlashLoan,
+    [..Flash],
+    call_data: true,
+    logs: true,
+    |info: CallInfo,
+    call_data: flashCall,
+    log_data: UniswapV3FlashCallLogs,
+    db_ctx: &DB| {
+        let flash = log_data.flash_field?;
+        let (token0, token1) = pool_tokens(
+            info.protocol_address,
+            &db_ctx.get_protocol_tokens_sorted(info.protocol_address)?,
+        )?;
+
+        // only the tokens that were actually borrowed or paid for
+        let (assets, (amounts, fees)): (Vec<_>, (Vec<_>, Vec<_>)) = [
+            (token0, (flash.amount0, flash.paid0)),
+            (token1, (flash.amount1, flash.paid1)),
+        ]
+        .into_iter()
+        .filter(|(_, (amount, paid))| !amount.is_zero() || !paid.is_zero())
+        .unzip();
+
+        Ok(NormalizedFlashLoan {
+            protocol: Protocol::UniswapV3,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            pool: info.target_address,
+            receiver: call_data.recipient,
+            assets,
+            amounts,
+            fees,
+        })
+    }
+);
+
+/// the position manager is registered under its own protocol name as its calls
+/// share their names with the pool's, the actions are tagged as uniswap v3.
+/// the pool's own `mint` / `burn` / `collect` below the manager are classified
+/// as the liquidity actions, the manager's calls only as position changes
+/// pointing at them.
+pub mod position_manager {
+    use alloy_primitives::Address;
+    use brontes_classifier::{action_impl, types::CallInfo};
+    use brontes_classifier_types::{
+        NormalizedPosition, NormalizedPositionChange, PositionChangeKind,
+    };
+
+    use super::INonfungiblePositionManager;
+    use crate::{Actions, Protocol, ProtocolMetadata};
+
+    action_impl!(
+        (Protocol, Actions, ProtocolMetadata),
+        Protocol::UniswapV3PositionManager,
+        INonfungiblePositionManager::mintCall,
+        PositionChange,
+        [..Mint],
+        call_data: true,
+        return_data: true,
+        logs: true,
+        include_child_logs: true,
+        |info: CallInfo,
+        call_data: mintCall,
+        return_data: mintReturn,
+        log_data: UniswapV3PositionManagerMintCallLogs,
+        _db_ctx: &DB| {
+            Ok(position_change(
+                &info,
+                PositionChangeKind::Open,
+                call_data.params.recipient,
+                log_data.mint_emitter?,
+                NormalizedPosition {
+                    token_id: Some(return_data.tokenId),
+                    tick_lower: call_data.params.tickLower.as_i32(),
+                    tick_upper: call_data.params.tickUpper.as_i32(),
+                    liquidity: return_data.liquidity,
+                },
+            ))
+        }
+    );
+
+    action_impl!(
+        (Protocol, Actions, ProtocolMetadata),
+        Protocol::UniswapV3PositionManager,
+        INonfungiblePositionManager::increaseLiquidityCall,
+        PositionChange,
+        [..Mint],
+        call_data: true,
+        return_data: true,
+        logs: true,
+        include_child_logs: true,
+        |info: CallInfo,
+        call_data: increaseLiquidityCall,
+        return_data: increaseLiquidityReturn,
+        log_data: UniswapV3PositionManagerIncreaseLiquidityCallLogs,
+        _db_ctx: &DB| {
+            let mint = log_data.mint_field?;
+
+            Ok(position_change(
+                &info,
+                PositionChangeKind::Increase,
+                info.target_address,
+                log_data.mint_emitter?,
+                NormalizedPosition {
+                    token_id: Some(call_data.params.tokenId),
+                    tick_lower: mint.tickLower.as_i32(),
+                    tick_upper: mint.tickUpper.as_i32(),
+                    liquidity: return_data.liquidity,
+                },
+            ))
+        }
+    );
+
+    action_impl!(
+        (Protocol, Actions, ProtocolMetadata),
+        Protocol::UniswapV3PositionManager,
+        INonfungiblePositionManager::decreaseLiquidityCall,
+        PositionChange,
+        [..Burn],
+        call_data: true,
+        logs: true,
+        include_child_logs: true,
+        |info: CallInfo,
+        call_data: decreaseLiquidityCall,
+        log_data: UniswapV3PositionManagerDecreaseLiquidityCallLogs,
+        _db_ctx: &DB| {
+            let burn = log_data.burn_field?;
+
+            // the amounts are owed to the position until collected
+            Ok(position_change(
+                &info,
+                PositionChangeKind::Decrease,
+                info.target_address,
+                log_data.burn_emitter?,
+                NormalizedPosition {
+                    token_id: Some(call_data.params.tokenId),
+                    tick_lower: burn.tickLower.as_i32(),
+                    tick_upper: burn.tickUpper.as_i32(),
+                    liquidity: call_data.params.liquidity,
+                },
+            ))
+        }
+    );
+
+    action_impl!(
+        (Protocol, Actions, ProtocolMetadata),
+        Protocol::UniswapV3PositionManager,
+        INonfungiblePositionManager::collectCall,
+        PositionChange,
+        [..Collect],
+        call_data: true,
+        logs: true,
+        include_child_logs: true,
+        |info: CallInfo,
+        call_data: collectCall,
+        log_data: UniswapV3PositionManagerCollectCallLogs,
+        _db_ctx: &DB| {
+            let collect = log_data.collect_field?;
+
+            // a zero recipient leaves the tokens on the manager to be unwrapped /
+            // swept by a later call of the same multicall
+            let recipient = if call_data.params.recipient == Address::ZERO {
+                info.target_address
+            } else {
+                call_data.params.recipient
+            };
+
+            Ok(position_change(
+                &info,
+                PositionChangeKind::Collect,
+                recipient,
+                log_data.collect_emitter?,
+                NormalizedPosition {
+                    token_id: Some(call_data.params.tokenId),
+                    tick_lower: collect.tickLower.as_i32(),
+                    tick_upper: collect.tickUpper.as_i32(),
+                    liquidity: 0,
+                },
+            ))
+        }
+    );
+
+    fn position_change(
+        info: &CallInfo,
+        kind: PositionChangeKind,
+        recipient: Address,
+        pool: Address,
+        position: NormalizedPosition,
+    ) -> NormalizedPositionChange<Protocol> {
+        NormalizedPositionChange {
+            protocol: Protocol::UniswapV3,
+            trace_index: info.trace_idx,
+            kind,
+            from: info.from_address,
+            recipient,
+            manager: info.target_address,
+            pool,
+            position,
+            child_trace_indices: info.child_trace_indices.clone(),
+        }
+    }
+}
+
+pub use position_manager::*;
+
+fn pool_tokens(pool: Address, tokens: &[Address]) -> eyre::Result<(Address, Address)> {
+    match tokens {
+        [token0, token1] => Ok((*token0, *token1)),
+        _ => Err(eyre::eyre!(
+            "pool {pool:?} needs exactly 2 registered tokens, found {}",
+            tokens.len()
+        )),
+    }
+}
+
+fn abs(delta: I256) -> U256 {
+    delta.unsigned_abs()
+}
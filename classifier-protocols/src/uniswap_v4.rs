@@ -0,0 +1,418 @@
+use alloy_primitives::{Address, B256, I256, U256};
+use alloy_sol_types::sol;
+use brontes_classifier::{
+    action_impl,
+    context::{DataContext, is_not_registered},
+    types::CallInfo,
+};
+use brontes_classifier_types::{
+    NATIVE_TOKEN, NormalizedBurn, NormalizedCollect, NormalizedMint, NormalizedPosition,
+    NormalizedSettlement, NormalizedSwap, SettlementKind,
+};
+
+use crate::{Actions, Protocol, ProtocolMetadata};
+
+sol! {
+    interface IPoolManager {
+        struct PoolKey {
+            address currency0;
+            address currency1;
+            uint24 fee;
+            int24 tickSpacing;
+            address hooks;
+        }
+
+        struct SwapParams {
+            bool zeroForOne;
+            int256 amountSpecified;
+            uint160 sqrtPriceLimitX96;
+        }
+
+        struct ModifyLiquidityParams {
+            int24 tickLower;
+            int24 tickUpper;
+            int256 liquidityDelta;
+            bytes32 salt;
+        }
+
+        event Swap(
+            bytes32 indexed id,
+            address indexed sender,
+            int128 amount0,
+            int128 amount1,
+            uint160 sqrtPriceX96,
+            uint128 liquidity,
+            int24 tick,
+            uint24 fee
+        );
+        event ModifyLiquidity(
+            bytes32 indexed id,
+            address indexed sender,
+            int24 tickLower,
+            int24 tickUpper,
+            int256 liquidityDelta,
+            bytes32 salt
+        );
+
+        // balance deltas are packed as `int128 amount0 | int128 amount1`
+        function swap(PoolKey key, SwapParams params, bytes hookData) external returns (
+            int256 swapDelta
+        );
+        function modifyLiquidity(
+            PoolKey key,
+            ModifyLiquidityParams params,
+            bytes hookData
+        ) external returns (int256 callerDelta, int256 feesAccrued);
+        function sync(address currency) external;
+        function settle() external payable returns (uint256 paid);
+        function settleFor(address recipient) external payable returns (uint256 paid);
+        function take(address currency, address to, uint256 amount) external;
+    }
+}
+
+// every pool lives inside the pool manager, the calls below are made by the
+// locker from within its unlock callback. the actions point at the pool
+// manager with the pool id the events carry, pools are registered under their
+// id with their currencies.
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::UniswapV4,
+    IPoolManager::swapCall,
+    Swap,
+    [..Swap],
+    call_data: true,
+    logs: true,
+    |info: CallInfo,
+    call_data: swapCall,
+    log_data: UniswapV4SwapCallLogs,
+    db_ctx: &DB| {
+        let swap = log_data.swap_field?;
+        let [currency0, currency1] = pool_currencies(db_ctx, &call_data.key, swap.id)?;
+
+        // the event deltas are the pool's swap from the locker's side, hook
+        // deltas are left out. negative amounts are owed by the locker
+        let (token_in, amount_in, token_out, amount_out) = if swap.amount0 < 0 {
+            (currency0, swap.amount0, currency1, swap.amount1)
+        } else {
+            (currency1, swap.amount1, currency0, swap.amount0)
+        };
+
+        Ok(NormalizedSwap {
+            protocol: Protocol::UniswapV4,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            recipient: info.from_address,
+            pool: info.target_address,
+            pool_id: Some(swap.id),
+            token_in,
+            token_out,
+            amount_in: U256::from(amount_in.unsigned_abs()),
+            amount_out: U256::from(amount_out.unsigned_abs()),
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::UniswapV4,
+    IPoolManager::modifyLiquidityCall,
+    Actions,
+    [..ModifyLiquidity],
+    call_data: true,
+    return_data: true,
+    logs: true,
+    |info: CallInfo,
+    call_data: modifyLiquidityCall,
+    return_data: modifyLiquidityReturn,
+    log_data: UniswapV4ModifyLiquidityCallLogs,
+    db_ctx: &DB| {
+        let modify = log_data.modify_liquidity_field?;
+        let tokens = pool_currencies(db_ctx, &call_data.key, modify.id)?.to_vec();
+        let position = Some(NormalizedPosition {
+            // the v4 position manager salts positions with their token id
+            token_id: (modify.salt != B256::ZERO).then(|| U256::from_be_bytes(modify.salt.0)),
+            tick_lower: modify.tickLower.as_i32(),
+            tick_upper: modify.tickUpper.as_i32(),
+            liquidity: modify.liquidityDelta.unsigned_abs().saturating_to(),
+        });
+
+        // fees are collected on every modification, the caller delta minus the
+        // fees is the principal added or removed
+        let fees = balance_delta(return_data.feesAccrued);
+        let caller = balance_delta(return_data.callerDelta);
+        let principal = [caller[0] - fees[0], caller[1] - fees[1]];
+
+        if modify.liquidityDelta.is_zero() {
+            return Ok(Actions::Collect(NormalizedCollect {
+                protocol: Protocol::UniswapV4,
+                trace_index: info.trace_idx,
+                from: info.from_address,
+                recipient: info.from_address,
+                pool: info.target_address,
+                pool_id: Some(modify.id),
+                tokens,
+                amounts: fees.map(I256::unsigned_abs).to_vec(),
+                position,
+            }));
+        }
+
+        if modify.liquidityDelta.is_negative() {
+            return Ok(Actions::Burn(NormalizedBurn {
+                protocol: Protocol::UniswapV4,
+                trace_index: info.trace_idx,
+                from: info.from_address,
+                recipient: info.from_address,
+                pool: info.target_address,
+                pool_id: Some(modify.id),
+                tokens,
+                amounts: principal.map(I256::unsigned_abs).to_vec(),
+                position,
+            }));
+        }
+
+        Ok(Actions::Mint(NormalizedMint {
+            protocol: Protocol::UniswapV4,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            recipient: info.from_address,
+            pool: info.target_address,
+            pool_id: Some(modify.id),
+            tokens,
+            amounts: principal.map(I256::unsigned_abs).to_vec(),
+            position,
+        }))
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::UniswapV4,
+    IPoolManager::syncCall,
+    Settlement,
+    [],
+    call_data: true,
+    |info: CallInfo,
+    call_data: syncCall,
+    _db_ctx: &DB| {
+        Ok(NormalizedSettlement {
+            protocol: Protocol::UniswapV4,
+            trace_index: info.trace_idx,
+            kind: SettlementKind::Sync,
+            singleton: info.target_address,
+            account: info.from_address,
+            recipient: info.target_address,
+            token: currency_token(call_data.currency),
+            amount: U256::ZERO,
+        })
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::UniswapV4,
+    IPoolManager::settleCall,
+    Settlement,
+    [],
+    return_data: true,
+    |info: CallInfo,
+    return_data: settleReturn,
+    _db_ctx: &DB| {
+        Ok(settled(&info, info.from_address, return_data.paid))
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::UniswapV4,
+    IPoolManager::settleForCall,
+    Settlement,
+    [],
+    call_data: true,
+    return_data: true,
+    |info: CallInfo,
+    call_data: settleForCall,
+    return_data: settleForReturn,
+    _db_ctx: &DB| {
+        Ok(settled(&info, call_data.recipient, return_data.paid))
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::UniswapV4,
+    IPoolManager::takeCall,
+    Settlement,
+    [],
+    call_data: true,
+    |info: CallInfo,
+    call_data: takeCall,
+    _db_ctx: &DB| {
+        Ok(NormalizedSettlement {
+            protocol: Protocol::UniswapV4,
+            trace_index: info.trace_idx,
+            kind: SettlementKind::Take,
+            singleton: info.target_address,
+            account: info.from_address,
+            recipient: call_data.to,
+            token: currency_token(call_data.currency),
+            amount: call_data.amount,
+        })
+    }
+);
+
+/// the pool id of `key`, the key under which v4 pools are registered
+pub fn uniswap_v4_pool_id(key: &IPoolManager::PoolKey) -> B256 {
+    alloy_primitives::keccak256(alloy_sol_types::SolValue::abi_encode(key))
+}
+
+/// the currencies of pool `id` in currency order. registered pools are read
+/// from the registry, which lists a pool's currencies with the zero address for
+/// the native currency, unregistered ones fall back to the call's pool key
+fn pool_currencies<DB: DataContext<Protocol, ProtocolMetadata>>(
+    db_ctx: &DB,
+    key: &IPoolManager::PoolKey,
+    id: B256,
+) -> eyre::Result<[Address; 2]> {
+    if uniswap_v4_pool_id(key) != id {
+        return Err(eyre::eyre!("pool key doesn't hash to the pool id {id:?}"));
+    }
+
+    let currencies = match db_ctx.get_pool_tokens_sorted(id) {
+        Ok(tokens) => match tokens.as_slice() {
+            [currency0, currency1] => [*currency0, *currency1],
+            _ => {
+                return Err(eyre::eyre!(
+                    "pool {id:?} needs exactly 2 registered currencies, found {}",
+                    tokens.len()
+                ));
+            }
+        },
+        Err(e) if is_not_registered(&e) => [key.currency0, key.currency1],
+        Err(e) => return Err(e),
+    };
+
+    Ok(currencies.map(currency_token))
+}
+
+/// v4 uses the zero address for the native currency
+fn currency_token(currency: Address) -> Address {
+    if currency == Address::ZERO {
+        NATIVE_TOKEN
+    } else {
+        currency
+    }
+}
+
+/// splits a packed balance delta into its sign extended amounts
+fn balance_delta(delta: I256) -> [I256; 2] {
+    [delta.asr(128), (delta << 128usize).asr(128)]
+}
+
+/// settles pay in the currency an earlier `sync` pointed at, which the call
+/// doesn't name. for erc20s the manager reads its balance of the currency, the
+/// first static call of the settle, native settles don't read a balance and
+/// carry their amount as call value.
+fn settled(info: &CallInfo, account: Address, paid: U256) -> NormalizedSettlement<Protocol> {
+    NormalizedSettlement {
+        protocol: Protocol::UniswapV4,
+        trace_index: info.trace_idx,
+        kind: SettlementKind::Settle,
+        singleton: info.target_address,
+        account,
+        recipient: info.target_address,
+        token: info
+            .static_call_targets
+            .first()
+            .copied()
+            .unwrap_or(NATIVE_TOKEN),
+        amount: paid,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{
+        address,
+        aliases::{I24, U24},
+        b256,
+    };
+    use brontes_classifier::context::NotRegistered;
+
+    use super::*;
+
+    const USDC: Address = address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+
+    /// the native / usdc 0.05% pool
+    fn eth_usdc_key() -> IPoolManager::PoolKey {
+        IPoolManager::PoolKey {
+            currency0: Address::ZERO,
+            currency1: USDC,
+            fee: U24::from(500),
+            tickSpacing: I24::try_from(10).unwrap(),
+            hooks: Address::ZERO,
+        }
+    }
+
+    fn packed(amount0: i128, amount1: i128) -> I256 {
+        I256::from_raw((U256::from(amount0 as u128) << 128) | U256::from(amount1 as u128))
+    }
+
+    struct Unregistered;
+
+    impl DataContext<Protocol, ProtocolMetadata> for Unregistered {
+        fn get_protocol(&self, target_address: Address) -> eyre::Result<Protocol> {
+            Err(NotRegistered::Address(target_address).into())
+        }
+
+        fn get_protocol_tokens_sorted(
+            &self,
+            target_address: Address,
+        ) -> eyre::Result<Vec<Address>> {
+            Err(NotRegistered::Address(target_address).into())
+        }
+    }
+
+    #[test]
+    fn splits_balance_deltas() {
+        for (amount0, amount1) in [
+            (0, 0),
+            (5, 9),
+            (-5, 9),
+            (5, -9),
+            (-5, -9),
+            (i128::MIN, i128::MAX),
+            (i128::MAX, i128::MIN),
+        ] {
+            assert_eq!(
+                balance_delta(packed(amount0, amount1)),
+                [
+                    I256::try_from(amount0).unwrap(),
+                    I256::try_from(amount1).unwrap()
+                ],
+                "({amount0}, {amount1})"
+            );
+        }
+    }
+
+    #[test]
+    fn hashes_pool_keys_into_pool_ids() {
+        assert_eq!(
+            uniswap_v4_pool_id(&eth_usdc_key()),
+            b256!("21c67e77068de97969ba93d4aab21826d33ca12bb9f565d8496e8fda8a82ca27")
+        );
+    }
+
+    #[test]
+    fn falls_back_to_the_pool_key_currencies() {
+        let key = eth_usdc_key();
+        let id = uniswap_v4_pool_id(&key);
+
+        assert_eq!(
+            pool_currencies(&Unregistered, &key, id).unwrap(),
+            [NATIVE_TOKEN, USDC]
+        );
+        assert!(pool_currencies(&Unregistered, &key, B256::ZERO).is_err());
+    }
+}
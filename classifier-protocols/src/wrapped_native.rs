@@ -0,0 +1,115 @@
+use alloy_primitives::{Address, address};
+use alloy_sol_types::sol;
+use brontes_classifier::{action_impl, context::RegistryEntry, types::CallInfo};
+use brontes_classifier_types::{NATIVE_TOKEN, NormalizedDeposit, NormalizedWithdraw};
+
+use crate::{Actions, Protocol, ProtocolMetadata};
+
+sol! {
+    interface IWETH9 {
+        event Deposit(address indexed dst, uint256 wad);
+        event Withdrawal(address indexed src, uint256 wad);
+        // wrappers that mint / burn through their erc20 logic instead of
+        // emitting the weth9 events, e.g. arbitrum's aeWETH
+        event Transfer(address indexed from, address indexed to, uint256 value);
+
+        function deposit() external payable;
+        function withdraw(uint256 wad) external;
+    }
+}
+
+// eth sent without calldata runs `deposit` through the receive function, so
+// the deposit classifier is also dispatched as the wrapper's receive classifier
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::WrappedNative,
+    IWETH9::depositCall,
+    Deposit,
+    [..(Deposit | Transfer)],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: WrappedNativeDepositCallLogs, _db_ctx: &DB| {
+        let amount = match (log_data.deposit_field, log_data.transfer_field) {
+            (Ok(deposit), _) => deposit.wad,
+            (_, Ok(transfer)) => transfer.value,
+            (Err(e), _) => return Err(e),
+        };
+
+        Ok(NormalizedDeposit {
+            protocol: Protocol::WrappedNative,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            from: info.from_address,
+            recipient: info.from_address,
+            asset: NATIVE_TOKEN,
+            amount,
+            shares: Some(amount),
+        })
+    }
+);
+
+action_impl!(
+    (Protocol, Actions, ProtocolMetadata),
+    Protocol::WrappedNative,
+    IWETH9::withdrawCall,
+    Withdraw,
+    [..(Withdrawal | Transfer)],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: WrappedNativeWithdrawCallLogs, _db_ctx: &DB| {
+        let amount = match (log_data.withdrawal_field, log_data.transfer_field) {
+            (Ok(withdrawal), _) => withdrawal.wad,
+            (_, Ok(transfer)) => transfer.value,
+            (Err(e), _) => return Err(e),
+        };
+
+        Ok(NormalizedWithdraw {
+            protocol: Protocol::WrappedNative,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            from: info.from_address,
+            owner: info.from_address,
+            recipient: info.from_address,
+            asset: NATIVE_TOKEN,
+            amount,
+            shares: Some(amount),
+        })
+    }
+);
+
+/// the canonical wrapped native token of a chain
+pub fn wrapped_native_address(chain_id: u64) -> Option<Address> {
+    Some(match chain_id {
+        // ethereum
+        1 => address!("0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2"),
+        // optimism, base
+        10 | 8453 => address!("0x4200000000000000000000000000000000000006"),
+        // bnb chain
+        56 => address!("0xbb4CdB9CBd36B01bD1cBaEBF2De08d9173bc095c"),
+        // gnosis
+        100 => address!("0xe91D153E0b41518A2Ce8Dd3D7944Fa863463a97d"),
+        // polygon
+        137 => address!("0x0d500B1d8E8eF31E21C99d1Db9A6444d3ADf1270"),
+        // arbitrum one
+        42161 => address!("0x82aF49447D8a07e3bd95BD0d56f35241523fBab1"),
+        // avalanche c-chain
+        43114 => address!("0xB31f66AA3C1e785363F0875A1B74E27b85FD66c7"),
+        // sepolia
+        11155111 => address!("0xfFf9976782d46CC05630D1f6eBAb18b2324d6B14"),
+        _ => return None,
+    })
+}
+
+/// a registry entry marking `address` as the chain's wrapped native token, for
+/// chains not covered by [`wrapped_native_address`] or custom deployments.
+pub fn wrapped_native_entry<M: Default>(address: Address) -> RegistryEntry<M> {
+    RegistryEntry {
+        address,
+        protocol: Protocol::WrappedNative.name().to_string(),
+        tokens: vec![address],
+        deployment_block: None,
+        metadata: M::default(),
+        pool_id: None,
+    }
+}
@@ -0,0 +1,122 @@
+use serde::{Deserialize, Serialize};
+
+use crate::normalized::*;
+
+/// The normalized output of a classifier collection. variants are named so
+/// they can be used as the action type of `action_impl!` directly, with the
+/// protocol enum bound through an alias:
+///
+/// ```ignore
+/// pub type Actions = Action<Protocol>;
+///
+/// action_dispatch!((Classifier, Protocol) => Actions | UniswapV2SwapCall);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Action<P> {
+    Swap(NormalizedSwap<P>),
+    SwapWithFee(NormalizedSwapWithFee<P>),
+    MultiSwap(NormalizedMultiSwap<P>),
+    AggregatorSwap(NormalizedAggregatorSwap<P>),
+    Transfer(NormalizedTransfer),
+    MultiTransfer(NormalizedMultiTransfer),
+    /// only built downstream, see [`NormalizedEthTransfer`]
+    EthTransfer(NormalizedEthTransfer),
+    Approval(NormalizedApproval),
+    MultiApproval(NormalizedMultiApproval),
+    NftTransfer(NormalizedNftTransfer),
+    NftApproval(NormalizedNftApproval),
+    Mint(NormalizedMint<P>),
+    Burn(NormalizedBurn<P>),
+    Collect(NormalizedCollect<P>),
+    Sync(NormalizedSync<P>),
+    PositionChange(NormalizedPositionChange<P>),
+    FlashLoan(NormalizedFlashLoan<P>),
+    Liquidation(NormalizedLiquidation<P>),
+    MultiLiquidation(NormalizedMultiLiquidation<P>),
+    Deposit(NormalizedDeposit<P>),
+    Withdraw(NormalizedWithdraw<P>),
+    Borrow(NormalizedBorrow<P>),
+    Repay(NormalizedRepay<P>),
+    Batch(NormalizedBatch<P>),
+    Settlement(NormalizedSettlement<P>),
+    NftTrade(NormalizedNftTrade<P>),
+    MultiNftTrade(NormalizedMultiNftTrade<P>),
+    Bridge(NormalizedBridge<P>),
+}
+
+impl<P> Action<P> {
+    pub fn trace_index(&self) -> u64 {
+        match self {
+            Self::Swap(swap) => swap.trace_index,
+            Self::SwapWithFee(swap) => swap.swap.trace_index,
+            Self::MultiSwap(swap) => swap.trace_index,
+            Self::AggregatorSwap(swap) => swap.swap.trace_index,
+            Self::Transfer(transfer) => transfer.trace_index,
+            Self::MultiTransfer(transfer) => transfer.trace_index,
+            Self::EthTransfer(transfer) => transfer.trace_index,
+            Self::Approval(approval) => approval.trace_index,
+            Self::MultiApproval(approval) => approval.trace_index,
+            Self::NftTransfer(transfer) => transfer.trace_index,
+            Self::NftApproval(approval) => approval.trace_index,
+            Self::Mint(mint) => mint.trace_index,
+            Self::Burn(burn) => burn.trace_index,
+            Self::Collect(collect) => collect.trace_index,
+            Self::Sync(sync) => sync.trace_index,
+            Self::PositionChange(change) => change.trace_index,
+            Self::FlashLoan(flash_loan) => flash_loan.trace_index,
+            Self::Liquidation(liquidation) => liquidation.trace_index,
+            Self::MultiLiquidation(liquidation) => liquidation.trace_index,
+            Self::Deposit(deposit) => deposit.trace_index,
+            Self::Withdraw(withdraw) => withdraw.trace_index,
+            Self::Borrow(borrow) => borrow.trace_index,
+            Self::Repay(repay) => repay.trace_index,
+            Self::Batch(batch) => batch.trace_index,
+            Self::Settlement(settlement) => settlement.trace_index,
+            Self::NftTrade(trade) => trade.trace_index,
+            Self::MultiNftTrade(trade) => trade.trace_index,
+            Self::Bridge(bridge) => bridge.trace_index,
+        }
+    }
+
+    /// the protocol the action happened on, `None` for token and nft transfers,
+    /// approvals and eth transfers
+    pub fn protocol(&self) -> Option<&P> {
+        match self {
+            Self::Swap(swap) => Some(&swap.protocol),
+            Self::SwapWithFee(swap) => Some(&swap.swap.protocol),
+            Self::MultiSwap(swap) => Some(&swap.protocol),
+            Self::AggregatorSwap(swap) => Some(&swap.swap.protocol),
+            Self::Transfer(_)
+            | Self::MultiTransfer(_)
+            | Self::EthTransfer(_)
+            | Self::Approval(_)
+            | Self::MultiApproval(_)
+            | Self::NftTransfer(_)
+            | Self::NftApproval(_) => None,
+            Self::Mint(mint) => Some(&mint.protocol),
+            Self::Burn(burn) => Some(&burn.protocol),
+            Self::Collect(collect) => Some(&collect.protocol),
+            Self::Sync(sync) => Some(&sync.protocol),
+            Self::PositionChange(change) => Some(&change.protocol),
+            Self::FlashLoan(flash_loan) => Some(&flash_loan.protocol),
+            Self::Liquidation(liquidation) => Some(&liquidation.protocol),
+            Self::MultiLiquidation(liquidation) => Some(&liquidation.protocol),
+            Self::Deposit(deposit) => Some(&deposit.protocol),
+            Self::Withdraw(withdraw) => Some(&withdraw.protocol),
+            Self::Borrow(borrow) => Some(&borrow.protocol),
+            Self::Repay(repay) => Some(&repay.protocol),
+            Self::Batch(batch) => Some(&batch.protocol),
+            Self::Settlement(settlement) => Some(&settlement.protocol),
+            Self::NftTrade(trade) => Some(&trade.protocol),
+            Self::MultiNftTrade(trade) => Some(&trade.protocol),
+            Self::Bridge(bridge) => Some(&bridge.protocol),
+        }
+    }
+
+    pub fn is_swap(&self) -> bool {
+        matches!(
+            self,
+            Self::Swap(_) | Self::SwapWithFee(_) | Self::MultiSwap(_) | Self::AggregatorSwap(_)
+        )
+    }
+}
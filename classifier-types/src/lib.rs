@@ -0,0 +1,10 @@
+mod action;
+pub mod normalized;
+
+pub use action::Action;
+use alloy_primitives::{Address, address};
+pub use normalized::*;
+
+/// placeholder token address for the chain's native currency in normalized
+/// actions, e.g. the eth side of a weth deposit
+pub const NATIVE_TOKEN: Address = address!("0xEeeeeEeeeEeEeeEeEeEeeEEEeeeeEeeeeeeeEEeE");
@@ -0,0 +1,30 @@
+use alloy_primitives::{Address, FixedBytes, U256};
+use serde::{Deserialize, Serialize};
+
+use super::NormalizedSwapWithFee;
+
+/// a batch settlement, `user_swaps` are the trades settled for users, one per
+/// filled order, and `solver_interactions` the calls the solver made to source
+/// the liquidity to fill them. trades matched against each other or against the
+/// settlement contract's own balances need no interaction.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizedBatch<P> {
+    pub protocol: P,
+    pub trace_index: u64,
+    pub solver: Address,
+    pub settlement_contract: Address,
+    pub user_swaps: Vec<NormalizedSwapWithFee<P>>,
+    pub solver_interactions: Vec<NormalizedInteraction>,
+    /// trace indices of the calls made below the settlement, the swaps
+    /// classified at those traces are the solver's liquidity
+    pub child_trace_indices: Vec<u64>,
+    pub msg_value: U256,
+}
+
+/// a call the settlement contract made on the solver's behalf
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizedInteraction {
+    pub target: Address,
+    pub value: U256,
+    pub selector: FixedBytes<4>,
+}
@@ -0,0 +1,28 @@
+use alloy_primitives::{Address, U256};
+use serde::{Deserialize, Serialize};
+
+/// which way funds cross a canonical bridge, seen from the l1
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BridgeDirection {
+    /// funds locked on the l1 to be minted or released on the l2
+    Deposit,
+    /// funds released on the l1 after being sent from the l2
+    Withdraw,
+}
+
+/// `amount` of `token` crossing `bridge` between the l1 and the l2
+/// `l2_chain_id`, the destination of deposits and the source of withdrawals.
+/// `from` is the sender on the source chain, zero for bridges that don't
+/// report it on withdrawal
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizedBridge<P> {
+    pub protocol: P,
+    pub trace_index: u64,
+    pub bridge: Address,
+    pub direction: BridgeDirection,
+    pub from: Address,
+    pub recipient: Address,
+    pub token: Address,
+    pub amount: U256,
+    pub l2_chain_id: u64,
+}
@@ -0,0 +1,16 @@
+use alloy_primitives::{Address, U256};
+use serde::{Deserialize, Serialize};
+
+/// a flash loan of `assets` from `pool` to `receiver`, `assets`, `amounts` and
+/// `fees` are index aligned
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizedFlashLoan<P> {
+    pub protocol: P,
+    pub trace_index: u64,
+    pub from: Address,
+    pub pool: Address,
+    pub receiver: Address,
+    pub assets: Vec<Address>,
+    pub amounts: Vec<U256>,
+    pub fees: Vec<U256>,
+}
@@ -0,0 +1,89 @@
+use alloy_primitives::{Address, U256};
+use serde::{Deserialize, Serialize};
+
+/// `liquidator` repays `covered_debt` of `debtor`'s `debt_asset` and seizes
+/// `liquidated_collateral` of `collateral_asset`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizedLiquidation<P> {
+    pub protocol: P,
+    pub trace_index: u64,
+    pub pool: Address,
+    pub liquidator: Address,
+    pub debtor: Address,
+    pub collateral_asset: Address,
+    pub debt_asset: Address,
+    pub covered_debt: U256,
+    pub liquidated_collateral: U256,
+    /// set when `liquidated_collateral` is an amount of the collateral
+    /// market's share token rather than of `collateral_asset`, e.g. the
+    /// ctokens seized by a compound v2 liquidation
+    #[serde(default)]
+    pub collateral_share_token: Option<Address>,
+}
+
+/// a single call liquidating several positions or collaterals at once, e.g. a
+/// comet `absorb`. `liquidations` holds one liquidation per seized collateral
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizedMultiLiquidation<P> {
+    pub protocol: P,
+    pub trace_index: u64,
+    pub pool: Address,
+    pub liquidator: Address,
+    pub liquidations: Vec<NormalizedLiquidation<P>>,
+}
+
+/// `amount` of `asset` deposited into `pool` on behalf of `recipient`. `shares`
+/// is set for protocols that mint a share / receipt token for the deposit.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizedDeposit<P> {
+    pub protocol: P,
+    pub trace_index: u64,
+    pub pool: Address,
+    pub from: Address,
+    pub recipient: Address,
+    pub asset: Address,
+    pub amount: U256,
+    pub shares: Option<U256>,
+}
+
+/// `amount` of `asset` withdrawn from `owner`'s position in `pool` and sent to
+/// `recipient`. `shares` is set for protocols that burn a share / receipt
+/// token for the withdrawal.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizedWithdraw<P> {
+    pub protocol: P,
+    pub trace_index: u64,
+    pub pool: Address,
+    pub from: Address,
+    pub owner: Address,
+    pub recipient: Address,
+    pub asset: Address,
+    pub amount: U256,
+    pub shares: Option<U256>,
+}
+
+/// `amount` of `asset` borrowed from `pool` against `borrower`'s collateral and
+/// sent to `recipient`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizedBorrow<P> {
+    pub protocol: P,
+    pub trace_index: u64,
+    pub pool: Address,
+    pub from: Address,
+    pub borrower: Address,
+    pub recipient: Address,
+    pub asset: Address,
+    pub amount: U256,
+}
+
+/// `amount` of `asset` repaid to `pool` by `from`, reducing `borrower`'s debt
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizedRepay<P> {
+    pub protocol: P,
+    pub trace_index: u64,
+    pub pool: Address,
+    pub from: Address,
+    pub borrower: Address,
+    pub asset: Address,
+    pub amount: U256,
+}
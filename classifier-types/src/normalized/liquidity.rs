@@ -0,0 +1,110 @@
+use alloy_primitives::{Address, B256, U256};
+use serde::{Deserialize, Serialize};
+
+/// liquidity added to `pool`, `tokens` and `amounts` are index aligned
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizedMint<P> {
+    pub protocol: P,
+    pub trace_index: u64,
+    pub from: Address,
+    pub recipient: Address,
+    pub pool: Address,
+    /// the id of pools identified by one, e.g. uniswap v4 pools whose `pool`
+    /// is the pool manager they live in
+    #[serde(default)]
+    pub pool_id: Option<B256>,
+    pub tokens: Vec<Address>,
+    pub amounts: Vec<U256>,
+    /// set for concentrated liquidity positions
+    #[serde(default)]
+    pub position: Option<NormalizedPosition>,
+}
+
+/// liquidity removed from `pool`, `tokens` and `amounts` are index aligned
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizedBurn<P> {
+    pub protocol: P,
+    pub trace_index: u64,
+    pub from: Address,
+    pub recipient: Address,
+    pub pool: Address,
+    /// the id of pools identified by one, e.g. uniswap v4 pools whose `pool`
+    /// is the pool manager they live in
+    #[serde(default)]
+    pub pool_id: Option<B256>,
+    pub tokens: Vec<Address>,
+    pub amounts: Vec<U256>,
+    /// set for concentrated liquidity positions
+    #[serde(default)]
+    pub position: Option<NormalizedPosition>,
+}
+
+/// fees / owed tokens collected from a liquidity position, `tokens` and
+/// `amounts` are index aligned
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizedCollect<P> {
+    pub protocol: P,
+    pub trace_index: u64,
+    pub from: Address,
+    pub recipient: Address,
+    pub pool: Address,
+    /// the id of pools identified by one, e.g. uniswap v4 pools whose `pool`
+    /// is the pool manager they live in
+    #[serde(default)]
+    pub pool_id: Option<B256>,
+    pub tokens: Vec<Address>,
+    pub amounts: Vec<U256>,
+    /// set for concentrated liquidity positions
+    #[serde(default)]
+    pub position: Option<NormalizedPosition>,
+}
+
+/// a pool's reserves forced to match its balances, `tokens` and `reserves` are
+/// index aligned
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizedSync<P> {
+    pub protocol: P,
+    pub trace_index: u64,
+    pub pool: Address,
+    pub tokens: Vec<Address>,
+    pub reserves: Vec<U256>,
+}
+
+/// what a position manager call did to a position
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PositionChangeKind {
+    Open,
+    Increase,
+    Decrease,
+    Collect,
+}
+
+/// a liquidity position held through a position manager, e.g. a uniswap v3
+/// nft, being changed. the tokens move in the pool call below the manager,
+/// which is classified as the mint / burn / collect at one of
+/// `child_trace_indices`, this only ties that action to the position.
+/// `recipient` is the holder of the position for opens and the recipient of
+/// the tokens for collects, the manager otherwise.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizedPositionChange<P> {
+    pub protocol: P,
+    pub trace_index: u64,
+    pub kind: PositionChangeKind,
+    pub from: Address,
+    pub recipient: Address,
+    pub manager: Address,
+    pub pool: Address,
+    pub position: NormalizedPosition,
+    pub child_trace_indices: Vec<u64>,
+}
+
+/// the range and size of a concentrated liquidity position change
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizedPosition {
+    /// the position nft, when the position is managed through one
+    pub token_id: Option<U256>,
+    pub tick_lower: i32,
+    pub tick_upper: i32,
+    /// the liquidity added or removed, zero for collects
+    pub liquidity: u128,
+}
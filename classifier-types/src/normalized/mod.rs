@@ -0,0 +1,32 @@
+mod batch;
+mod bridge;
+mod flashloan;
+mod lending;
+mod liquidity;
+mod nft;
+mod settlement;
+mod swap;
+mod transfer;
+
+pub use batch::{NormalizedBatch, NormalizedInteraction};
+pub use bridge::{BridgeDirection, NormalizedBridge};
+pub use flashloan::NormalizedFlashLoan;
+pub use lending::{
+    NormalizedBorrow, NormalizedDeposit, NormalizedLiquidation, NormalizedMultiLiquidation,
+    NormalizedRepay, NormalizedWithdraw,
+};
+pub use liquidity::{
+    NormalizedBurn, NormalizedCollect, NormalizedMint, NormalizedPosition,
+    NormalizedPositionChange, NormalizedSync, PositionChangeKind,
+};
+pub use nft::{
+    NormalizedMultiNftTrade, NormalizedNftApproval, NormalizedNftTrade, NormalizedNftTransfer,
+};
+pub use settlement::{NormalizedSettlement, SettlementKind};
+pub use swap::{
+    NormalizedAggregatorSwap, NormalizedMultiSwap, NormalizedSwap, NormalizedSwapWithFee,
+};
+pub use transfer::{
+    NormalizedApproval, NormalizedEthTransfer, NormalizedMultiApproval, NormalizedMultiTransfer,
+    NormalizedTransfer,
+};
@@ -0,0 +1,54 @@
+use alloy_primitives::{Address, U256};
+use serde::{Deserialize, Serialize};
+
+/// nfts of `collection` moved from `from` to `to`, `amounts[i]` of
+/// `token_ids[i]`. erc721 transfers move a single token with an amount of one,
+/// erc1155 batch transfers several ids at once
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizedNftTransfer {
+    pub trace_index: u64,
+    pub collection: Address,
+    pub from: Address,
+    pub to: Address,
+    pub token_ids: Vec<U256>,
+    pub amounts: Vec<U256>,
+}
+
+/// `owner` allowing `operator` to move `token_id` of `collection`, or every
+/// token of it when `token_id` is `None`. `approved` is false when the call
+/// revoked the approval instead
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizedNftApproval {
+    pub trace_index: u64,
+    pub collection: Address,
+    pub owner: Address,
+    pub operator: Address,
+    pub token_id: Option<U256>,
+    pub approved: bool,
+}
+
+/// `buyer` paying `price` of `payment_token` to `seller` for `amount` of
+/// `token_id` through a marketplace. `price` is what the buyer paid in total,
+/// marketplace fees and royalties included
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizedNftTrade<P> {
+    pub protocol: P,
+    pub trace_index: u64,
+    pub marketplace: Address,
+    pub seller: Address,
+    pub buyer: Address,
+    pub collection: Address,
+    pub token_id: U256,
+    pub amount: U256,
+    pub payment_token: Address,
+    pub price: U256,
+}
+
+/// a single call filling several nft orders, e.g. a sweep of listings
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizedMultiNftTrade<P> {
+    pub protocol: P,
+    pub trace_index: u64,
+    pub marketplace: Address,
+    pub trades: Vec<NormalizedNftTrade<P>>,
+}
@@ -0,0 +1,29 @@
+use alloy_primitives::{Address, U256};
+use serde::{Deserialize, Serialize};
+
+/// which way a flash accounting delta is closed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SettlementKind {
+    /// `account` paid `amount` into the singleton, clearing a debt
+    Settle,
+    /// `amount` was taken out of the singleton to `recipient`, clearing a
+    /// credit of `account`
+    Take,
+    /// `token` was marked as the currency the next settle of `account` pays
+    /// in, `amount` is zero
+    Sync,
+}
+
+/// a currency delta on a flash accounting singleton such as the uniswap v4
+/// pool manager being closed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizedSettlement<P> {
+    pub protocol: P,
+    pub trace_index: u64,
+    pub kind: SettlementKind,
+    pub singleton: Address,
+    pub account: Address,
+    pub recipient: Address,
+    pub token: Address,
+    pub amount: U256,
+}
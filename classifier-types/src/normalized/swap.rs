@@ -0,0 +1,58 @@
+use alloy_primitives::{Address, B256, U256};
+use serde::{Deserialize, Serialize};
+
+/// `amount_in` of `token_in` swapped for `amount_out` of `token_out` through
+/// a single pool. multi hop routes are one swap per pool.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizedSwap<P> {
+    pub protocol: P,
+    pub trace_index: u64,
+    /// the address that called the pool
+    pub from: Address,
+    pub recipient: Address,
+    pub pool: Address,
+    /// the id of pools identified by one, e.g. uniswap v4 pools whose `pool`
+    /// is the pool manager they live in
+    #[serde(default)]
+    pub pool_id: Option<B256>,
+    pub token_in: Address,
+    pub token_out: Address,
+    pub amount_in: U256,
+    pub amount_out: U256,
+    pub msg_value: U256,
+}
+
+/// a swap where the protocol takes an explicit fee on top of the pool's
+/// pricing, e.g. an aggregator or a settlement contract
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizedSwapWithFee<P> {
+    pub swap: NormalizedSwap<P>,
+    pub fee_token: Address,
+    pub fee_amount: U256,
+}
+
+/// a single call swapping through several pools, e.g. a balancer batch swap.
+/// `swaps` holds one swap per hop in execution order, the `tokens_*` and
+/// `amounts_*` vectors are the net amounts the caller paid in and received
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizedMultiSwap<P> {
+    pub protocol: P,
+    pub trace_index: u64,
+    pub from: Address,
+    pub recipient: Address,
+    pub tokens_in: Vec<Address>,
+    pub amounts_in: Vec<U256>,
+    pub tokens_out: Vec<Address>,
+    pub amounts_out: Vec<U256>,
+    pub swaps: Vec<NormalizedSwap<P>>,
+    pub msg_value: U256,
+}
+
+/// the caller's net trade through an aggregator or router, `swap.pool` is the
+/// router. `child_trace_indices` are the calls the router made, the pool swaps
+/// of the route are the swaps classified at those traces
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizedAggregatorSwap<P> {
+    pub swap: NormalizedSwap<P>,
+    pub child_trace_indices: Vec<u64>,
+}
@@ -0,0 +1,55 @@
+use alloy_primitives::{Address, U256};
+use serde::{Deserialize, Serialize};
+
+/// an erc20 transfer of `amount` of `token` from `from` to `to`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizedTransfer {
+    pub trace_index: u64,
+    pub from: Address,
+    pub to: Address,
+    pub token: Address,
+    /// the amount `to` actually received
+    pub amount: U256,
+    /// the amount taken by the token on transfer, zero for regular tokens
+    pub fee: U256,
+}
+
+/// a plain eth value transfer. no classifier produces it, value moved by a
+/// call is only visible on the trace itself, it exists for downstream
+/// consumers that derive the value flow of a transaction from its traces.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizedEthTransfer {
+    pub trace_index: u64,
+    pub from: Address,
+    pub to: Address,
+    pub value: U256,
+}
+
+/// several transfers made by a single call, e.g. a permit2 batch transfer
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizedMultiTransfer {
+    pub trace_index: u64,
+    pub transfers: Vec<NormalizedTransfer>,
+}
+
+/// `owner` allowing `spender` to move up to `amount` of `token`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizedApproval {
+    pub trace_index: u64,
+    pub owner: Address,
+    pub spender: Address,
+    pub token: Address,
+    pub amount: U256,
+    /// the timestamp the allowance lapses at, for allowances that do
+    pub expiration: Option<u64>,
+    /// the contract holding the allowance when it isn't the token itself, e.g.
+    /// permit2
+    pub via: Option<Address>,
+}
+
+/// several allowances set by a single call, e.g. a permit2 batch permit
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizedMultiApproval {
+    pub trace_index: u64,
+    pub approvals: Vec<NormalizedApproval>,
+}
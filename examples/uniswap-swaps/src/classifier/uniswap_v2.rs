@@ -1,14 +1,15 @@
 use alloy_primitives::U256;
 use brontes_classifier::action_impl;
 use brontes_classifier::types::CallInfo;
+use brontes_classifier_types::NormalizedSwap;
 
 use crate::abis::UniswapV2;
-use crate::types::ActionSwap;
 use crate::types::Actions;
 use crate::types::Protocol;
+use crate::types::UniswapProtocolTokens;
 
 action_impl! {
-    (Protocol, Actions),
+    (Protocol, Actions, UniswapProtocolTokens),
     crate::types::Protocol::UniswapV2,
     UniswapV2::swapCall,
     Swap,
@@ -23,23 +24,26 @@ action_impl! {
         let logs = log_data.swap_field?;
         let recipient = call_data.to;
 
-        let tokens = db_ctx.get_protocol_tokens_sorted(info.target_address)?;
+        let tokens = db_ctx.get_metadata(info.protocol_address)?.sorted_tokens;
 
         let (token_in, amount_in, token_out, amount_out) = if logs.amount0In == U256::ZERO {
-            (tokens[1], logs.amount1In, tokens[0], logs.amount0In)
+            (tokens[1], logs.amount1In, tokens[0], logs.amount0Out)
         } else {
-            (tokens[0], logs.amount0In, tokens[1], logs.amount1In)
+            (tokens[0], logs.amount0In, tokens[1], logs.amount1Out)
         };
 
-        Ok(ActionSwap {
+        Ok(NormalizedSwap {
             protocol: Protocol::UniswapV2,
-            pool: info.target_address,
+            trace_index: info.trace_idx,
+            from: info.from_address,
             recipient,
+            pool: info.target_address,
+            pool_id: None,
             token_in,
             token_out,
             amount_in,
             amount_out,
-
+            msg_value: info.msg_value,
         })
     }
 }
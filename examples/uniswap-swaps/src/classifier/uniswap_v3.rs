@@ -1,13 +1,14 @@
 use crate::abis::UniswapV3;
 use brontes_classifier::action_impl;
 use brontes_classifier::types::CallInfo;
+use brontes_classifier_types::NormalizedSwap;
 
-use crate::types::ActionSwap;
 use crate::types::Actions;
 use crate::types::Protocol;
+use crate::types::UniswapProtocolTokens;
 
 action_impl! {
-    (Protocol, Actions),
+    (Protocol, Actions, UniswapProtocolTokens),
     Protocol::UniswapV3,
     UniswapV3::swapCall,
     Swap,
@@ -22,7 +23,7 @@ action_impl! {
         let token_0_delta = return_data.amount0.abs().try_into().unwrap();
         let token_1_delta = return_data.amount1.abs().try_into().unwrap();
         let recipient = call_data.recipient;
-        let tokens = db_tx.get_protocol_tokens_sorted(info.target_address)?;
+        let tokens = db_tx.get_metadata(info.protocol_address)?.sorted_tokens;
 
         let (token_in, amount_in, token_out, amount_out) = if return_data.amount0.is_negative() {
             (tokens[1], token_1_delta, tokens[0], token_0_delta)
@@ -30,15 +31,18 @@ action_impl! {
             (tokens[0], token_0_delta, tokens[1], token_1_delta)
         };
 
-        Ok(ActionSwap {
-            protocol: Protocol::UniswapV2,
-            pool: info.target_address,
+        Ok(NormalizedSwap {
+            protocol: Protocol::UniswapV3,
+            trace_index: info.trace_idx,
+            from: info.from_address,
             recipient,
+            pool: info.target_address,
+            pool_id: None,
             token_in,
             token_out,
             amount_in,
             amount_out,
-
+            msg_value: info.msg_value,
         })
     }
 }
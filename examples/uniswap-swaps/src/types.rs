@@ -2,13 +2,14 @@ use std::collections::HashMap;
 
 use crate::classifier::uniswap_v2::*;
 use crate::classifier::uniswap_v3::*;
-use alloy_primitives::{Address, U256};
+use alloy_primitives::Address;
 
 use brontes_classifier::action_dispatch;
-use brontes_classifier::context::DataContext;
+use brontes_classifier::context::{DataContext, NotRegistered};
+use brontes_classifier_types::Action;
 
 action_dispatch!(
-    (UniswapSwapClassifer, Protocol) => Actions | UniswapV2SwapCall, UniswapV3SwapCall
+    (UniswapSwapClassifer, Protocol, UniswapProtocolTokens) => Actions | UniswapV2SwapCall, UniswapV3SwapCall
 );
 
 #[derive(Debug, Clone, Copy)]
@@ -17,21 +18,7 @@ pub enum Protocol {
     UniswapV3,
 }
 
-#[derive(Debug, Clone)]
-pub enum Actions {
-    Swap(ActionSwap),
-}
-
-#[derive(Debug, Clone)]
-pub struct ActionSwap {
-    pub protocol: Protocol,
-    pub pool: Address,
-    pub recipient: Address,
-    pub token_in: Address,
-    pub token_out: Address,
-    pub amount_in: U256,
-    pub amount_out: U256,
-}
+pub type Actions = Action<Protocol>;
 
 #[derive(Debug, Clone)]
 pub struct DataCache {
@@ -46,12 +33,12 @@ impl DataCache {
     }
 }
 
-impl DataContext<Protocol> for DataCache {
+impl DataContext<Protocol, UniswapProtocolTokens> for DataCache {
     fn get_protocol(&self, target_address: Address) -> eyre::Result<Protocol> {
         Ok(self
             .cache
             .get(&target_address)
-            .ok_or(eyre::eyre!("protocol does not exist"))?
+            .ok_or(NotRegistered::Address(target_address))?
             .protocol)
     }
 
@@ -63,6 +50,13 @@ impl DataContext<Protocol> for DataCache {
             .sorted_tokens
             .to_vec())
     }
+
+    fn get_metadata(&self, target_address: Address) -> eyre::Result<UniswapProtocolTokens> {
+        self.cache
+            .get(&target_address)
+            .cloned()
+            .ok_or(eyre::eyre!("metadata does not exist"))
+    }
 }
 
 #[derive(Debug, Clone)]